@@ -23,14 +23,15 @@ The purpose of the library is to test general properties of a program rather tha
 - Once a failing case is found, `{{name}}` will try to reduce the input to the simplest version of it that continues to fail (using a kind of binary search of the input space) to make the debugging process much easier.
 - Note that `{{name}}` does not guarantee any kind of exhaustive search of the input space (the size of it gets out of hand rather quickly) and is meant as a complement to other testing strategies.
 - It is recommended to write a regular unit test with the exact failing input to prevent a regression and to truly guarantee that the failing input is always tested.
+- Checks also run on `wasm32-unknown-unknown` (such as under `wasm-bindgen-test`). Since that target has no clock, reported durations are zero, and since its panics abort, a check that fails by panicking ends the test with its panic message rather than being shrunk. Enable the `js` feature of `fastrand` in your own manifest to get random seeds.
 
 ---
 ### Main Traits
 -   [`Generate`](src/generate.rs): is implemented for many of rust's standard types and allows the generation of any random composite/structured data through combinator (such as tuples, [`Any`](src/any.rs), [`Map`](src/map.rs), [`Flatten`](src/flatten.rs) and more). It is designed for composability and its usage should feel like working with `Iterator`s.
 -   [`Shrink`](src/shrink.rs): tries to reduce a generated sample to a 'smaller' version of it while maintaining its constraints (ex: a sample `usize` in the range `10..100` will never be shrunk below `10`). For numbers, it means bringing the sample closer to 0, for vectors, it means removing irrelevant items and shrinking the remaining ones, and so on.
--   [`Prove`](src/prove.rs): represents a desirable property of a program under test. It is used mainly in the context of the [`Check::check`](src/check.rs) or [`Checker::check`](src/check.rs) methods and it is the failure of a proof that triggers the shrinking process. It is implemented for a couple of standard types such as `()`, `bool` and `Result`. A `panic!()` is also considered as a failing property, thus standard `assert!()` macros (or any other panicking assertions) can be used to check the property.
+-   [`Prove`](src/prove.rs): represents a desirable property of a program under test. It is used mainly in the context of the [`Check::check`](src/check.rs) or [`Checker::check`](src/check.rs) methods and it is the failure of a proof that triggers the shrinking process. It is implemented for a couple of standard types such as `()`, `bool`, `Option` and `Result` (results compose when wrapped in [`Nested`](src/prove.rs)) and the [`all!`](src/prove.rs) macro proves a conjunction of properties while identifying the one that failed. [`Check::differential`](src/check.rs) (or `#[check(differential = reference)]`) compares a candidate implementation to a reference one and reports a diff of their outputs. A `panic!()` is also considered as a failing property, thus standard `assert!()` macros (or any other panicking assertions) can be used to check the property.
    
-*To ensure safety, this library is `#![forbid(unsafe_code)]`.*
+*To ensure safety, this library is `#![forbid(unsafe_code)]`. The only exception is the opt-in `allocation` feature, since implementing a global allocator requires `unsafe`; its tracker only forwards to the wrapped allocator.*
 
 ---
 ### Cheat Sheet
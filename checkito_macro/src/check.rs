@@ -1,11 +1,14 @@
 use core::{fmt, mem::replace, ops::Deref};
+use proc_macro2::TokenTree;
 use quote::{ToTokens, format_ident, quote_spanned};
 use std::collections::HashSet;
 use syn::{
     __private::{Span, TokenStream2},
-    Error, Expr, ExprAssign, ExprField, ExprLit, ExprPath, ExprRange, FnArg, Ident, Lit, LitBool,
-    Member, Meta, PatType, Path, PathSegment, RangeLimits, Signature,
+    Error, Expr, ExprAssign, ExprContinue, ExprField, ExprLit, ExprPath, ExprRange, FnArg, Ident,
+    Item, ItemMod, Lit, LitBool, Member, Meta, Pat, PatType, Path, PathSegment, RangeLimits,
+    Signature, Type,
     parse::{Parse, ParseStream},
+    parse_quote,
     punctuated::Punctuated,
     spanned::Spanned,
     token::Comma,
@@ -19,6 +22,21 @@ pub struct Check {
     pub debug: Option<bool>,
     pub color: Option<bool>,
     pub verbose: Option<bool>,
+    pub quiet: Option<bool>,
+    pub literals: Option<bool>,
+    pub progress: Option<bool>,
+    pub corpus: Option<bool>,
+    pub erased: Option<bool>,
+    pub fixture: Option<(Expr, Option<Expr>)>,
+    pub reporter: Option<Expr>,
+    pub differential: Option<Expr>,
+    pub continues: Option<bool>,
+    /// The positions of the parameters whose generator is wrapped in 'keep'.
+    pub keep: Vec<(usize, Span)>,
+    /// The positions of the generators that are annotated with '#[keep]'.
+    pub kept: Vec<usize>,
+    /// The size overrides of the parameters, by position.
+    pub sizes: Vec<(usize, TokenStream2)>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -26,29 +44,67 @@ pub enum Key {
     Color,
     Debug,
     Verbose,
+    Quiet,
+    Literals,
+    Progress,
+    Corpus,
+    Erased,
+    Fixture,
+    Reporter,
+    Differential,
+    Continue,
+    Keep,
+    Size,
     GenerateCount,
+    GenerateRuns,
     GenerateSeed,
     GenerateSize,
     GenerateItems,
     GenerateError,
+    GenerateExhaustive,
+    GenerateRamp,
+    GenerateSkips,
+    GenerateHits,
     ShrinkCount,
     ShrinkItems,
     ShrinkErrors,
+    ShrinkStrategy,
+    ShrinkConfirm,
+    ShrinkExplain,
 }
 
 impl Key {
-    const KEYS: [Key; 11] = [
+    const KEYS: [Key; 30] = [
         Key::Color,
         Key::Debug,
         Key::Verbose,
+        Key::Quiet,
+        Key::Literals,
+        Key::Progress,
+        Key::Corpus,
+        Key::Erased,
+        Key::Fixture,
+        Key::Reporter,
+        Key::Differential,
+        Key::Continue,
+        Key::Keep,
+        Key::Size,
         Key::GenerateCount,
+        Key::GenerateRuns,
         Key::GenerateSeed,
         Key::GenerateSize,
         Key::GenerateItems,
         Key::GenerateError,
+        Key::GenerateExhaustive,
+        Key::GenerateRamp,
+        Key::GenerateSkips,
+        Key::GenerateHits,
         Key::ShrinkCount,
         Key::ShrinkItems,
         Key::ShrinkErrors,
+        Key::ShrinkStrategy,
+        Key::ShrinkConfirm,
+        Key::ShrinkExplain,
     ];
 }
 
@@ -72,14 +128,33 @@ impl From<Key> for &'static str {
             Key::Color => "color",
             Key::Debug => "debug",
             Key::Verbose => "verbose",
+            Key::Quiet => "quiet",
+            Key::Literals => "literals",
+            Key::Progress => "progress",
+            Key::Corpus => "corpus",
+            Key::Erased => "erased",
+            Key::Fixture => "fixture",
+            Key::Reporter => "reporter",
+            Key::Differential => "differential",
+            Key::Continue => "continue",
+            Key::Keep => "keep",
+            Key::Size => "size",
             Key::GenerateCount => "generate.count",
+            Key::GenerateRuns => "generate.runs",
             Key::GenerateSeed => "generate.seed",
             Key::GenerateSize => "generate.size",
             Key::GenerateItems => "generate.items",
             Key::GenerateError => "generate.error",
+            Key::GenerateExhaustive => "generate.exhaustive",
+            Key::GenerateRamp => "generate.ramp",
+            Key::GenerateSkips => "generate.skips",
+            Key::GenerateHits => "generate.hits",
             Key::ShrinkCount => "shrink.count",
             Key::ShrinkItems => "shrink.items",
             Key::ShrinkErrors => "shrink.errors",
+            Key::ShrinkStrategy => "shrink.strategy",
+            Key::ShrinkConfirm => "shrink.confirm",
+            Key::ShrinkExplain => "shrink.explain",
         }
     }
 }
@@ -144,6 +219,8 @@ impl TryFrom<&Expr> for Key {
                 }
                 Err(unrecognized())
             }
+            // The 'continue' key is a keyword and parses as an expression of its own.
+            Expr::Continue(ExprContinue { label: None, .. }) => Ok(Key::Continue),
             _ => Err(invalid()),
         }
     }
@@ -165,35 +242,79 @@ impl Check {
             debug: None,
             color: None,
             verbose: None,
+            quiet: None,
+            literals: None,
+            progress: None,
+            corpus: None,
+            erased: None,
+            fixture: None,
+            reporter: None,
+            differential: None,
+            continues: None,
+            keep: Vec::new(),
+            kept: Vec::new(),
+            sizes: Vec::new(),
         }
     }
 
-    pub fn run(&self, signature: &Signature) -> Result<TokenStream2, Error> {
+    /// The `test` is the name of the generated test function, which differs from
+    /// the name of the wrapped `signature`.
+    pub fn run(
+        &self,
+        signature: &Signature,
+        test: &Ident,
+        literals: &[TokenStream2],
+        draws: bool,
+    ) -> Result<TokenStream2, Error> {
+        // The fixture is always passed as the first parameter.
+        let skip = usize::from(self.fixture.is_some());
+        let inputs = signature.inputs.iter().skip(skip).collect::<Vec<_>>();
         let rest = match self.rest {
             Some((rest, span)) => (
                 rest,
-                rest + signature.inputs.len().saturating_sub(self.generators.len()),
+                rest + inputs.len().saturating_sub(self.generators.len()),
                 span,
             ),
             None => (usize::MAX, usize::MAX, Span::call_site()),
         };
-        let mut expressions = self.generators.iter();
+        let positions = self
+            .keep
+            .iter()
+            .copied()
+            .chain(self.sizes.iter().map(|(index, size)| (*index, size.span())));
+        if let Some((_, span)) = positions
+            .into_iter()
+            .find(|(index, _)| *index >= inputs.len())
+        {
+            return Err(Error::new(
+                span,
+                format!(
+                    "invalid parameter position\nmust be smaller than the number of parameters '{}'",
+                    inputs.len()
+                ),
+            ));
+        }
+        let mut expressions = self.generators.iter().enumerate();
         let mut generators = Vec::new();
         let mut arguments = Vec::new();
-        for (index, parameter) in signature.inputs.iter().enumerate() {
-            let FnArg::Typed(PatType { ty, .. }) = parameter else {
+        let mut names = Vec::new();
+        for (index, parameter) in inputs.into_iter().enumerate() {
+            let FnArg::Typed(PatType { pat, ty, .. }) = parameter else {
                 return Err(error(parameter, |parameter| {
                     format!("invalid parameter '{parameter}'")
                 }));
             };
 
+            let mut keep = self.keep.iter().any(|(position, _)| *position == index);
             let generator = if index >= rest.0 && index < rest.1 {
-                quote_spanned!(rest.2 => <#ty as ::checkito::generate::FullGenerate>::generator())
+                self.infer(ty, rest.2, literals)
             } else {
-                match expressions.next() {
-                    Some(Expr::Infer(infer)) => {
-                        quote_spanned!(infer.span() => <#ty as ::checkito::generate::FullGenerate>::generator())
-                    }
+                let next = expressions.next();
+                if let Some((position, _)) = next {
+                    keep |= self.kept.contains(&position);
+                }
+                match next.map(|(_, expression)| expression) {
+                    Some(Expr::Infer(infer)) => self.infer(ty, infer.span(), literals),
                     Some(expression) => quote_spanned!(expression.span() => #expression),
                     None => {
                         return Err(error(parameter, |parameter| {
@@ -207,11 +328,26 @@ impl Check {
                     }
                 }
             };
+            let generator = match self.sizes.iter().find(|(position, _)| *position == index) {
+                Some((_, size)) => quote_spanned!(size.span() =>
+                    ::checkito::Generate::size(#generator, |_| ::checkito::check::Sizes::from(#size))
+                ),
+                None => generator,
+            };
+            let generator = if keep {
+                quote_spanned!(generator.span() => ::checkito::Generate::keep(#generator))
+            } else {
+                generator
+            };
             generators.push(generator);
             arguments.push(format_ident!("_{}", arguments.len()));
+            names.push(match &**pat {
+                Pat::Ident(pat) => pat.ident.to_string(),
+                pat => pat.to_token_stream().to_string(),
+            });
         }
 
-        if let Some(expression) = expressions.next() {
+        if let Some((_, expression)) = expressions.next() {
             return Err(error(expression, |expression| {
                 format!(
                     "missing parameter for generator '{expression}'\neither add a parameter in \
@@ -226,6 +362,9 @@ impl Check {
                 Key::GenerateCount => {
                     quote_spanned!(left.span() => _checker.generate.count = #right;)
                 }
+                Key::GenerateRuns => {
+                    quote_spanned!(left.span() => _checker.generate.runs = #right;)
+                }
                 Key::GenerateSeed => {
                     quote_spanned!(left.span() => _checker.generate.seed = #right;)
                 }
@@ -238,6 +377,18 @@ impl Check {
                 Key::GenerateError => {
                     quote_spanned!(left.span() => _checker.generate.error = #right;)
                 }
+                Key::GenerateExhaustive => {
+                    quote_spanned!(left.span() => _checker.generate.mode = #right;)
+                }
+                Key::GenerateRamp => {
+                    quote_spanned!(left.span() => _checker.generate.ramp = #right;)
+                }
+                Key::GenerateSkips => {
+                    quote_spanned!(left.span() => _checker.generate.skips = #right;)
+                }
+                Key::GenerateHits => {
+                    quote_spanned!(left.span() => _checker.generate.hits = #right;)
+                }
                 Key::ShrinkCount => {
                     quote_spanned!(left.span() => _checker.shrink.count = #right;)
                 }
@@ -247,36 +398,194 @@ impl Check {
                 Key::ShrinkErrors => {
                     quote_spanned!(left.span() => _checker.shrink.errors = #right;)
                 }
-                Key::Debug | Key::Color | Key::Verbose => continue,
+                Key::ShrinkStrategy => {
+                    quote_spanned!(left.span() => _checker.shrink.strategy = #right;)
+                }
+                Key::ShrinkConfirm => {
+                    quote_spanned!(left.span() => _checker.shrink.confirm = #right;)
+                }
+                Key::ShrinkExplain => {
+                    quote_spanned!(left.span() => _checker.shrink.explain = #right;)
+                }
+                Key::Debug
+                | Key::Color
+                | Key::Verbose
+                | Key::Quiet
+                | Key::Literals
+                | Key::Progress
+                | Key::Corpus
+                | Key::Erased
+                | Key::Fixture
+                | Key::Reporter
+                | Key::Differential
+                | Key::Continue
+                | Key::Keep
+                | Key::Size => continue,
             });
         }
 
         let name = &signature.ident;
         let color = self.color.unwrap_or(true);
+        if self.progress.unwrap_or(false) {
+            updates.push(quote_spanned!(self.span =>
+                _checker.generate.progress = Some(::checkito::check::help::progress::<#color>);
+            ));
+        }
         let verbose = self.verbose.unwrap_or(false);
-        Ok(match self.debug {
-            Some(true) => quote_spanned!(self.span => ::checkito::check::help::debug(
-                (#(#generators,)*),
-                |_checker| { #(#updates)* },
-                |(#(#arguments,)*)| #name(#(#arguments,)*),
-                #color,
-                #verbose,
-            )),
-            Some(false) => quote_spanned!(self.span => ::checkito::check::help::minimal(
-                (#(#generators,)*),
-                |_checker| { #(#updates)* },
-                |(#(#arguments,)*)| #name(#(#arguments,)*),
-                #color,
-                #verbose,
-            )),
-            None => quote_spanned!(self.span => ::checkito::check::help::default(
-                (#(#generators,)*),
-                |_checker| { #(#updates)* },
-                |(#(#arguments,)*)| #name(#(#arguments,)*),
-                #color,
-                #verbose,
-            )),
-        })
+        let quiet = self.quiet.unwrap_or(false);
+        let help = match self.debug {
+            Some(true) => format_ident!("debug"),
+            Some(false) => format_ident!("minimal"),
+            None => format_ident!("default"),
+        };
+        let (fixture, call) = match &self.fixture {
+            Some((setup, teardown)) => {
+                let teardown = match teardown {
+                    Some(teardown) => quote_spanned!(teardown.span() => #teardown),
+                    None => quote_spanned!(setup.span() => ::core::mem::drop),
+                };
+                (
+                    quote_spanned!(setup.span() =>
+                        let _fixture = ::core::cell::RefCell::new(
+                            ::checkito::check::help::fixture(#setup, #teardown)
+                        );
+                    ),
+                    quote_spanned!(self.span => #name(&mut *_fixture.borrow_mut(), #(#arguments,)*)),
+                )
+            }
+            None => (
+                TokenStream2::new(),
+                quote_spanned!(self.span => #name(#(#arguments,)*)),
+            ),
+        };
+        // The function is the candidate implementation that is compared to the reference.
+        let call = match &self.differential {
+            Some(reference) => quote_spanned!(reference.span() =>
+                ::checkito::prove::Differential::new(
+                    #reference(#(::core::clone::Clone::clone(&#arguments),)*),
+                    #call,
+                )
+            ),
+            None => call,
+        };
+        // Items drawn with 'gen!' come from an additional state that is not passed to the function.
+        let mut pattern = arguments.clone();
+        let call = if draws {
+            generators.push(quote_spanned!(self.span => ::checkito::draw::Draws));
+            pattern.push(format_ident!("_draws"));
+            names.push("draws".into());
+            quote_spanned!(self.span => ::checkito::draw::scope(_draws, || #call))
+        } else {
+            call
+        };
+        let path = quote_spanned!(self.span =>
+            ::core::concat!(::core::module_path!(), "::", ::core::stringify!(#test))
+        );
+        let (generator, check) = if self.corpus.unwrap_or(false) {
+            (
+                quote_spanned!(self.span => ::checkito::corpus::Corpus::new((#(#generators,)*))),
+                quote_spanned!(self.span => ::checkito::corpus::record(#path, |(#(#pattern,)*)| #call)),
+            )
+        } else if pattern.is_empty() {
+            (
+                quote_spanned!(self.span => ()),
+                quote_spanned!(self.span => |()| #call),
+            )
+        } else {
+            // Items are wrapped such that their 'Debug' representation names each parameter.
+            (
+                quote_spanned!(self.span =>
+                    ::checkito::check::help::arguments(&[#(#names,)*], (#(#generators,)*))
+                ),
+                quote_spanned!(self.span =>
+                    |::checkito::check::help::Arguments { values: (#(#pattern,)*), .. }| #call
+                ),
+            )
+        };
+        // The default reporter describes errors with their `Display` when they have one.
+        let check = if self.reporter.is_none() && self.debug.is_none() {
+            quote_spanned!(self.span => {
+                #[allow(unused_imports)]
+                use ::checkito::check::help::describe::{
+                    Chain as _, Debug as _, Display as _, ResultChain as _, ResultDisplay as _,
+                };
+                let _check = #check;
+                let _format = (&&&&&::checkito::check::help::describe::tag(&_check)).describe();
+                ::checkito::check::help::describe::check(_check, _format)
+            })
+        } else {
+            check
+        };
+        let update = quote_spanned!(self.span => |_checker| { #(#updates)* });
+        // An erased check boxes its generator, update and check such that the reporting
+        // machinery is shared by all the checks with the same item and proof types.
+        let (erase, generator, update, check) = if self.erased.unwrap_or(false) {
+            (
+                quote_spanned!(self.span =>
+                    let (_generator, _update, _check) =
+                        ::checkito::check::help::erase(#generator, #update, #check);
+                ),
+                quote_spanned!(self.span => _generator),
+                quote_spanned!(self.span => _update),
+                quote_spanned!(self.span => _check),
+            )
+        } else {
+            (TokenStream2::new(), generator, update, check)
+        };
+        let run = match &self.reporter {
+            Some(reporter) => quote_spanned!(self.span =>
+                ::checkito::check::help::report(
+                    #generator,
+                    #update,
+                    #check,
+                    #reporter,
+                    #color,
+                    #verbose,
+                    #quiet,
+                    #path,
+                )
+            ),
+            None => quote_spanned!(self.span =>
+                ::checkito::check::help::#help(
+                    #generator,
+                    #update,
+                    #check,
+                    #color,
+                    #verbose,
+                    #quiet,
+                    #path,
+                )
+            ),
+        };
+        Ok(quote_spanned!(self.span => {
+            #fixture
+            #erase
+            #run
+        }))
+    }
+}
+
+impl Check {
+    /// Literals are only mixed into inferred generators since their domain is
+    /// the full domain of the parameter's type. Mixing them into an explicit
+    /// generator could produce values outside of its bounds.
+    ///
+    /// The literals that fit in the parameter's type are resolved by the
+    /// compiler (see `checkito::check::help::Literals`) rather than by
+    /// matching the name of the type, such that aliases are supported.
+    fn infer(&self, ty: &Type, span: Span, literals: &[TokenStream2]) -> TokenStream2 {
+        let generator =
+            quote_spanned!(span => <#ty as ::checkito::generate::FullGenerate>::generator());
+        if self.literals.unwrap_or(false) && !literals.is_empty() {
+            quote_spanned!(span => ::checkito::check::help::literals(#generator, {
+                // Only one of the traits is used, depending on the type of the parameter.
+                #[allow(unused_imports)]
+                use ::checkito::check::help::{Fit as _, Miss as _};
+                (&::checkito::check::help::Literals::<#ty>::new(&[#(#literals,)*])).values()
+            }))
+        } else {
+            generator
+        }
     }
 }
 
@@ -284,11 +593,17 @@ impl Parse for Check {
     fn parse(input: ParseStream) -> Result<Self, Error> {
         let mut check = Check::new(input.span());
         let mut keys = Key::KEYS.into_iter().collect::<HashSet<_>>();
+        // The `shrink.count = 0` setting, if any, which disables shrinking.
+        let mut unshrunk = None;
         for expression in Punctuated::<Expr, Comma>::parse_terminated(input)? {
             match expression {
                 Expr::Assign(ExprAssign { left, right, .. }) => {
                     let key = Key::try_from(left.as_ref())?;
                     if keys.remove(&key) {
+                        validate(key, &right)?;
+                        if key == Key::ShrinkCount && as_integer(&right) == Some(0) {
+                            unshrunk = Some(format!("{} = {}", string(&left), string(&right)));
+                        }
                         let right = match key {
                             Key::Debug => {
                                 check.debug = Some(as_bool(&right)?);
@@ -302,9 +617,61 @@ impl Parse for Check {
                                 check.verbose = Some(as_bool(&right)?);
                                 continue;
                             }
+                            Key::Quiet => {
+                                check.quiet = Some(as_bool(&right)?);
+                                continue;
+                            }
+                            Key::Literals => {
+                                check.literals = Some(as_bool(&right)?);
+                                continue;
+                            }
+                            Key::Progress => {
+                                check.progress = Some(as_bool(&right)?);
+                                continue;
+                            }
+                            Key::Corpus => {
+                                check.corpus = Some(as_bool(&right)?);
+                                continue;
+                            }
+                            Key::Erased => {
+                                check.erased = Some(as_bool(&right)?);
+                                continue;
+                            }
+                            Key::Fixture => {
+                                check.fixture = Some(match *right {
+                                    Expr::Tuple(tuple) if tuple.elems.len() == 2 => {
+                                        let mut elements = tuple.elems.into_iter();
+                                        (elements.next().unwrap(), elements.next())
+                                    }
+                                    right => (right, None),
+                                });
+                                continue;
+                            }
+                            Key::Reporter => {
+                                check.reporter = Some(*right);
+                                continue;
+                            }
+                            Key::Differential => {
+                                check.differential = Some(*right);
+                                continue;
+                            }
+                            Key::Continue => {
+                                check.continues = Some(as_bool(&right)?);
+                                continue;
+                            }
+                            Key::Keep => {
+                                check.keep.extend(as_indices(&right)?);
+                                continue;
+                            }
+                            Key::Size => {
+                                check.sizes.extend(as_sizes(&right)?);
+                                continue;
+                            }
                             Key::GenerateSize => {
                                 quote_spanned!(right.span() => ::checkito::check::Sizes::from(#right))
                             }
+                            Key::GenerateRamp => as_ramp(&right)?,
+                            Key::GenerateExhaustive => as_mode(&right)?,
                             _ => right.to_token_stream(),
                         };
                         check.settings.push((key, *left, right));
@@ -324,7 +691,34 @@ impl Parse for Check {
                         check.rest = Some((check.generators.len(), expression.span()));
                     }
                 }
-                expression => check.generators.push(expression),
+                expression => {
+                    // A '#[keep]' generator keeps the parameter at its position.
+                    let expression = match unkeep(&expression)? {
+                        Some(expression) => {
+                            check.kept.push(check.generators.len());
+                            expression
+                        }
+                        None => expression,
+                    };
+                    check.generators.push(expression);
+                }
+            }
+        }
+        if let Some(unshrunk) = unshrunk {
+            for (key, left, right) in check.settings.iter() {
+                let inert = match key {
+                    Key::ShrinkStrategy => true,
+                    Key::ShrinkExplain => right.to_string() == "true",
+                    _ => false,
+                };
+                if inert {
+                    return Err(error(left, |left| {
+                        format!(
+                            "setting '{left}' has no effect with '{unshrunk}'\neither remove \
+                             it or allow shrinking"
+                        )
+                    }));
+                }
             }
         }
         Ok(check)
@@ -356,6 +750,129 @@ impl TryFrom<&syn::Attribute> for Check {
     }
 }
 
+/// Appends the module level `settings` to every `#[check]` attribute of the
+/// functions in `items` (including the ones in nested modules) unless the
+/// attribute already has a setting with the same key.
+pub fn inherit(settings: &Punctuated<Expr, Comma>, items: &mut [Item]) -> Result<(), Error> {
+    for item in items {
+        match item {
+            Item::Fn(function) => {
+                for attribute in function.attrs.iter_mut() {
+                    if Check::try_from(&*attribute).is_err() {
+                        continue;
+                    }
+                    let path = attribute.path().to_token_stream();
+                    let mut expressions = match &attribute.meta {
+                        Meta::Path(_) => Punctuated::new(),
+                        meta => meta
+                            .require_list()?
+                            .parse_args_with(Punctuated::<Expr, Comma>::parse_terminated)?,
+                    };
+                    let keys = expressions
+                        .iter()
+                        .filter_map(|expression| match expression {
+                            Expr::Assign(ExprAssign { left, .. }) => {
+                                Key::try_from(left.as_ref()).ok()
+                            }
+                            _ => None,
+                        })
+                        .collect::<HashSet<_>>();
+                    for setting in settings {
+                        if let Expr::Assign(ExprAssign { left, .. }) = setting {
+                            if !keys.contains(&Key::try_from(left.as_ref())?) {
+                                expressions.push(parse_quote!(#setting));
+                            }
+                        }
+                    }
+                    *attribute = parse_quote!(#[#path(#expressions)]);
+                }
+            }
+            Item::Mod(ItemMod {
+                content: Some((_, items)),
+                ..
+            }) => inherit(settings, items)?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Collects all the literals in the `tokens`, including the ones in nested
+/// groups, as unique `checkito::check::help::Literal` expressions. Since a
+/// negative number is a negation of a literal, a literal that follows a `-` is
+/// collected both with and without its sign.
+pub fn literals(tokens: TokenStream2) -> Vec<TokenStream2> {
+    fn collect(tokens: TokenStream2, literals: &mut Vec<TokenStream2>) {
+        let mut negative = false;
+        for tree in tokens {
+            match &tree {
+                TokenTree::Literal(literal) => {
+                    let literal = Lit::new(literal.clone());
+                    if negative {
+                        literals.extend(self::literal(&literal, true));
+                    }
+                    literals.extend(self::literal(&literal, false));
+                }
+                TokenTree::Group(group) => collect(group.stream(), literals),
+                TokenTree::Ident(_) | TokenTree::Punct(_) => {}
+            }
+            negative = matches!(&tree, TokenTree::Punct(punct) if punct.as_char() == '-');
+        }
+    }
+
+    let mut literals = Vec::new();
+    collect(tokens, &mut literals);
+    let mut unique = HashSet::new();
+    literals.retain(|literal| unique.insert(literal.to_string()));
+    literals
+}
+
+fn literal(literal: &Lit, negative: bool) -> Option<TokenStream2> {
+    let sign = if negative { "-" } else { "" };
+    match literal {
+        Lit::Int(literal) => {
+            let (digits, suffix) = (
+                format!("{sign}{}", literal.base10_digits()),
+                literal.suffix(),
+            );
+            Some(quote_spanned!(literal.span() =>
+                ::checkito::check::help::Literal::Integer(#digits, #suffix)))
+        }
+        Lit::Float(literal) => {
+            let (digits, suffix) = (
+                format!("{sign}{}", literal.base10_digits()),
+                literal.suffix(),
+            );
+            Some(quote_spanned!(literal.span() =>
+                ::checkito::check::help::Literal::Float(#digits, #suffix)))
+        }
+        Lit::Char(literal) if !negative => Some(quote_spanned!(literal.span() =>
+            ::checkito::check::help::Literal::Char(#literal))),
+        Lit::Str(literal) if !negative => Some(quote_spanned!(literal.span() =>
+            ::checkito::check::help::Literal::Str(#literal))),
+        _ => None,
+    }
+}
+
+/// Whether the `tokens` invoke the `gen!` macro, in which case the check is given
+/// a state to draw from.
+pub fn draws(tokens: TokenStream2) -> bool {
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(tree) = tokens.next() {
+        match tree {
+            TokenTree::Ident(ident) if ident == "gen" || ident == "r#gen" => {
+                if matches!(tokens.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == '!')
+                {
+                    return true;
+                }
+            }
+            TokenTree::Group(group) if draws(group.stream()) => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
 fn string<T: ToTokens>(tokens: &T) -> String {
     tokens.to_token_stream().to_string()
 }
@@ -393,3 +910,238 @@ fn as_bool(expression: &Expr) -> Result<bool, Error> {
         })),
     }
 }
+
+/// Parses an array of parameter positions such as `[0, 2]`.
+fn as_indices(expression: &Expr) -> Result<Vec<(usize, Span)>, Error> {
+    let invalid = || {
+        error(expression, |expression| {
+            format!("expression '{expression}' must be an array of parameter positions")
+        })
+    };
+    let Expr::Array(array) = expression else {
+        return Err(invalid());
+    };
+    array
+        .elems
+        .iter()
+        .map(|element| match element {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(literal),
+                ..
+            }) => Ok((literal.base10_parse()?, literal.span())),
+            _ => Err(invalid()),
+        })
+        .collect()
+}
+
+/// Parses an array of sizes by parameter position such as `[1.0, _, 0.2]`,
+/// where `_` leaves the size of its parameter unchanged.
+fn as_sizes(expression: &Expr) -> Result<Vec<(usize, TokenStream2)>, Error> {
+    let Expr::Array(array) = expression else {
+        return Err(error(expression, |expression| {
+            format!("expression '{expression}' must be an array of sizes")
+        }));
+    };
+    let mut sizes = Vec::new();
+    for (index, element) in array.elems.iter().enumerate() {
+        if !matches!(element, Expr::Infer(_)) {
+            validate(Key::GenerateSize, element)?;
+            sizes.push((index, element.to_token_stream()));
+        }
+    }
+    Ok(sizes)
+}
+
+/// Strips a leading '#[keep]' attribute from a generator expression, if any.
+fn unkeep(expression: &Expr) -> Result<Option<Expr>, Error> {
+    let mut tokens = expression.to_token_stream().into_iter();
+    match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Punct(punct)), Some(TokenTree::Group(group)))
+            if punct.as_char() == '#' && group.stream().to_string() == "keep" =>
+        {
+            syn::parse2(tokens.collect()).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Validates the literal values of the settings at compile time such that an
+/// invalid value is reported on the attribute rather than when the check runs.
+/// Non-literal expressions are left to the type checker and the runtime.
+///
+/// ```
+/// #[checkito::check(_, generate.size = 0.5..1.0, generate.count = 10, shrink.count = 0)]
+/// fn valid(_: u8) {}
+/// ```
+///
+/// An empty size range is rejected.
+/// ```compile_fail
+/// #[checkito::check(_, generate.size = 1.0..0.5)]
+/// fn empty(_: u8) {}
+/// ```
+///
+/// A size outside of `0.0..=1.0` is rejected.
+/// ```compile_fail
+/// #[checkito::check(_, generate.size = 0.0..2.0)]
+/// fn outside(_: u8) {}
+/// ```
+///
+/// A check that generates no item is rejected.
+/// ```compile_fail
+/// #[checkito::check(_, generate.count = 0)]
+/// fn zero(_: u8) {}
+/// ```
+///
+/// A negative count is rejected.
+/// ```compile_fail
+/// #[checkito::check(_, shrink.count = -1)]
+/// fn negative(_: u8) {}
+/// ```
+///
+/// A shrinking strategy is rejected when shrinking is disabled.
+/// ```compile_fail
+/// #[checkito::check(_, shrink.count = 0, shrink.strategy = checkito::check::Strategy::Exhaustive)]
+/// fn inert(_: u8) {}
+/// ```
+fn validate(key: Key, expression: &Expr) -> Result<(), Error> {
+    match key {
+        Key::GenerateSize => match expression {
+            Expr::Range(ExprRange {
+                start, end, limits, ..
+            }) => {
+                let start = start.as_deref().map(as_size).transpose()?.flatten();
+                let end = end.as_deref().map(as_size).transpose()?.flatten();
+                match (start, end, limits) {
+                    (Some(start), Some(end), RangeLimits::HalfOpen(_)) if start >= end => {}
+                    (Some(start), Some(end), RangeLimits::Closed(_)) if start > end => {}
+                    _ => return Ok(()),
+                }
+                Err(error(expression, |expression| {
+                    format!("size range '{expression}' must not be empty")
+                }))
+            }
+            expression => as_size(expression).map(|_| ()),
+        },
+        Key::GenerateCount | Key::GenerateRuns => match as_count(expression)? {
+            Some(0) => Err(error(expression, |expression| {
+                format!("count '{expression}' must be greater than 0")
+            })),
+            _ => Ok(()),
+        },
+        Key::GenerateSkips => match as_float(expression) {
+            Some(ratio) if !(0.0..=1.0).contains(&ratio) => Err(error(expression, |expression| {
+                format!("ratio '{expression}' must be within 0.0..=1.0")
+            })),
+            _ => Ok(()),
+        },
+        Key::ShrinkCount | Key::ShrinkConfirm => as_count(expression).map(|_| ()),
+        _ => Ok(()),
+    }
+}
+
+/// The value of an integer literal, possibly negated.
+fn as_integer(expression: &Expr) -> Option<i128> {
+    match expression {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(literal),
+            ..
+        }) => literal.base10_parse().ok(),
+        Expr::Unary(unary) if matches!(unary.op, syn::UnOp::Neg(_)) => {
+            as_integer(&unary.expr)?.checked_neg()
+        }
+        Expr::Paren(paren) => as_integer(&paren.expr),
+        _ => None,
+    }
+}
+
+/// The value of a floating point or integer literal, possibly negated.
+fn as_float(expression: &Expr) -> Option<f64> {
+    match expression {
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(literal),
+            ..
+        }) => literal.base10_parse().ok(),
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(literal),
+            ..
+        }) => literal.base10_parse().ok(),
+        Expr::Unary(unary) if matches!(unary.op, syn::UnOp::Neg(_)) => {
+            as_float(&unary.expr).map(|value| -value)
+        }
+        Expr::Paren(paren) => as_float(&paren.expr),
+        _ => None,
+    }
+}
+
+/// A literal count must fit in a `usize`.
+fn as_count(expression: &Expr) -> Result<Option<i128>, Error> {
+    match as_integer(expression) {
+        Some(count) if count < 0 => Err(error(expression, |expression| {
+            format!("count '{expression}' must not be negative")
+        })),
+        Some(count) if count > usize::MAX as i128 => Err(error(expression, |expression| {
+            format!("count '{expression}' must fit in a 'usize'")
+        })),
+        count => Ok(count),
+    }
+}
+
+/// A literal size must be within `0.0..=1.0`.
+fn as_size(expression: &Expr) -> Result<Option<f64>, Error> {
+    match as_float(expression) {
+        Some(size) if !(0.0..=1.0).contains(&size) => Err(error(expression, |expression| {
+            format!("size '{expression}' must be within 0.0..=1.0")
+        })),
+        size => Ok(size),
+    }
+}
+
+/// A string literal is resolved to a `Modes` variant at compile time such that a
+/// typo is reported on the attribute. Any other expression is passed through.
+fn as_mode(expression: &Expr) -> Result<TokenStream2, Error> {
+    match expression {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(literal),
+            ..
+        }) => {
+            let variant = match literal.value().as_str() {
+                "random" => format_ident!("Random"),
+                "stratified" => format_ident!("Stratified"),
+                _ => {
+                    return Err(error(expression, |expression| {
+                        format!(
+                            "invalid mode {expression}\nmust be one of [\"random\", \"stratified\"]"
+                        )
+                    }));
+                }
+            };
+            Ok(quote_spanned!(literal.span() => ::checkito::check::Modes::#variant))
+        }
+        expression => Ok(expression.to_token_stream()),
+    }
+}
+
+fn as_ramp(expression: &Expr) -> Result<TokenStream2, Error> {
+    match expression {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(literal),
+            ..
+        }) => {
+            let variant = match literal.value().as_str() {
+                "ascending" => format_ident!("Ascending"),
+                "descending" => format_ident!("Descending"),
+                "constant" => format_ident!("Constant"),
+                _ => {
+                    return Err(error(expression, |expression| {
+                        format!(
+                            "invalid ramp {expression}\nmust be one of [\"ascending\", \
+                             \"descending\", \"constant\"]"
+                        )
+                    }));
+                }
+            };
+            Ok(quote_spanned!(literal.span() => ::checkito::check::Ramp::#variant))
+        }
+        expression => Ok(expression.to_token_stream()),
+    }
+}
@@ -26,12 +26,55 @@ pub fn check(
     item: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
     use core::mem::{replace, take};
-    use quote::{format_ident, quote};
-    use syn::{ItemFn, Visibility, parse_macro_input};
+    use quote::{ToTokens, format_ident, quote};
+    use syn::{
+        Error, Expr, Item, ItemFn, ItemMod, Visibility, parse::Parser, parse_macro_input,
+        punctuated::Punctuated, token::Comma,
+    };
 
+    let settings = attribute.clone();
     let check: check::Check = parse_macro_input!(attribute);
     let mut checks = vec![check];
-    let mut function: ItemFn = parse_macro_input!(item);
+    let mut function: ItemFn = match parse_macro_input!(item) {
+        Item::Fn(function) => function,
+        // Settings of a module level '#[check]' are inherited by the '#[check]' attributes
+        // of the functions inside the module.
+        Item::Mod(mut module) => {
+            let check = &checks[0];
+            if let Some(generator) = check.generators.first() {
+                return Error::new_spanned(
+                    generator,
+                    "a module level '#[check]' only accepts settings",
+                )
+                .to_compile_error()
+                .into();
+            }
+            let result = Punctuated::<Expr, Comma>::parse_terminated
+                .parse(settings)
+                .and_then(|settings| match &mut module {
+                    ItemMod {
+                        content: Some((_, items)),
+                        ..
+                    } => check::inherit(&settings, items),
+                    module => Err(Error::new_spanned(
+                        module,
+                        "a module level '#[check]' requires an inline module",
+                    )),
+                });
+            return match result {
+                Ok(()) => module.into_token_stream().into(),
+                Err(error) => error.to_compile_error().into(),
+            };
+        }
+        item => {
+            return Error::new_spanned(
+                item,
+                "'#[check]' must be applied to a function or a module",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
     let name = replace(&mut function.sig.ident, format_ident!("check"));
     let visibility = replace(&mut function.vis, Visibility::Inherited);
     let mut attributes = take(&mut function.attrs);
@@ -43,19 +86,45 @@ pub fn check(
             true
         }
     });
+    let literals = check::literals(function.block.to_token_stream());
+    let draws = check::draws(function.block.to_token_stream());
+    let count = checks.len();
     let mut runs = Vec::new();
-    for check in checks {
-        match check.run(&function.sig) {
+    for (index, check) in checks.iter().enumerate() {
+        match check.run(&function.sig, &name, &literals, draws) {
+            // The failure of a check with 'continue = true' is reported once all checks have run.
+            Ok(run) if check.continues.unwrap_or(false) => {
+                let index = index + 1;
+                runs.push(quote!(_failures.extend(
+                    ::checkito::check::help::attempt(#index, || { #run; })
+                )));
+            }
             Ok(run) => runs.push(run),
             Err(error) => return error.to_compile_error().into(),
         }
     }
+    let (failures, conclude) = if checks.iter().any(|check| check.continues.unwrap_or(false)) {
+        (
+            quote!(let mut _failures = ::std::vec::Vec::new();),
+            quote! {
+                ::checkito::check::help::conclude(
+                    ::core::concat!(::core::module_path!(), "::", ::core::stringify!(#name)),
+                    #count,
+                    &_failures,
+                );
+            },
+        )
+    } else {
+        (quote!(), quote!())
+    };
     quote! {
         #(#attributes)*
         #[test]
         #visibility fn #name() {
             #function
+            #failures
             #(#runs;)*
+            #conclude
         }
     }
     .into()
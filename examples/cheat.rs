@@ -112,6 +112,14 @@ fn person_has_valid_name_and_is_major(person: Person) {
     assert!(person.age >= 18);
 }
 
+/// Parameters can be destructured with irrefutable patterns, as in any function
+/// signature. A failing item is reported under the pattern of its parameter.
+#[check((letter().collect(), 18usize..=100).map(|(name, age)| Person { name, age }), debug = false)]
+fn person_can_be_destructured(Person { name, age }: Person) {
+    assert!(name.is_ascii());
+    assert!(age >= 18);
+}
+
 /// The `#[check]` attribute essentially expands to a call to [`Check::check`]
 /// with pretty printing. For some more complex scenarios, it may become more
 /// convenient to simply call the [`Check::check`] manually.
@@ -146,6 +154,8 @@ fn main() {
             check::Result::Shrink(_pass) => {}
             check::Result::Shrunk(_fail) => {}
             check::Result::Fail(_fail) => {}
+            check::Result::Flaky(_flaky) => {}
+            check::Result::Skip(_skip) => {}
         }
     }
 
@@ -8,7 +8,7 @@ while IFS= read -r line || [[ -n "$line" ]]; do
             content=$(cat "$key")
             line="${line//\{\{$key\}\}/$content}"
         else 
-            value=$(awk -F " = " "/^$key/ {gsub(/(^\"|\"\$)/, \"\", \$2); print \$2}" Cargo.toml)
+            value=$(awk -F " = " "/^$key/ {gsub(/(^\"|\"\$)/, \"\", \$2); print \$2; exit}" Cargo.toml)
             if [[ -n "$value" ]]; then
                 line="${line//\{\{$key\}\}/$value}"
             fi
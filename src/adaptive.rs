@@ -0,0 +1,219 @@
+use crate::{
+    any::{self, Shrinker, Weight},
+    cardinality,
+    generate::{Generate, State},
+    shrink::Shrink,
+};
+use std::sync::{Mutex, PoisonError};
+
+/// Chooses among branches with a bandit policy that favors the branches whose
+/// items were the most novel so far (see [`crate::adaptive()`]).
+///
+/// The statistics of a run start over when its first item is generated (or
+/// when the seed changes), such that the items of a run only depend on its
+/// seed and on the items generated before them. Since an item depends on the
+/// items before it, an [`Adaptive`] does not implement [`Clone`] and can not
+/// be checked in parallel (where items are generated out of order).
+#[derive(Debug)]
+pub struct Adaptive<C: ?Sized, F> {
+    pub(crate) statistics: Mutex<Statistics>,
+    pub(crate) novelty: F,
+    pub(crate) branches: C,
+}
+
+/// The statistics of the current run of an [`Adaptive`] choice. The pulls of
+/// an item are only committed to the `arms` once a later item is generated,
+/// such that generating the same item again makes the same choice.
+#[derive(Debug, Default)]
+pub(crate) struct Statistics {
+    /// The seed and index of the last generated item.
+    last: Option<(u64, usize)>,
+    arms: Vec<Arm>,
+    pending: Vec<Arm>,
+}
+
+/// The statistics of a branch of an [`Adaptive`] choice.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Arm {
+    /// The number of times that the branch was chosen.
+    pub pulls: usize,
+    /// The sum of the novelty of the items generated by the branch.
+    pub reward: f64,
+}
+
+/// A branch of an [`Adaptive`] choice. A [`Weight`] scales the exploration
+/// bonus of its generator by its weight relative to the average weight.
+pub trait Branch {
+    type Generator: Generate + ?Sized;
+
+    fn generator(&self) -> &Self::Generator;
+
+    fn weight(&self) -> f64 {
+        1.0
+    }
+}
+
+impl<G: Generate> Branch for G {
+    type Generator = G;
+
+    fn generator(&self) -> &Self::Generator {
+        self
+    }
+}
+
+impl<G: Generate> Branch for Weight<G> {
+    type Generator = G;
+
+    fn generator(&self) -> &Self::Generator {
+        self.value()
+    }
+
+    fn weight(&self) -> f64 {
+        Weight::weight(self)
+    }
+}
+
+impl<C: ?Sized, F> Adaptive<C, F> {
+    /// A snapshot of the statistics of each branch, in the order of the
+    /// branches.
+    pub fn arms(&self) -> Vec<Arm> {
+        let statistics = self
+            .statistics
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        statistics
+            .arms
+            .iter()
+            .zip(&statistics.pending)
+            .map(|(arm, pending)| Arm {
+                pulls: arm.pulls + pending.pulls,
+                reward: arm.reward + pending.reward,
+            })
+            .collect()
+    }
+}
+
+impl Statistics {
+    /// Prepares the statistics to generate the item at `index` of the run
+    /// with `seed` among `branches`.
+    fn advance(&mut self, seed: u64, index: usize, branches: usize) {
+        match self.last {
+            Some((last, previous)) if last == seed && previous <= index => {
+                if previous < index {
+                    for (arm, pending) in self.arms.iter_mut().zip(&mut self.pending) {
+                        arm.pulls += pending.pulls;
+                        arm.reward += pending.reward;
+                        *pending = Arm::default();
+                    }
+                }
+            }
+            // A new run starts over.
+            _ => {
+                self.arms.clear();
+                self.pending.clear();
+            }
+        }
+        self.last = Some((seed, index));
+        self.arms.resize(branches, Arm::default());
+        self.pending.resize(branches, Arm::default());
+    }
+}
+
+/// Selects the branch with the highest upper confidence bound (UCB1). Branches
+/// that were never chosen are chosen first. Ties are broken with the random
+/// number generator of the `state`.
+fn select<B: Branch>(arms: &[Arm], branches: &[B], state: &mut State) -> usize {
+    let untried = arms
+        .iter()
+        .enumerate()
+        .filter(|(_, arm)| arm.pulls == 0)
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    let candidates = if untried.is_empty() {
+        let total = arms.iter().map(|arm| arm.pulls).sum::<usize>() as f64;
+        let average = branches.iter().map(Branch::weight).sum::<f64>() / branches.len() as f64;
+        let scores = arms
+            .iter()
+            .zip(branches)
+            .map(|(arm, branch)| {
+                let pulls = arm.pulls as f64;
+                let bonus = (2.0 * total.ln() / pulls).sqrt() * branch.weight() / average;
+                arm.reward / pulls + bonus
+            })
+            .collect::<Vec<_>>();
+        let best = scores.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        scores
+            .iter()
+            .enumerate()
+            .filter(|&(_, &score)| score == best)
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>()
+    } else {
+        untried
+    };
+    candidates[state.random().usize(..candidates.len())]
+}
+
+macro_rules! slice {
+    ($t:ty, [$($n:ident)?]) => {
+        impl<B: Branch, F: Fn(&<B::Generator as Generate>::Item) -> f64, $(const $n: usize)?> Generate
+            for Adaptive<$t, F>
+        {
+            type Item = Option<<B::Generator as Generate>::Item>;
+            type Shrink = Shrinker<<B::Generator as Generate>::Shrink>;
+
+            fn generate(&self, state: &mut State) -> Self::Shrink {
+                let branches = as_slice(&self.branches);
+                if branches.is_empty() {
+                    return Shrinker(None);
+                }
+                let index = {
+                    let mut statistics =
+                        self.statistics.lock().unwrap_or_else(PoisonError::into_inner);
+                    statistics.advance(state.seed(), state.index, branches.len());
+                    select(&statistics.arms, branches, state)
+                };
+                any::hit(self, index, branches.len());
+                let shrinker = branches[index].generator().generate(state);
+                // The lock is not held while calling `novelty` such that it may not deadlock.
+                let reward = (self.novelty)(&shrinker.item());
+                let mut statistics = self.statistics.lock().unwrap_or_else(PoisonError::into_inner);
+                let arm = &mut statistics.pending[index];
+                arm.pulls += 1;
+                arm.reward += if reward.is_nan() {
+                    0.0
+                } else {
+                    reward.clamp(0.0, 1.0)
+                };
+                Shrinker(Some(shrinker))
+            }
+
+            fn constant(&self) -> bool {
+                match as_slice(&self.branches) {
+                    [] => true,
+                    [branch] => branch.generator().constant(),
+                    _ => false,
+                }
+            }
+
+            fn cardinality(&self) -> Option<u128> {
+                let branches = as_slice(&self.branches);
+                if branches.is_empty() {
+                    Some(1)
+                } else {
+                    branches.iter().try_fold(0, |sum, branch| {
+                        cardinality::sum(Some(sum), branch.generator().cardinality())
+                    })
+                }
+            }
+        }
+    };
+}
+
+const fn as_slice<T>(slice: &[T]) -> &[T] {
+    slice
+}
+
+slice!([B], []);
+slice!([B; N], [N]);
+slice!(Vec<B>, []);
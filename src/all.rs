@@ -1,5 +1,7 @@
 use crate::{
+    cardinality,
     generate::{FullGenerate, Generate, State},
+    order::Components,
     shrink::Shrink,
     utility::tuples,
 };
@@ -26,6 +28,33 @@ pub(crate) fn shrink<S: Shrink, I: AsMut<[S]> + Clone>(
     }
 }
 
+/// Releases every component and moves `index` back to the first released one.
+pub(crate) fn release<S: Shrink>(shrinkers: &mut [S], index: &mut usize) -> bool {
+    let mut first = None;
+    for (position, shrinker) in shrinkers.iter_mut().enumerate() {
+        if shrinker.release() {
+            first = first.or(Some(position));
+        }
+    }
+    match first {
+        Some(first) => {
+            *index = first;
+            true
+        }
+        None => false,
+    }
+}
+
+pub(crate) fn shrink_component<S: Shrink, I: AsMut<[S]> + Clone>(
+    shrinkers: &mut I,
+    index: usize,
+) -> Option<I> {
+    let new = shrinkers.as_mut().get_mut(index)?.shrink()?;
+    let mut shrinkers = shrinkers.clone();
+    shrinkers.as_mut()[index] = new;
+    Some(shrinkers)
+}
+
 mod array {
     use super::*;
     use core::array;
@@ -53,6 +82,34 @@ mod array {
         fn constant(&self) -> bool {
             self.iter().all(Generate::constant)
         }
+
+        fn cardinality(&self) -> Option<u128> {
+            self.iter().try_fold(1, |product, generator| {
+                cardinality::product(Some(product), generator.cardinality())
+            })
+        }
+
+        fn shrink_from(&self, item: Self::Item) -> Option<Self::Shrink> {
+            let shrinkers = self
+                .iter()
+                .zip(item)
+                .map(|(generator, item)| generator.shrink_from(item))
+                .collect::<Option<Vec<_>>>()?;
+            Some(Shrinker {
+                index: 0,
+                shrinkers: shrinkers.try_into().ok()?,
+            })
+        }
+    }
+
+    impl<S: Shrink, const N: usize> Components for Shrinker<[S; N]> {
+        fn shrink_component(&mut self, index: usize) -> Option<Self> {
+            let shrinkers = shrink_component(&mut self.shrinkers, index)?;
+            Some(Self {
+                shrinkers,
+                index: self.index,
+            })
+        }
     }
 
     impl<S: Shrink, const N: usize> Shrink for Shrinker<[S; N]> {
@@ -69,6 +126,10 @@ mod array {
                 index: self.index,
             })
         }
+
+        fn release(&mut self) -> bool {
+            release(&mut self.shrinkers, &mut self.index)
+        }
     }
 }
 
@@ -92,6 +153,22 @@ mod slice {
         fn constant(&self) -> bool {
             self.iter().all(Generate::constant)
         }
+
+        fn cardinality(&self) -> Option<u128> {
+            self.iter().try_fold(1, |product, generator| {
+                cardinality::product(Some(product), generator.cardinality())
+            })
+        }
+    }
+
+    impl<S: Shrink> Components for Shrinker<Box<[S]>> {
+        fn shrink_component(&mut self, index: usize) -> Option<Self> {
+            let shrinkers = shrink_component(&mut self.shrinkers, index)?;
+            Some(Self {
+                shrinkers,
+                index: self.index,
+            })
+        }
     }
 
     impl<S: Shrink> Shrink for Shrinker<Box<[S]>> {
@@ -108,6 +185,10 @@ mod slice {
                 index: self.index,
             })
         }
+
+        fn release(&mut self) -> bool {
+            release(&mut self.shrinkers, &mut self.index)
+        }
     }
 }
 
@@ -131,6 +212,22 @@ mod vector {
         fn constant(&self) -> bool {
             self.iter().all(Generate::constant)
         }
+
+        fn cardinality(&self) -> Option<u128> {
+            self.iter().try_fold(1, |product, generator| {
+                cardinality::product(Some(product), generator.cardinality())
+            })
+        }
+    }
+
+    impl<S: Shrink> Components for Shrinker<Vec<S>> {
+        fn shrink_component(&mut self, index: usize) -> Option<Self> {
+            let shrinkers = shrink_component(&mut self.shrinkers, index)?;
+            Some(Self {
+                shrinkers,
+                index: self.index,
+            })
+        }
     }
 
     impl<S: Shrink> Shrink for Shrinker<Vec<S>> {
@@ -147,6 +244,10 @@ mod vector {
                 index: self.index,
             })
         }
+
+        fn release(&mut self) -> bool {
+            release(&mut self.shrinkers, &mut self.index)
+        }
     }
 }
 
@@ -176,6 +277,19 @@ macro_rules! tuple {
             fn constant(&self) -> bool {
                 $($t::constant(&self.$i) &&)* true
             }
+
+            fn cardinality(&self) -> Option<u128> {
+                let _product = Some(1);
+                $(let _product = cardinality::product(_product, $t::cardinality(&self.$i));)*
+                _product
+            }
+
+            fn shrink_from(&self, _item: Self::Item) -> Option<Self::Shrink> {
+                Some(Shrinker {
+                    index: 0,
+                    shrinkers: ($($t::shrink_from(&self.$i, _item.$i)?,)*),
+                })
+            }
         }
 
         impl<$($t: Shrink,)*> Shrink for Shrinker<($($t,)*)> {
@@ -202,8 +316,39 @@ macro_rules! tuple {
                     }
                 }
             }
+
+            #[allow(unused_mut)]
+            fn release(&mut self) -> bool {
+                let mut first: Option<usize> = None;
+                $(if self.shrinkers.$i.release() {
+                    first = first.or(Some($i));
+                })*
+                match first {
+                    Some(first) => {
+                        self.index = first;
+                        true
+                    }
+                    None => false,
+                }
+            }
+        }
+
+        impl<$($t: Shrink,)*> Components for Shrinker<($($t,)*)> {
+            fn shrink_component(&mut self, _index: usize) -> Option<Self> {
+                match _index {
+                    $($i => {
+                        let shrinker = self.shrinkers.$i.shrink()?;
+                        let mut shrinkers = self.shrinkers.clone();
+                        shrinkers.$i = shrinker;
+                        Some(Self { shrinkers, index: self.index })
+                    })*
+                    _ => None,
+                }
+            }
         }
     };
 }
 
 tuples!(tuple);
+#[cfg(feature = "big-tuples")]
+crate::utility::big_tuples!(tuple);
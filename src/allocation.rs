@@ -0,0 +1,148 @@
+//! An allocation budget for the checks of a property.
+//!
+//! A check that allocates without bounds for some inputs may exhaust the memory
+//! of the machine, such that the whole test process is killed and the offending
+//! input is lost. When the [`Tracker`] is installed as the global allocator,
+//! the bytes allocated by each check invocation are tracked on the thread that
+//! runs it. An invocation that exceeds the budget of the tracker at any point
+//! fails with a [`Cause::ResourceExhausted`] and its input is shrunk like any
+//! other failing input.
+//!
+//! Since a global allocator is not allowed to unwind, the invocation runs to
+//! completion and is failed when it returns. An unbounded allocation larger
+//! than the available memory still aborts the process.
+//!
+//! ```
+//! use checkito::allocation::Tracker;
+//!
+//! // In the test binary (or a `tests/*.rs` file).
+//! #[global_allocator]
+//! static ALLOCATOR: Tracker = Tracker::new(64 << 20);
+//! ```
+//!
+//! [`Cause::ResourceExhausted`]: crate::check::Cause::ResourceExhausted
+
+#![allow(unsafe_code)]
+
+use core::cell::Cell;
+use std::alloc::{GlobalAlloc, Layout, System};
+
+/// A global allocator that tracks the bytes allocated by check invocations
+/// and flags the invocations that exceed a budget.
+#[derive(Debug)]
+pub struct Tracker<A = System> {
+    allocator: A,
+    budget: usize,
+}
+
+/// The usage of the check invocation that is running on a thread.
+#[derive(Clone, Copy)]
+struct Usage {
+    live: usize,
+    peak: usize,
+    exceeded: Option<usize>,
+}
+
+thread_local! { static USAGE: Cell<Option<Usage>> = const { Cell::new(None) }; }
+
+impl Tracker {
+    /// A tracker over the [`System`] allocator with a budget of `budget`
+    /// bytes per check invocation.
+    pub const fn new(budget: usize) -> Self {
+        Self::with_allocator(System, budget)
+    }
+}
+
+impl<A> Tracker<A> {
+    /// A tracker over `allocator` with a budget of `budget` bytes per check
+    /// invocation.
+    pub const fn with_allocator(allocator: A, budget: usize) -> Self {
+        Self { allocator, budget }
+    }
+
+    pub const fn budget(&self) -> usize {
+        self.budget
+    }
+
+    fn grow(&self, size: usize) {
+        // `try_with` may fail while the thread is being torn down, in which case nothing
+        // is tracked anymore.
+        let _ = USAGE.try_with(|usage| {
+            if let Some(mut current) = usage.get() {
+                current.live = current.live.saturating_add(size);
+                current.peak = current.peak.max(current.live);
+                if current.peak > self.budget {
+                    current.exceeded.get_or_insert(self.budget);
+                }
+                usage.set(Some(current));
+            }
+        });
+    }
+
+    fn shrink(&self, size: usize) {
+        let _ = USAGE.try_with(|usage| {
+            if let Some(mut current) = usage.get() {
+                // Memory allocated before the invocation may be freed during it.
+                current.live = current.live.saturating_sub(size);
+                usage.set(Some(current));
+            }
+        });
+    }
+}
+
+// SAFETY: Every call is forwarded to `allocator` with the same arguments. The
+// tracking only touches a thread local `Cell` that never allocates.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for Tracker<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: The caller upholds the contract of `alloc`, which is forwarded as is.
+        let pointer = unsafe { self.allocator.alloc(layout) };
+        if !pointer.is_null() {
+            self.grow(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: The caller upholds the contract of `alloc_zeroed`, which is forwarded
+        // as is.
+        let pointer = unsafe { self.allocator.alloc_zeroed(layout) };
+        if !pointer.is_null() {
+            self.grow(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        // SAFETY: `pointer` was allocated by `self.allocator` with `layout`, since every
+        // allocation of this tracker is forwarded to it.
+        unsafe { self.allocator.dealloc(pointer, layout) };
+        self.shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, size: usize) -> *mut u8 {
+        // SAFETY: `pointer` was allocated by `self.allocator` with `layout`, and the
+        // caller upholds the contract of `realloc` for `size`.
+        let new = unsafe { self.allocator.realloc(pointer, layout, size) };
+        if !new.is_null() {
+            self.shrink(layout.size());
+            self.grow(size);
+        }
+        new
+    }
+}
+
+/// Runs `run` while tracking its allocations on this thread and returns the
+/// peak number of bytes that it allocated along with the exceeded budget, if
+/// any. The usage of an enclosing invocation is restored afterwards.
+pub(crate) fn track<T>(run: impl FnOnce() -> T) -> (T, Option<(usize, usize)>) {
+    let fresh = Usage {
+        live: 0,
+        peak: 0,
+        exceeded: None,
+    };
+    let outer = USAGE.with(|usage| usage.replace(Some(fresh)));
+    let value = run();
+    let inner = USAGE.with(|usage| usage.replace(outer));
+    let exceeded = inner.and_then(|inner| Some((inner.peak, inner.exceeded?)));
+    (value, exceeded)
+}
@@ -1,11 +1,15 @@
 use crate::{
+    adaptive::{Adaptive, Branch},
+    cardinality,
+    cover::Cover,
     generate::{Generate, State},
+    prelude,
     shrink::Shrink,
     utility::tuples,
 };
-use core::f64;
+use core::{any::type_name, cell::RefCell, f64, fmt, mem::take};
 use ref_cast::RefCast;
-use std::{rc::Rc, sync::Arc};
+use std::{error, rc::Rc, sync::Arc};
 
 #[repr(transparent)]
 #[derive(Clone, Debug, RefCast)]
@@ -20,6 +24,32 @@ pub struct Weight<T: ?Sized> {
     generator: T,
 }
 
+/// The number of times that each branch of a choice was chosen over the checks
+/// of a property (see [`crate::check::Generates::hits`]).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Hits {
+    /// A readable label of the choice made of its type name without module
+    /// paths followed by its position among the choices in the order in
+    /// which they were first chosen, starting at 1 (such as
+    /// `Any<(Range<u8>, Range<u8>)> #2`).
+    pub name: String,
+    /// The number of times that each branch was chosen, in the order of the
+    /// branches. A branch with a count of `0` was never chosen.
+    pub counts: Vec<usize>,
+    /// The address and the type name of the choice, such that choices of the
+    /// same type (such as two parameters of a check) are counted separately.
+    /// Choices that are created while generating (such as with
+    /// [`Generate::flatten`]) are identified by where they happen to live.
+    key: (usize, &'static str),
+}
+
+thread_local! { static HITS: RefCell<Option<Vec<Hits>>> = const { RefCell::new(None) }; }
+
+/// The error returned when a weight is not finite or is smaller than
+/// [`f64::EPSILON`] (see [`Weight::try_new`]).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InvalidWeight(pub f64);
+
 impl<T> Weight<T> {
     pub const fn weight(&self) -> f64 {
         self.weight
@@ -31,28 +61,224 @@ impl<T> Weight<T> {
 }
 
 impl<G: Generate> Weight<G> {
+    /// # Panics
+    /// If the weight is invalid (see [`Weight::try_new`]).
     pub fn new(weight: f64, generator: G) -> Self {
-        assert!(weight.is_finite());
-        assert!(weight >= f64::EPSILON);
-        Self { weight, generator }
+        match Self::try_new(weight, generator) {
+            Ok(weight) => weight,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Creates a weighted generator unless `weight` is not finite or is smaller
+    /// than [`f64::EPSILON`], which is useful when weights are computed at
+    /// runtime.
+    pub fn try_new(weight: f64, generator: G) -> Result<Self, InvalidWeight> {
+        Ok(Self {
+            weight: validate(weight)?,
+            generator,
+        })
+    }
+
+    /// Replaces the weight of this generator while preserving the same
+    /// invariants as [`Weight::new`]. This allows to adjust the weights of an
+    /// existing collection of [`Weight`] at runtime (e.g. from a configuration
+    /// file) without rebuilding it.
+    ///
+    /// # Panics
+    /// If the weight is invalid (see [`Weight::try_reweigh`]).
+    pub fn reweigh(&mut self, weight: f64) {
+        if let Err(error) = self.try_reweigh(weight) {
+            panic!("{error}");
+        }
+    }
+
+    /// Replaces the weight of this generator unless `weight` is invalid (see
+    /// [`Weight::try_new`]), in which case the weight is left unchanged.
+    pub fn try_reweigh(&mut self, weight: f64) -> Result<(), InvalidWeight> {
+        self.weight = validate(weight)?;
+        Ok(())
+    }
+}
+
+impl<C> Any<C> {
+    /// Turns this choice into an [`Adaptive`] choice rewarded by `novelty`
+    /// (see [`crate::adaptive()`]).
+    pub fn adaptive<B: Branch, F: Fn(&<B::Generator as Generate>::Item) -> f64>(
+        self,
+        novelty: F,
+    ) -> Adaptive<C, F>
+    where
+        C: AsRef<[B]>,
+    {
+        prelude::adaptive(self.0, novelty)
+    }
+
+    /// Turns this choice into a [`Cover`] choice that chooses each branch at
+    /// least once before following the weights (see
+    /// [`crate::cover_all_branches`]).
+    pub fn cover_all_branches<B: Branch>(self) -> Cover<C>
+    where
+        C: AsRef<[B]>,
+    {
+        prelude::cover_all_branches(self.0)
+    }
+}
+
+impl<W: ?Sized> Any<W> {
+    /// Replaces the weight of each of the weighted generators with the weight
+    /// that `weigh` computes from it, with the same invariants as
+    /// [`Weight::reweigh`]. This allows to adjust the weights of an existing
+    /// [`Any`] at runtime, such as to favor the branches that were rarely hit
+    /// in previous checks.
+    ///
+    /// ```
+    /// use checkito::{any::Weight, *};
+    ///
+    /// let mut generator = any([Weight::new(1.0, 0..10), Weight::new(1.0, 10..20)]);
+    /// generator.reweigh(|range| if range.start == 0 { 100.0 } else { 1.0 });
+    /// ```
+    ///
+    /// # Panics
+    /// If any of the weights is invalid (see [`Any::try_reweigh`]).
+    pub fn reweigh<G: Generate, F: FnMut(&G) -> f64>(&mut self, weigh: F)
+    where
+        W: AsMut<[Weight<G>]>,
+    {
+        if let Err(error) = self.try_reweigh(weigh) {
+            panic!("{error}");
+        }
+    }
+
+    /// Replaces the weights like [`Any::reweigh`] unless any of the computed
+    /// weights is invalid (see [`Weight::try_new`]), in which case the first
+    /// invalid weight is returned and no weight is changed.
+    pub fn try_reweigh<G: Generate, F: FnMut(&G) -> f64>(
+        &mut self,
+        mut weigh: F,
+    ) -> Result<(), InvalidWeight>
+    where
+        W: AsMut<[Weight<G>]>,
+    {
+        let weights = self.0.as_mut();
+        let values = weights
+            .iter()
+            .map(|weight| validate(weigh(&weight.generator)))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (weight, value) in weights.iter_mut().zip(values) {
+            weight.weight = value;
+        }
+        Ok(())
+    }
+}
+
+fn validate(weight: f64) -> Result<f64, InvalidWeight> {
+    if weight.is_finite() && weight >= f64::EPSILON {
+        Ok(weight)
+    } else {
+        Err(InvalidWeight(weight))
+    }
+}
+
+impl fmt::Display for InvalidWeight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid weight '{}', a weight must be finite and at least '{}'",
+            self.0,
+            f64::EPSILON
+        )
     }
 }
 
+impl error::Error for InvalidWeight {}
+
 impl<G: Generate + ?Sized> Weight<G> {
+    fn generate(&self, state: &mut State) -> G::Shrink {
+        self.generator.generate(state)
+    }
+
     fn constant(&self) -> bool {
         self.generator.constant()
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.generator.cardinality()
+    }
+}
+
+impl fmt::Display for Hits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total = self.counts.iter().sum::<usize>().max(1) as f64;
+        write!(f, "{}", self.name)?;
+        for (index, count) in self.counts.iter().enumerate() {
+            let percent = *count as f64 / total * 100.0;
+            write!(f, "\n  branch {index}: {count} ({percent:.1}%)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Records that the branch at `index` out of `branches` of the `choice` was
+/// chosen, if hits are being recorded on this thread.
+pub(crate) fn hit<C: ?Sized>(choice: &C, index: usize, branches: usize) {
+    HITS.with(|hits| {
+        if let Some(hits) = hits.borrow_mut().as_mut() {
+            let key = (choice as *const C as *const () as usize, type_name::<C>());
+            let position = match hits.iter().position(|hits| hits.key == key) {
+                Some(position) => position,
+                None => {
+                    hits.push(Hits {
+                        name: format!("{} #{}", short(key.1), hits.len() + 1),
+                        counts: Vec::new(),
+                        key,
+                    });
+                    hits.len() - 1
+                }
+            };
+            let counts = &mut hits[position].counts;
+            // Choices of the same type, such as vectors, may have different numbers of branches.
+            if counts.len() < branches {
+                counts.resize(branches, 0);
+            }
+            counts[index] += 1;
+        }
+    });
 }
 
-fn indexed<'a, T>(items: &'a [T], state: &mut State) -> Option<&'a T> {
+/// Removes the module paths of a type name, such that
+/// `checkito::any::Any<(core::ops::Range<u8>,)>` becomes `Any<(Range<u8>,)>`.
+fn short(name: &str) -> String {
+    let mut parts = name.split("::");
+    let mut short = String::from(parts.next().unwrap_or_default());
+    for part in parts {
+        // Removes the module that precedes the separator.
+        let module =
+            short.trim_end_matches(|letter: char| letter.is_alphanumeric() || letter == '_');
+        short.truncate(module.len());
+        short.push_str(part);
+    }
+    short
+}
+
+/// Runs `run` while adding the branches that it chose to `hits`. The hits of
+/// an enclosing recording are restored afterwards.
+pub(crate) fn record<T>(hits: &mut Vec<Hits>, run: impl FnOnce() -> T) -> T {
+    let outer = HITS.with(|cell| cell.replace(Some(take(hits))));
+    let value = run();
+    *hits = HITS.with(|cell| cell.replace(outer)).unwrap_or_default();
+    value
+}
+
+fn indexed<T>(items: &[T], state: &mut State) -> Option<usize> {
     if items.is_empty() {
         None
     } else {
-        items.get(state.random().usize(0..items.len()))
+        Some(state.random().usize(0..items.len()))
     }
 }
 
-fn weighted<'a, T>(items: &'a [Weight<T>], state: &mut State) -> Option<&'a T> {
+pub(crate) fn weighted<T>(items: &[Weight<T>], state: &mut State) -> Option<usize> {
     if items.is_empty() {
         None
     } else {
@@ -62,15 +288,11 @@ fn weighted<'a, T>(items: &'a [Weight<T>], state: &mut State) -> Option<&'a T> {
             .sum::<f64>()
             .min(f64::MAX);
         debug_assert!(total > 0.0 && total.is_finite());
-        let mut random = state.random().f64() * total;
+        let mut random = state.ratio() * total;
         debug_assert!(random.is_finite());
-        for Weight {
-            weight,
-            generator: value,
-        } in items
-        {
+        for (index, Weight { weight, .. }) in items.iter().enumerate() {
             if random < *weight {
-                return Some(value);
+                return Some(index);
             } else {
                 random -= weight;
             }
@@ -99,6 +321,10 @@ where
     fn constant(&self) -> bool {
         Any::ref_cast(self.0).constant()
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        Any::ref_cast(self.0).cardinality()
+    }
 }
 
 impl<G: ?Sized> Generate for Any<&mut G>
@@ -115,6 +341,10 @@ where
     fn constant(&self) -> bool {
         Any::ref_cast(self.0).constant()
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        Any::ref_cast(self.0).cardinality()
+    }
 }
 
 impl<S: Shrink> Shrink for Shrinker<S> {
@@ -127,6 +357,10 @@ impl<S: Shrink> Shrink for Shrinker<S> {
     fn shrink(&mut self) -> Option<Self> {
         Some(Self(self.0.as_mut()?.shrink()))
     }
+
+    fn release(&mut self) -> bool {
+        self.0.as_mut().map_or(false, Shrink::release)
+    }
 }
 
 const fn as_slice<T>(slice: &[T]) -> &[T] {
@@ -149,6 +383,10 @@ macro_rules! pointer {
             fn constant(&self) -> bool {
                 Any::ref_cast(self.0.as_ref()).constant()
             }
+
+            fn cardinality(&self) -> Option<u128> {
+                Any::ref_cast(self.0.as_ref()).cardinality()
+            }
         }
     };
 }
@@ -164,12 +402,25 @@ macro_rules! slice {
             type Shrink = Shrinker<G::Shrink>;
 
             fn generate(&self, state: &mut State) -> Self::Shrink {
-                Shrinker($i(as_slice(self.as_ref()), state).map(|generator| generator.generate(state)))
+                let generators = as_slice(self.as_ref());
+                Shrinker($i(generators, state).map(|index| {
+                    hit(self, index, generators.len());
+                    generators[index].generate(state)
+                }))
             }
 
             fn constant(&self) -> bool {
                 as_slice(self.as_ref()).iter().all(|generator| generator.constant())
             }
+
+            fn cardinality(&self) -> Option<u128> {
+                let generators = as_slice(self.as_ref());
+                if generators.is_empty() {
+                    Some(1)
+                } else {
+                    generators.iter().try_fold(0, |sum, generator| cardinality::sum(Some(sum), generator.cardinality()))
+                }
+            }
         }
     };
 }
@@ -180,6 +431,9 @@ slice!(Any<Vec<G>>, indexed, []);
 slice!([Weight<G>], weighted, []);
 slice!([Weight<G>; N], weighted, [N]);
 slice!(Vec<Weight<G>>, weighted, []);
+slice!(Any<[Weight<G>]>, weighted, []);
+slice!(Any<[Weight<G>; N]>, weighted, [N]);
+slice!(Any<Vec<Weight<G>>>, weighted, []);
 
 macro_rules! tuple {
     ($n:ident, $c:tt) => {};
@@ -199,6 +453,12 @@ macro_rules! tuple {
                     $(Self::$ts(generator) => generator.constant(),)*
                 }
             }
+
+            fn cardinality(&self) -> Option<u128> {
+                match self {
+                    $(Self::$ts(generator) => generator.cardinality(),)*
+                }
+            }
         }
 
         impl<$($ts: Shrink,)*> Shrink for orn::$n::Or<$($ts,)*> {
@@ -222,7 +482,9 @@ macro_rules! tuple {
             type Shrink = orn::$n::Or<$($ts::Shrink,)*>;
 
             fn generate(&self, state: &mut State) -> Self::Shrink {
-                match state.random().u8(..$c) {
+                let index = state.random().u8(..$c);
+                hit(self, index as usize, $c);
+                match index {
                     $($is => orn::$n::Or::$ts(self.0.$is.generate(state)),)*
                     _ => unreachable!(),
                 }
@@ -231,6 +493,12 @@ macro_rules! tuple {
             fn constant(&self) -> bool {
                 $(self.0.$is.constant() &&)* true
             }
+
+            fn cardinality(&self) -> Option<u128> {
+                let _sum = Some(0);
+                $(let _sum = cardinality::sum(_sum, self.0.$is.cardinality());)*
+                _sum
+            }
         }
 
         impl<$($ts: Generate,)*> Generate for ($(Weight<$ts>,)*) {
@@ -240,11 +508,12 @@ macro_rules! tuple {
             fn generate(&self, state: &mut State) -> Self::Shrink {
                 let _total = ($(self.$is.weight +)* 0.0).min(f64::MAX);
                 debug_assert!(_total > 0.0 && _total.is_finite());
-                let mut _random = state.random().f64() * _total;
+                let mut _random = state.ratio() * _total;
                 debug_assert!(_random.is_finite());
                 $(
                     let Weight { weight, generator } = &self.$is;
                     if _random < *weight {
+                        hit(self, $is, $c);
                         return orn::$n::Or::$ts(generator.generate(state));
                     } else {
                         _random -= weight;
@@ -256,6 +525,12 @@ macro_rules! tuple {
             fn constant(&self) -> bool {
                 $(self.$is.constant() &&)* true
             }
+
+            fn cardinality(&self) -> Option<u128> {
+                let _sum = Some(0);
+                $(let _sum = cardinality::sum(_sum, self.$is.cardinality());)*
+                _sum
+            }
         }
     };
 }
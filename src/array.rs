@@ -1,5 +1,5 @@
 use crate::{
-    all,
+    all, cardinality,
     generate::{Generate, State},
 };
 use core::array;
@@ -21,4 +21,8 @@ impl<G: Generate + ?Sized, const N: usize> Generate for Array<G, N> {
     fn constant(&self) -> bool {
         N == 0 || self.0.constant()
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        cardinality::power(self.0.cardinality(), N as u32)
+    }
 }
@@ -0,0 +1,298 @@
+//! Generators for the arbitrary precision integers of [`num_bigint`].
+//!
+//! Ranges of [`BigInt`] and [`BigUint`] implement [`Generate`] like the ranges
+//! of primitive integers do. Generated integers grow with the size of the
+//! [`State`] and shrink toward `0` (or the bound of the range that is closest to
+//! `0`). An unbounded side of a range is limited to [`BITS`] bits past `0`.
+//!
+//! ```
+//! use checkito::{bigint::num_bigint::BigInt, *};
+//!
+//! let start = BigInt::from(i128::MAX);
+//! (start.clone()..start * 4).check(|value| value.bits() > 126);
+//! ```
+
+use crate::{
+    generate::{FullGenerate, Generate, State},
+    primitive::{Direction, Shrinker},
+    shrink::Shrink,
+};
+use core::ops::{self, Bound};
+pub use num_bigint;
+use num_bigint::{BigInt, BigUint, Sign};
+
+/// The number of bits of the largest magnitude generated for an unbounded side
+/// of a range.
+pub const BITS: u64 = 256;
+
+trait Integer: Clone + Ord {
+    /// The start of a range with an unbounded start.
+    fn minimum() -> Self;
+    fn zero() -> Self;
+    fn distance(&self, other: &Self) -> BigUint;
+    fn offset(&self, magnitude: &BigUint, negative: bool) -> Self;
+}
+
+impl Integer for BigInt {
+    fn minimum() -> Self {
+        -BigInt::from(limit())
+    }
+
+    fn zero() -> Self {
+        BigInt::default()
+    }
+
+    fn distance(&self, other: &Self) -> BigUint {
+        (self - other).into_parts().1
+    }
+
+    fn offset(&self, magnitude: &BigUint, negative: bool) -> Self {
+        let sign = if negative { Sign::Minus } else { Sign::Plus };
+        self + BigInt::from_biguint(sign, magnitude.clone())
+    }
+}
+
+impl Integer for BigUint {
+    fn minimum() -> Self {
+        BigUint::default()
+    }
+
+    fn zero() -> Self {
+        BigUint::default()
+    }
+
+    fn distance(&self, other: &Self) -> BigUint {
+        if self <= other {
+            other - self
+        } else {
+            self - other
+        }
+    }
+
+    fn offset(&self, magnitude: &BigUint, negative: bool) -> Self {
+        if negative {
+            self - magnitude
+        } else {
+            self + magnitude
+        }
+    }
+}
+
+fn limit() -> BigUint {
+    (BigUint::from(1u8) << BITS) - 1u8
+}
+
+/// - An empty range (0..=0) or invalid range (0..0) will use the `start` value.
+/// - An reversed range will be flipped.
+fn range<T: Integer, R: ops::RangeBounds<T>>(range: &R) -> (T, T) {
+    let start = match range.start_bound() {
+        Bound::Included(bound) => (bound.clone(), false),
+        Bound::Excluded(bound) => (bound.clone(), true),
+        Bound::Unbounded => (T::minimum(), false),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(bound) => (bound.clone(), false),
+        Bound::Excluded(bound) => (bound.clone(), true),
+        Bound::Unbounded => (
+            start.0.clone().max(T::zero()).offset(&limit(), false),
+            false,
+        ),
+    };
+    if start.0 == end.0 {
+        return (start.0.clone(), end.0);
+    }
+    let (mut start, mut end) = if start.0 > end.0 {
+        (end, start)
+    } else {
+        (start, end)
+    };
+    if start.1 {
+        start.0 = start.0.offset(&BigUint::from(1u8), false);
+    }
+    if end.1 {
+        end.0 = end.0.offset(&BigUint::from(1u8), true);
+    }
+    let low = start.0.clone().min(end.0.clone());
+    (low, end.0.max(start.0))
+}
+
+/// Limits `magnitude` to a number of bits that grows with `size`.
+fn shrinked(magnitude: BigUint, size: f64) -> BigUint {
+    let bits = (magnitude.bits() as f64 * size.clamp(0.0, 1.0)) as u64;
+    magnitude.min((BigUint::from(1u8) << bits) - 1u8)
+}
+
+/// Generates a magnitude within `0..=maximum`.
+fn magnitude(state: &mut State, maximum: &BigUint) -> BigUint {
+    let random = state.random();
+    let digits = Iterator::map(0..maximum.bits() / 32 + 2, |_| random.u32(..)).collect();
+    BigUint::new(digits) % (maximum + 1u8)
+}
+
+fn generate<T: Integer>(start: T, end: T, state: &mut State) -> Shrinker<T> {
+    let origin = T::zero().clamp(start.clone(), end.clone());
+    let low = shrinked(start.distance(&origin), state.size());
+    let high = shrinked(origin.distance(&end), state.size());
+    let start = origin.offset(&low, true);
+    let end = origin.offset(&high, false);
+    let item = start.offset(&magnitude(state, &start.distance(&end)), false);
+    Shrinker {
+        start,
+        end,
+        item,
+        direction: Direction::None,
+    }
+}
+
+fn middle<T: Integer>(shrinker: &Shrinker<T>) -> T {
+    let half = shrinker.start.distance(&shrinker.end) >> 1;
+    shrinker.start.offset(&half, false)
+}
+
+fn shrink<T: Integer>(shrinker: &mut Shrinker<T>) -> Option<Shrinker<T>> {
+    // Never change `shrinker.item` to preserve coherence in calls to `item()`.
+    match shrinker.direction {
+        Direction::None if shrinker.item >= T::zero() => {
+            shrinker.start = shrinker.start.clone().max(T::zero());
+            if shrinker.start == shrinker.item {
+                None
+            } else {
+                shrinker.direction = Direction::High;
+                shrinker.end = shrinker.item.clone();
+                Some(Shrinker {
+                    direction: shrinker.direction,
+                    start: shrinker.start.clone(),
+                    end: shrinker.start.clone(),
+                    item: shrinker.start.clone(),
+                })
+            }
+        }
+        Direction::None => {
+            shrinker.end = shrinker.end.clone().min(T::zero());
+            if shrinker.end == shrinker.item {
+                None
+            } else {
+                shrinker.direction = Direction::Low;
+                shrinker.start = shrinker.item.clone();
+                Some(Shrinker {
+                    direction: shrinker.direction,
+                    start: shrinker.end.clone(),
+                    end: shrinker.end.clone(),
+                    item: shrinker.end.clone(),
+                })
+            }
+        }
+        Direction::Low => {
+            let middle = middle(shrinker);
+            if middle == shrinker.start || middle == shrinker.end {
+                None
+            } else {
+                let mut candidate = shrinker.clone();
+                candidate.start = middle.clone();
+                candidate.item = middle.clone();
+                shrinker.end = middle;
+                Some(candidate)
+            }
+        }
+        Direction::High => {
+            let middle = middle(shrinker);
+            if middle == shrinker.start || middle == shrinker.end {
+                None
+            } else {
+                let mut candidate = shrinker.clone();
+                candidate.end = middle.clone();
+                candidate.item = middle.clone();
+                shrinker.start = middle;
+                Some(candidate)
+            }
+        }
+    }
+}
+
+macro_rules! range {
+    ($t:ident, $r:ty) => {
+        impl Generate for $r {
+            type Item = $t;
+            type Shrink = Shrinker<$t>;
+
+            fn generate(&self, state: &mut State) -> Self::Shrink {
+                let (start, end) = range(self);
+                generate(start, end, state)
+            }
+
+            fn constant(&self) -> bool {
+                let (start, end) = range(self);
+                start == end
+            }
+
+            fn cardinality(&self) -> Option<u128> {
+                let (start, end) = range(self);
+                u128::try_from(start.distance(&end)).ok()?.checked_add(1)
+            }
+        }
+    };
+}
+
+macro_rules! integer {
+    ($t:ident) => {
+        impl FullGenerate for $t {
+            type Generator = ops::RangeInclusive<$t>;
+            type Item = $t;
+
+            /// Integers with a magnitude of at most [`BITS`] bits.
+            fn generator() -> Self::Generator {
+                $t::minimum()..=$t::zero().offset(&limit(), false)
+            }
+        }
+
+        impl Generate for $t {
+            type Item = Self;
+            type Shrink = Self;
+
+            fn generate(&self, _: &mut State) -> Self::Shrink {
+                self.clone()
+            }
+
+            fn constant(&self) -> bool {
+                true
+            }
+
+            fn cardinality(&self) -> Option<u128> {
+                Some(1)
+            }
+        }
+
+        impl Shrink for $t {
+            type Item = Self;
+
+            fn item(&self) -> Self::Item {
+                self.clone()
+            }
+
+            fn shrink(&mut self) -> Option<Self> {
+                None
+            }
+        }
+
+        impl Shrink for Shrinker<$t> {
+            type Item = $t;
+
+            fn item(&self) -> Self::Item {
+                self.item.clone()
+            }
+
+            fn shrink(&mut self) -> Option<Self> {
+                shrink(self)
+            }
+        }
+
+        range!($t, ops::Range<$t>);
+        range!($t, ops::RangeInclusive<$t>);
+        range!($t, ops::RangeFrom<$t>);
+        range!($t, ops::RangeTo<$t>);
+        range!($t, ops::RangeToInclusive<$t>);
+    };
+}
+
+integer!(BigInt);
+integer!(BigUint);
@@ -0,0 +1,80 @@
+//! Prints samples of a generator expression (see [`checkito::explore`]).
+//!
+//! ```text
+//! explore [--count <count>] [--size <size>] [--seed <seed>] <expression>
+//! explore --list
+//! ```
+
+use checkito::{explore::Explorer, *};
+use std::{env, process::ExitCode, str::FromStr};
+
+const USAGE: &str = "usage: explore [--count <count>] [--size <size>] [--seed <seed>] <expression>
+       explore --list";
+
+fn value<T: FromStr>(arguments: &mut env::Args, name: &str) -> Result<T, String> {
+    arguments
+        .next()
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| format!("invalid value for `{name}`"))
+}
+
+fn run() -> Result<(), String> {
+    let explorer = Explorer::new();
+    let mut arguments = env::args();
+    arguments.next();
+    let mut count = 10;
+    let mut size = None;
+    let mut seed = None;
+    let mut expression = None;
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--count" => count = value(&mut arguments, "--count")?,
+            "--size" => size = Some(value::<f64>(&mut arguments, "--size")?),
+            "--seed" => seed = Some(value(&mut arguments, "--seed")?),
+            "--list" => {
+                for name in explorer.names() {
+                    println!("{name}");
+                }
+                return Ok(());
+            }
+            "--help" | "-h" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            _ if expression.is_none() => expression = Some(argument),
+            _ => return Err(format!("unexpected argument `{argument}`")),
+        }
+    }
+
+    let expression = expression.ok_or_else(|| USAGE.to_string())?;
+    let generator = explorer.parse(&expression).map_err(|error| {
+        format!(
+            "{error}\n  {expression}\n  {:>width$}",
+            "^",
+            width = error.offset + 1
+        )
+    })?;
+    let mut sampler = generator.sampler();
+    sampler.count = count;
+    if let Some(size) = size {
+        let size = size.clamp(0.0, 1.0);
+        sampler.size = size..size;
+    }
+    if let Some(seed) = seed {
+        sampler.seed = seed;
+    }
+    for item in sampler.samples() {
+        println!("{item}");
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -5,9 +5,33 @@ use crate::{
 use core::{any::Any, fmt};
 
 pub struct Boxed<I> {
-    generator: Box<dyn Any>,
-    generate: fn(&dyn Any, &mut State) -> Shrinker<I>,
-    constant: fn(&dyn Any) -> bool,
+    generator: Box<dyn DynGenerate<I>>,
+}
+
+/// An object safe version of [`Generate`] that is implemented for all
+/// `'static` generators. This allows to store heterogeneous generators that
+/// produce the same item type behind a `Box<dyn DynGenerate<T>>` (for example,
+/// in a map of generators keyed by name).
+///
+/// `dyn DynGenerate<T>` implements [`Generate`] itself.
+pub trait DynGenerate<T> {
+    /// Same as [`Generate::generate`] but with a type-erased shrinker.
+    fn dyn_generate(&self, state: &mut State) -> Shrinker<T>;
+    /// Same as [`Generate::constant`].
+    fn dyn_constant(&self) -> bool;
+    /// Same as [`Generate::cardinality`].
+    fn dyn_cardinality(&self) -> Option<u128> {
+        None
+    }
+    fn as_any(&self) -> &dyn Any;
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+/// A generator built from a generation function and a declared cardinality
+/// (see [`Boxed::from_parts`]).
+struct Parts<F> {
+    generate: F,
+    cardinality: Option<u128>,
 }
 
 pub struct Shrinker<I> {
@@ -15,11 +39,14 @@ pub struct Shrinker<I> {
     clone: fn(&dyn Any) -> Box<dyn Any>,
     item: fn(&dyn Any) -> I,
     shrink: fn(&mut dyn Any) -> Option<Box<dyn Any>>,
+    release: fn(&mut dyn Any) -> bool,
 }
 
 impl<I> fmt::Debug for Boxed<I> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_tuple("Boxed").field(&self.generator).finish()
+        f.debug_tuple("Boxed")
+            .field(&DynGenerate::as_any(self.generator.as_ref()))
+            .finish()
     }
 }
 
@@ -29,30 +56,105 @@ impl<I> fmt::Debug for Shrinker<I> {
     }
 }
 
+impl<G: Generate + 'static> DynGenerate<G::Item> for G
+where
+    G::Shrink: 'static,
+{
+    fn dyn_generate(&self, state: &mut State) -> Shrinker<G::Item> {
+        Shrinker::new(Box::new(self.generate(state)))
+    }
+
+    fn dyn_constant(&self) -> bool {
+        self.constant()
+    }
+
+    fn dyn_cardinality(&self) -> Option<u128> {
+        self.cardinality()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl<T> Generate for dyn DynGenerate<T> {
+    type Item = T;
+    type Shrink = Shrinker<T>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        self.dyn_generate(state)
+    }
+
+    fn constant(&self) -> bool {
+        self.dyn_constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.dyn_cardinality()
+    }
+}
+
 impl<I> Generate for Boxed<I> {
     type Item = I;
     type Shrink = Shrinker<I>;
 
     fn generate(&self, state: &mut State) -> Self::Shrink {
-        (self.generate)(self.generator.as_ref(), state)
+        DynGenerate::dyn_generate(self.generator.as_ref(), state)
+    }
+
+    fn constant(&self) -> bool {
+        DynGenerate::dyn_constant(self.generator.as_ref())
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        DynGenerate::dyn_cardinality(self.generator.as_ref())
+    }
+}
+
+impl<S: Shrink, F: Fn(&mut State) -> S> Generate for Parts<F> {
+    type Item = S::Item;
+    type Shrink = S;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        (self.generate)(state)
     }
 
     fn constant(&self) -> bool {
-        (self.constant)(self.generator.as_ref())
+        self.cardinality == Some(1)
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.cardinality
     }
 }
 
 impl<I> Boxed<I> {
+    /// Builds a boxed generator from a `generate` function that declares the
+    /// `cardinality` of its items, such that a custom generator that is only
+    /// known as a function does not hide its cardinality (`None` meaning
+    /// unknown) from the checks that rely on it.
+    ///
+    /// A cardinality of `Some(1)` declares a constant generator.
+    pub fn from_parts<S: Shrink<Item = I> + 'static, F: Fn(&mut State) -> S + 'static>(
+        generate: F,
+        cardinality: Option<u128>,
+    ) -> Self {
+        Self::new(Box::new(Parts {
+            generate,
+            cardinality,
+        }))
+    }
+
     #[rustversion::since(1.75)]
     pub(crate) const fn new<G: Generate<Item = I> + 'static>(generator: Box<G>) -> Self
     where
         G::Shrink: 'static,
     {
-        Self {
-            generator,
-            generate: generate::<G>,
-            constant: constant::<G>,
-        }
+        Self { generator }
     }
 
     #[rustversion::before(1.75)]
@@ -60,25 +162,28 @@ impl<I> Boxed<I> {
     where
         G::Shrink: 'static,
     {
-        Self {
-            generator,
-            generate: generate::<G>,
-            constant: constant::<G>,
-        }
+        Self { generator }
     }
 
     pub fn downcast<G: Generate + 'static>(self) -> Result<Box<G>, Self> {
-        match self.generator.downcast::<G>() {
-            Ok(generator) => Ok(generator),
-            Err(generator) => Err(Self {
-                generator,
-                generate: self.generate,
-                constant: self.constant,
-            }),
+        if DynGenerate::as_any(self.generator.as_ref()).is::<G>() {
+            Ok(self
+                .generator
+                .into_any()
+                .downcast::<G>()
+                .expect("type was checked"))
+        } else {
+            Err(self)
         }
     }
 }
 
+impl<I> From<Box<dyn DynGenerate<I>>> for Boxed<I> {
+    fn from(generator: Box<dyn DynGenerate<I>>) -> Self {
+        Self { generator }
+    }
+}
+
 impl<I> Shrinker<I> {
     pub(crate) fn new<S: Shrink<Item = I> + 'static>(shrinker: Box<S>) -> Self {
         Self {
@@ -86,6 +191,7 @@ impl<I> Shrinker<I> {
             clone: clone::<S>,
             item: item::<S>,
             shrink: shrink::<S>,
+            release: release::<S>,
         }
     }
 
@@ -97,6 +203,7 @@ impl<I> Shrinker<I> {
                 clone: self.clone,
                 item: self.item,
                 shrink: self.shrink,
+                release: self.release,
             }),
         }
     }
@@ -109,6 +216,7 @@ impl<I> Clone for Shrinker<I> {
             clone: self.clone,
             item: self.item,
             shrink: self.shrink,
+            release: self.release,
         }
     }
 }
@@ -126,21 +234,13 @@ impl<I> Shrink for Shrinker<I> {
             clone: self.clone,
             item: self.item,
             shrink: self.shrink,
+            release: self.release,
         })
     }
-}
 
-fn generate<G: Generate + 'static>(generator: &dyn Any, state: &mut State) -> Shrinker<G::Item>
-where
-    G::Shrink: 'static,
-{
-    Shrinker::new(Box::new(
-        generator.downcast_ref::<G>().unwrap().generate(state),
-    ))
-}
-
-fn constant<G: Generate + 'static>(generator: &dyn Any) -> bool {
-    generator.downcast_ref::<G>().unwrap().constant()
+    fn release(&mut self) -> bool {
+        (self.release)(self.shrinker.as_mut())
+    }
 }
 
 fn clone<S: Shrink + 'static>(shrinker: &dyn Any) -> Box<dyn Any> {
@@ -154,3 +254,7 @@ fn item<S: Shrink + 'static>(shrinker: &dyn Any) -> S::Item {
 fn shrink<S: Shrink + 'static>(shrinker: &mut dyn Any) -> Option<Box<dyn Any>> {
     Some(Box::new(shrinker.downcast_mut::<S>().unwrap().shrink()?))
 }
+
+fn release<S: Shrink + 'static>(shrinker: &mut dyn Any) -> bool {
+    shrinker.downcast_mut::<S>().unwrap().release()
+}
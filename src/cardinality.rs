@@ -0,0 +1,101 @@
+//! Overflow-safe arithmetic over generator cardinalities.
+//!
+//! A cardinality is the number of distinct items that a generator may produce.
+//! It is represented as an `Option<u128>` where `None` means that the
+//! cardinality is unknown or that it overflowed a `u128`. All combinators in
+//! this module propagate `None` and never silently wrap or saturate, such that a
+//! `Some` cardinality can always be trusted as an upper bound.
+//!
+//! Since a cardinality never saturates, the checker only warns about the
+//! opposite case: a `Some` cardinality that is smaller than the number of
+//! checks of a run (see [`crate::check::Checker::saturation`] and
+//! [`crate::check::report::Reporter::saturated`]).
+
+/// Cardinality of a generator that produces any combination of items from
+/// generators with cardinalities `left` and `right` (such as a tuple).
+pub const fn product(left: Option<u128>, right: Option<u128>) -> Option<u128> {
+    match (left, right) {
+        (Some(0), _) | (_, Some(0)) => Some(0),
+        (Some(left), Some(right)) => left.checked_mul(right),
+        _ => None,
+    }
+}
+
+/// Cardinality of a generator that produces an item from either the generator
+/// with cardinality `left` or the one with cardinality `right` (such as
+/// [`crate::any::Any`]).
+pub const fn sum(left: Option<u128>, right: Option<u128>) -> Option<u128> {
+    match (left, right) {
+        (Some(left), Some(right)) => left.checked_add(right),
+        _ => None,
+    }
+}
+
+/// Cardinality of a generator that produces `exponent` items from a generator
+/// with cardinality `base` (such as an array).
+pub const fn power(base: Option<u128>, exponent: u32) -> Option<u128> {
+    match base {
+        Some(0 | 1) if exponent > 0 => base,
+        Some(_) if exponent == 0 => Some(1),
+        Some(base) => base.checked_pow(exponent),
+        None if exponent == 0 => Some(1),
+        None => None,
+    }
+}
+
+/// Cardinality of a generator that produces `count` distinct items out of
+/// `length` items (such as [`crate::choose::Choose`]), which is the binomial
+/// coefficient of `length` and `count`.
+pub const fn binomial(length: usize, count: usize) -> Option<u128> {
+    if count > length {
+        return Some(0);
+    }
+    let count = if count > length - count {
+        length - count
+    } else {
+        count
+    };
+    // Each partial product is itself a binomial coefficient, so the division is exact.
+    let mut total: u128 = 1;
+    let mut index = 0;
+    while index < count {
+        total = match total.checked_mul((length - index) as u128) {
+            Some(total) => total / (index as u128 + 1),
+            None => return None,
+        };
+        index += 1;
+    }
+    Some(total)
+}
+
+/// Cardinality of a generator that produces a sequence of length within
+/// `minimum..=maximum` from a generator with cardinality `base` (such as
+/// [`crate::collect::Collect`]).
+pub const fn series(base: Option<u128>, minimum: usize, maximum: usize) -> Option<u128> {
+    if minimum > maximum {
+        return Some(0);
+    }
+    match base {
+        Some(0) => Some(if minimum == 0 { 1 } else { 0 }),
+        Some(1) => Some((maximum - minimum) as u128 + 1),
+        Some(_) => {
+            // Terms at least double at each length, so this loop overflows after at
+            // most 128 iterations.
+            let mut total = Some(0);
+            let mut length = minimum;
+            while length <= maximum {
+                if length > u32::MAX as usize {
+                    return None;
+                }
+                total = sum(total, power(base, length as u32));
+                if total.is_none() {
+                    return None;
+                }
+                length += 1;
+            }
+            total
+        }
+        None if maximum == 0 => Some(1),
+        None => None,
+    }
+}
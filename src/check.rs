@@ -1,19 +1,36 @@
 use crate::{
-    generate::{Generate, State},
+    any::{self, Hits},
+    boxed::DynGenerate,
+    generate::{self, FullGenerate, Generate, State},
     nudge::Nudge,
-    prove::Prove,
+    prove::{All, Differential, Divergence, NamedProperty, Property, Prove},
     random,
     shrink::Shrink,
 };
+use clock::Instant;
 use core::{
+    cell::Cell,
+    cmp::Reverse,
     fmt,
     mem::replace,
     ops::{
         Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
     },
     panic::AssertUnwindSafe,
+    str::FromStr,
+};
+use std::{
+    any::Any,
+    borrow::Cow,
+    error,
+    panic::catch_unwind,
+    result,
+    sync::{
+        Arc, Mutex, PoisonError,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
 };
-use std::{any::Any, borrow::Cow, error, panic::catch_unwind, result};
 
 #[derive(Clone, Copy, Debug)]
 pub struct Sizes {
@@ -21,6 +38,10 @@ pub struct Sizes {
     end: f64,
 }
 
+/// A callback that receives the progress of the generation process as
+/// `(done, total, passes, fails, elapsed)` (see [`Generates::progress`]).
+pub type Progress = fn(usize, usize, usize, usize, Duration);
+
 /// Bounds the generation process.
 #[derive(Clone, Debug)]
 pub struct Generates {
@@ -38,10 +59,54 @@ pub struct Generates {
     ///
     /// Defaults to `1000`.
     pub count: usize,
+    /// Number of times that the whole generation process is repeated, each
+    /// time with a different seed derived from [`Generates::seed`]. A failing
+    /// run does not prevent the following runs, such that all failures are
+    /// reported.
+    ///
+    /// Defaults to `1`.
+    pub runs: usize,
     /// Whether or not the [`Checks`] iterator will yield generation items.
     ///
     /// Defaults to `true`.
     pub items: bool,
+    /// How the items of a run cover the value space of the generator (see
+    /// [`Modes`]).
+    ///
+    /// Defaults to [`Modes::Random`].
+    pub mode: Modes,
+    /// How the size of the generated items progresses within
+    /// [`Generates::size`] over the course of a run.
+    ///
+    /// Defaults to [`Ramp::Ascending`].
+    pub ramp: Ramp,
+    /// A callback that is called periodically during the generation process
+    /// (at most 100 times per run and once more when it ends) with the number
+    /// of generated items, the total number of items to generate, the number
+    /// of items that passed and failed their check in the current run and the
+    /// elapsed time.
+    ///
+    /// Defaults to `None`.
+    pub progress: Option<Progress>,
+    /// Maximum ratio of the [`Generates::count`] items of a run that may be
+    /// skipped with [`crate::skip!`]. A run that skips more items than that
+    /// ends with a [`Cause::Vacuous`] failure, since a property that is rarely
+    /// checked passes vacuously.
+    ///
+    /// Defaults to `0.5`.
+    pub skips: f64,
+    /// Whether or not the [`Checks`] count the branches chosen by the
+    /// [`crate::any()`] choices of the generated items (see
+    /// [`Checks::hits`]). A branch table is reported at the end of the checks
+    /// such that skewed weights or starved branches become visible.
+    ///
+    /// Defaults to `false`.
+    pub hits: bool,
+    /// A token that cancels the [`Checks`] once it is cancelled (see
+    /// [`Checks::cancel_on`]).
+    ///
+    /// Defaults to `None`.
+    pub cancel: Option<Cancel>,
 }
 
 /// Bounds the shrinking process.
@@ -61,8 +126,132 @@ pub struct Shrinks {
     ///
     /// Defaults to `true`.
     pub errors: bool,
+    /// How the shrinking process explores the candidates produced by
+    /// [`Shrink::shrink`].
+    ///
+    /// Defaults to [`Strategy::Greedy`].
+    pub strategy: Strategy,
+    /// Number of times a generated item that has failed a check is checked
+    /// again before shrinking it. If any of these checks passes, the failure is
+    /// nondeterministic and a [`Result::Flaky`] is produced instead of
+    /// shrinking it into a misleading minimal item.
+    ///
+    /// This setting lives with the shrinking settings (as `shrink.confirm` in
+    /// the `#[check]` macro) rather than in a separate `fail` group since the
+    /// confirmation checks run between the generation of a failing item and
+    /// its shrinking, and decide whether it is shrunk at all.
+    ///
+    /// Defaults to `0`.
+    pub confirm: usize,
+    /// Whether or not the `#[check]` macro prints the [`Fail::search`]
+    /// statistics of a failure, which helps to understand a poor minimization
+    /// without going through the verbose output.
+    ///
+    /// Defaults to `false`.
+    pub explain: bool,
+}
+
+/// Ways in which the items of a run cover the value space of the generator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Modes {
+    /// Samples every item at random.
+    Random,
+    /// Partitions the value space in [`Generates::count`] strata such that
+    /// each generated item samples a different stratum. This gives a better
+    /// coverage than pure random sampling for medium-sized domains.
+    ///
+    /// Only the first draw of an item is stratified, that is its first
+    /// weighted choice or integer or floating point range. The following
+    /// draws (such as the other elements of a collection) are random. Since
+    /// strata are visited in a permuted order, weighted choices are
+    /// interleaved proportionally to their weights even when the run stops
+    /// early.
+    Stratified,
+}
+
+/// Progressions of the size of the generated items over the course of a run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ramp {
+    /// Starts with small items and gradually grows them such that the last
+    /// quarter of the items is fully sized.
+    Ascending,
+    /// Starts with fully sized items (the first quarter of them) and gradually
+    /// shrinks them, which finds bugs that require large items faster.
+    Descending,
+    /// Generates all items with the end of [`Generates::size`].
+    Constant,
+}
+
+/// Shrinking strategies that trade minimality of the counterexample for speed.
+///
+/// Regardless of the strategy, the shrinking process never evaluates the
+/// property more than [`Shrinks::count`] times.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// Accepts the first shrunk candidate that fails the check and stops as
+    /// soon as a candidate can not be shrunk further.
+    Greedy,
+    /// Explores the shrink tree by backtracking to previously accepted
+    /// candidates and keeps the best counterexample found within the given
+    /// number of property evaluations.
+    Bounded(usize),
+    /// Explores the full shrink tree by backtracking to previously accepted
+    /// candidates and keeps the best counterexample found.
+    Exhaustive,
+}
+
+/// This iterator yields up to a number of distinct minimal counterexamples of a
+/// property (see [`Checker::failures`]).
+pub struct Failures<'a, G: Generate + ?Sized, E, F> {
+    checks: Checks<'a, G, E, F>,
+    items: Vec<G::Item>,
+    count: usize,
+}
+
+/// A group of failures that share the same normalized message, as produced by
+/// [`Failures::triage`].
+#[derive(Clone, Debug)]
+pub struct Bucket<T, E> {
+    /// The message of the failures with its numbers replaced by `#`.
+    pub key: String,
+    /// The failure of the bucket with the shortest `Debug` representation.
+    pub fail: Fail<T, E>,
+    /// The number of failures in the bucket.
+    pub count: usize,
+}
+
+/// Deliberately wrong inputs that [`Checker::sanity`] runs a property
+/// against. Only the items that the checked generator can not produce are
+/// used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mutation {
+    /// The smallest item of the item type (such as `0`, `false` or an empty
+    /// collection).
+    Zero,
+    /// Items of the item type generated with the largest size (such as extreme
+    /// numbers or long collections).
+    Maximal,
+    /// Items of the item type generated with the sizes of the checker.
+    Outside,
+}
+
+/// A generator that has fewer distinct items than the number of items that a
+/// [`Checker`] generates, such that most checks are duplicates (see
+/// [`Checker::saturation`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Saturation {
+    /// The number of distinct items of the generator.
+    pub cardinality: u128,
+    /// The number of items that the checker generates in a run.
+    pub count: usize,
 }
 
+/// A token that cooperatively cancels the [`Checks`] that observe it (see
+/// [`Checks::cancel_on`]). Clones share the same state, such that a surrounding
+/// test framework may keep a clone and cancel a run when its test times out.
+#[derive(Clone, Debug, Default)]
+pub struct Cancel(Arc<AtomicBool>);
+
 /// The [`Checker`] structure holds a reference to a [`Generate`] instance and
 /// some configuration options for the checking and shrinking processes.
 #[derive(Debug)]
@@ -80,6 +269,9 @@ pub struct Checker<'a, G: ?Sized> {
 /// - The iterator initially starts in a generate phase where it generates items
 ///   and it runs check against them.
 /// - If a check passes, a [`Result::Pass`] is produced.
+/// - If a check fails and [`Shrinks::confirm`] is set, the item is checked again
+///   that many times. If any of these checks passes, a [`Result::Flaky`] is
+///   produced and the run ends.
 /// - If a check fails, the iterator enters the shrinking phase.
 /// - When shrinking, the iterator tries to repeatedly shrink the previous item
 ///   and runs the check against it.
@@ -98,13 +290,64 @@ pub struct Checker<'a, G: ?Sized> {
 /// - Yield only [`Result::Pass`] results if [`Generates::items`] is set to
 ///   `true` and all checks passed.
 /// - Never yield a [`Result::Pass`] after a check has failed.
-/// - Always yield a single final result of [`Result::Fail`] if a check failed.
+/// - Always yield a single final result of [`Result::Fail`] or
+///   [`Result::Flaky`] per run (see [`Generates::runs`]) in which a check
+///   failed.
 /// - Yield at most a single [`Result::Pass`] result if [`Generate::constant`]
 ///   returns `true`.
 pub struct Checks<'a, G: Generate + ?Sized, E, F> {
     checker: Checker<'a, G>,
     machine: Machine<G::Shrink, E>,
     check: F,
+    start: Instant,
+    run: usize,
+    generation: Duration,
+    duration: Duration,
+    /// The number of items skipped in the current run.
+    skips: usize,
+    /// The number of items that passed in the current run.
+    passes: usize,
+    /// The number of items that failed in the current run, including the ones
+    /// that [`Failures`] resumed after.
+    fails: usize,
+    /// The number of items skipped over all runs.
+    skipped: usize,
+    /// The branches chosen over all runs, if [`Generates::hits`] is set.
+    hits: Vec<Hits>,
+}
+
+impl Generates {
+    /// Creates the state that generates the item at `index` in a run of
+    /// `count` items.
+    pub(crate) fn state(&self, index: usize, count: usize) -> State {
+        let mut state = State::new(index, count, self.size, self.seed);
+        match self.ramp {
+            Ramp::Ascending => {}
+            Ramp::Descending => {
+                state.size = generate::size(count.saturating_sub(index + 1), count, self.size)
+            }
+            Ramp::Constant => state.size = Sizes::from(self.size.end()),
+        }
+        if self.mode == Modes::Stratified {
+            state = state.stratified(index, count);
+        }
+        state
+    }
+}
+
+impl FromStr for Ramp {
+    type Err = String;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "ascending" => Ok(Ramp::Ascending),
+            "descending" => Ok(Ramp::Descending),
+            "constant" => Ok(Ramp::Constant),
+            value => Err(format!(
+                "invalid ramp '{value}', must be one of 'ascending', 'descending' or 'constant'"
+            )),
+        }
+    }
 }
 
 impl Sizes {
@@ -161,6 +404,7 @@ range!(RangeTo);
 range!(RangeToInclusive);
 range!(RangeFrom);
 
+#[allow(clippy::large_enum_variant)]
 enum Machine<S, E> {
     Generate {
         index: usize,
@@ -169,17 +413,37 @@ enum Machine<S, E> {
         indices: (usize, usize),
         state: State,
         shrinker: S,
-        cause: Cause<E>,
+        cause: Option<Failure<E>>,
+        depth: usize,
+        parents: Vec<(usize, S)>,
+        best: Option<(usize, S, Failure<E>)>,
+        search: Search,
     },
     Done,
 }
 
+/// Keeps the deepest failing shrinker (the one that went through the most
+/// accepted shrinks) as the best counterexample.
+fn deepest<S, C>(best: Option<(usize, S, C)>, current: (usize, S, Option<C>)) -> (usize, S, C) {
+    match (best, current) {
+        (Some(best), (depth, shrinker, Some(cause))) if depth > best.0 => (depth, shrinker, cause),
+        (Some(best), _) => best,
+        (None, (depth, shrinker, Some(cause))) => (depth, shrinker, cause),
+        (None, (_, _, None)) => {
+            unreachable!("a shrinker without a cause is always a parent of a deeper best shrinker")
+        }
+    }
+}
+
 pub trait Check: Generate {
-    fn checker(&self) -> Checker<Self> {
+    fn checker(&self) -> Checker<'_, Self> {
         Checker::new(self, random::seed())
     }
 
-    fn checks<P: Prove, F: FnMut(Self::Item) -> P>(&self, check: F) -> Checks<Self, P::Error, F> {
+    fn checks<P: Prove, F: FnMut(Self::Item) -> P>(
+        &self,
+        check: F,
+    ) -> Checks<'_, Self, P::Error, F> {
         self.checker().checks(check)
     }
 
@@ -194,11 +458,75 @@ pub trait Check: Generate {
         match checker.checks(check).last()? {
             Result::Pass(_) => None,
             Result::Fail(fail) => Some(fail),
+            Result::Skip(_) => None,
+            // A flaky failure is still a failure, even if it could not be shrunk.
+            Result::Flaky(flaky) => Some(flaky.fail),
             Result::Shrink(_) | Result::Shrunk(_) => {
                 unreachable!("it is invalid for the `Checks` iterator to end on a shrinking result")
             }
         }
     }
+
+    /// Checks that a `candidate` implementation produces the same outputs as a
+    /// `reference` implementation for every generated item. On disagreement,
+    /// the item is shrunk to a minimal diverging input and the [`Divergence`]
+    /// holds both outputs.
+    ///
+    /// ```
+    /// use checkito::*;
+    ///
+    /// let fail = (0..100u32)
+    ///     .differential(|value| value * 2, |value| value << 1 | u32::from(value > 50))
+    ///     .unwrap();
+    /// assert_eq!(fail.item, 51);
+    /// ```
+    fn differential<O: PartialEq, R: FnMut(Self::Item) -> O, C: FnMut(Self::Item) -> O>(
+        &self,
+        mut reference: R,
+        mut candidate: C,
+    ) -> Option<Fail<Self::Item, Divergence<O>>>
+    where
+        Self::Item: Clone,
+    {
+        self.check(|item: Self::Item| Differential::new(reference(item.clone()), candidate(item)))
+    }
+
+    /// Checks many named `properties` against each generated item such that
+    /// expensive items are generated only once. Once a property fails, the
+    /// item is shrunk against that property only and the [`Property`] error
+    /// identifies it.
+    ///
+    /// ```
+    /// use checkito::*;
+    ///
+    /// let fail = (0..100u32)
+    ///     .check_all([
+    ///         ("bounded", &|value: &u32| *value < 100),
+    ///         ("small", &|value: &u32| *value < 10),
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(fail.item, 10);
+    /// ```
+    fn check_all<P: Prove, const N: usize>(
+        &self,
+        properties: [NamedProperty<'_, Self::Item, P>; N],
+    ) -> Option<Fail<Self::Item, Property<P::Error>>> {
+        // Holds the property being proven such that a panicking property is
+        // also the only one proven while shrinking.
+        let failed = Cell::new(None);
+        self.check(|item: Self::Item| {
+            let fixed = failed.get();
+            for index in fixed.map_or(0..N, |index| index..index + 1) {
+                let (name, property) = properties[index];
+                failed.set(Some(index));
+                if let Err(error) = property(&item).prove() {
+                    return All::new(Err(Property { index, name, error }));
+                }
+            }
+            failed.set(fixed);
+            All::new(Ok(()))
+        })
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -211,6 +539,11 @@ pub enum Result<T, P: Prove> {
     Shrunk(Fail<T, P::Error>),
     /// The last generated of shrunk item that failed the check.
     Fail(Fail<T, P::Error>),
+    /// A generated item that failed the check, but did not fail it
+    /// consistently when checked again (see [`Shrinks::confirm`]).
+    Flaky(Flaky<T, P::Error>),
+    /// An item was generated and its check was skipped with [`crate::skip!`].
+    Skip(Skip<T>),
 }
 
 #[derive(Clone, Debug)]
@@ -222,6 +555,10 @@ pub struct Pass<T, P> {
     pub shrinks: usize,
     /// The generator state that produced the item.
     pub state: State,
+    /// The time spent generating (or shrinking) the item.
+    pub generation: Duration,
+    /// The time spent checking the item.
+    pub duration: Duration,
 }
 
 #[derive(Clone, Debug)]
@@ -229,10 +566,90 @@ pub struct Pass<T, P> {
 pub struct Fail<T, E> {
     pub item: T,
     pub cause: Cause<E>,
+    /// The breadcrumbs that the check recorded for the item (see
+    /// [`crate::report`]).
+    pub context: Vec<Cow<'static, str>>,
     pub generates: usize,
     pub shrinks: usize,
     /// The generator state that caused the error.
     pub state: State,
+    /// The time spent generating (or shrinking) the item. For a
+    /// [`Result::Fail`], the total time spent generating and shrinking items
+    /// during the run.
+    pub generation: Duration,
+    /// The time spent checking the item. For a [`Result::Fail`], the total time
+    /// spent checking items during the run.
+    pub duration: Duration,
+    /// The progress of the shrinking process when the failure was produced.
+    pub search: Search,
+}
+
+/// Statistics of the exploration of the shrink tree of a failing item.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Search {
+    /// Number of shrunk candidates that failed the check and thus were accepted.
+    pub accepted: usize,
+    /// Number of shrunk candidates that passed the check and thus were rejected.
+    pub rejected: usize,
+    /// Number of times the search returned to a previously accepted candidate
+    /// (see [`Strategy`]).
+    pub backtracks: usize,
+    /// Why the search stopped. Only set for a [`Result::Fail`] that went
+    /// through the shrinking process.
+    pub stop: Option<Stop>,
+    /// The statistics of each phase of the search, which add up to the
+    /// statistics above. The first phase shrinks the item and each following
+    /// phase shrinks the components that were kept until last once the rest
+    /// of the item is minimal (see [`Generate::keep_until_last`]).
+    pub phases: Vec<Phase>,
+}
+
+/// Statistics of a phase of the exploration of the shrink tree of a failing
+/// item (see [`Search::phases`]).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Phase {
+    /// Number of shrunk candidates of the phase that were accepted.
+    pub accepted: usize,
+    /// Number of shrunk candidates of the phase that were rejected.
+    pub rejected: usize,
+    /// Number of backtracks of the phase.
+    pub backtracks: usize,
+}
+
+/// The reasons for which the shrinking process stops.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stop {
+    /// The budget of [`Shrinks::count`] or [`Strategy::Bounded`] was spent.
+    Budget,
+    /// The greedy search reached an item that has no failing candidate.
+    Fixpoint,
+    /// The backtracking search explored all of the candidates.
+    Exhausted,
+    /// The [`Checks`] were cancelled (see [`Checks::cancel_on`]).
+    Cancelled,
+}
+
+#[derive(Clone, Debug)]
+/// A structure that represents a skipped check (see [`crate::skip!`]).
+pub struct Skip<T> {
+    pub item: T,
+    /// The reason given to [`crate::skip!`].
+    pub reason: Cow<'static, str>,
+    pub generates: usize,
+    /// The generator state that produced the item.
+    pub state: State,
+}
+
+#[derive(Clone, Debug)]
+/// A structure that represents a failed check that could not be reproduced
+/// consistently.
+pub struct Flaky<T, E> {
+    /// The original failure.
+    pub fail: Fail<T, E>,
+    /// The number of confirmation checks that passed.
+    pub passes: usize,
+    /// The number of confirmation checks that failed.
+    pub fails: usize,
 }
 
 /// The cause of a check failure.
@@ -243,10 +660,35 @@ pub enum Cause<E> {
     Disprove(E),
     /// A `Panic` cause is produced when a check panics during its evaluation.
     /// The message associated with the panic is included if it can be casted to
-    /// a string.
-    Panic(Option<Cow<'static, str>>),
+    /// a string. The original panic value is preserved in the [`Payload`].
+    Panic(Option<Cow<'static, str>>, Payload),
+    /// A `ResourceExhausted` cause is produced when a check allocates more than
+    /// the budget of the [`crate::allocation`] tracker (only with the
+    /// `allocation` feature).
+    ResourceExhausted {
+        /// The peak number of bytes allocated by the check.
+        allocated: usize,
+        /// The allocation budget in bytes.
+        budget: usize,
+    },
+    /// A `Vacuous` cause is produced when a run skips more items than allowed
+    /// by [`Generates::skips`]. The item of such a failure is the last skipped
+    /// item and it is not shrunk.
+    Vacuous {
+        /// The number of items skipped in the run.
+        skips: usize,
+        /// The number of items generated in the run.
+        count: usize,
+    },
 }
 
+/// A shared handle to the value that a check panicked with (such as the value
+/// passed to [`std::panic::panic_any`]).
+///
+/// Clones of a [`Payload`] refer to the same value and compare equal.
+#[derive(Clone)]
+pub struct Payload(Arc<Mutex<Box<dyn Any + Send>>>);
+
 pub const COUNT: usize = 1000;
 
 impl<G: Generate + ?Sized> Check for G {}
@@ -258,18 +700,94 @@ impl<'a, G: Generate + ?Sized> Checker<'a, G> {
             generate: Generates {
                 items: true,
                 count: COUNT,
+                runs: 1,
                 seed,
                 size: (0.0..=1.0).into(),
+                mode: Modes::Random,
+                ramp: Ramp::Ascending,
+                progress: None,
+                skips: 0.5,
+                hits: false,
+                cancel: None,
             },
             shrink: Shrinks {
                 count: usize::MAX,
                 items: true,
                 errors: true,
+                strategy: Strategy::Greedy,
+                confirm: 0,
+                explain: false,
             },
         }
     }
 }
 
+impl<'a, G: Generate + 'static> Checker<'a, G>
+where
+    G::Shrink: 'static,
+{
+    /// Erases the type of the generator of this checker such that its items
+    /// are generated and shrunk through a [`DynGenerate`] and its boxed
+    /// shrinkers. The check pipeline is then only instantiated once per item
+    /// type rather than once per generator, which reduces the compile time of
+    /// crates with many properties at a small runtime cost. Passing a boxed
+    /// check (such as a `Box<dyn FnMut(T) -> bool>`) also erases its type.
+    ///
+    /// The `#[check]` macro uses this path when `erased = true` is set.
+    ///
+    /// ```
+    /// use checkito::*;
+    ///
+    /// let checker = (0..100u8, 0..100u8).checker().erased();
+    /// let check: Box<dyn FnMut((u8, u8)) -> bool> = Box::new(|(left, right)| left < 100 && right < 100);
+    /// assert!(checker.checks(check).all(|result| result.pass(false).is_some()));
+    /// ```
+    pub fn erased(self) -> Checker<'a, dyn DynGenerate<G::Item>> {
+        Checker {
+            generator: self.generator,
+            generate: self.generate,
+            shrink: self.shrink,
+        }
+    }
+}
+
+impl<G: ?Sized> Checker<'_, G> {
+    /// Sets [`Generates::seed`].
+    #[must_use]
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.generate.seed = seed;
+        self
+    }
+
+    /// Sets [`Generates::count`].
+    #[must_use]
+    pub const fn with_count(mut self, count: usize) -> Self {
+        self.generate.count = count;
+        self
+    }
+
+    /// Sets [`Generates::size`].
+    #[must_use]
+    pub fn with_sizes<S: Into<Sizes>>(mut self, sizes: S) -> Self {
+        self.generate.size = sizes.into();
+        self
+    }
+
+    /// Sets [`Shrinks::count`].
+    #[must_use]
+    pub const fn with_shrink_count(mut self, count: usize) -> Self {
+        self.shrink.count = count;
+        self
+    }
+
+    /// Sets [`Shrinks::strategy`].
+    #[must_use]
+    pub const fn with_strategy(mut self, strategy: Strategy) -> Self {
+        self.shrink.strategy = strategy;
+        self
+    }
+}
+
 impl<G: ?Sized> Clone for Checker<'_, G> {
     fn clone(&self) -> Self {
         Self {
@@ -286,6 +804,232 @@ impl<'a, G: Generate + ?Sized> Checker<'a, G> {
             checker: self.clone(),
             machine: Machine::Generate { index: 0 },
             check,
+            start: Instant::now(),
+            run: 0,
+            generation: Duration::ZERO,
+            duration: Duration::ZERO,
+            skips: 0,
+            passes: 0,
+            fails: 0,
+            skipped: 0,
+            hits: Vec::new(),
+        }
+    }
+
+    /// Same as [`Checker::checks`] but generation resumes after each failure
+    /// (once it is shrunk) instead of ending the run, such that up to `count`
+    /// minimal counterexamples are found in a single run. Counterexamples that
+    /// are equal to a previous one are skipped.
+    ///
+    /// Only [`Result::Fail`] results are yielded.
+    pub fn failures<P: Prove, F: FnMut(G::Item) -> P>(
+        &self,
+        count: usize,
+        check: F,
+    ) -> Failures<'a, G, P::Error, F> {
+        let mut checks = self.checks(check);
+        checks.checker.generate.items = false;
+        checks.checker.shrink.items = false;
+        checks.checker.shrink.errors = false;
+        Failures {
+            checks,
+            items: Vec::new(),
+            count,
+        }
+    }
+
+    /// Checks the property against the items of every [`Mutation`] that the
+    /// generator can not produce and returns the mutations for which it failed.
+    /// Since these items are outside of the domain of the generator, a property
+    /// that passes for all of them is likely vacuous, in which case a warning
+    /// is printed.
+    ///
+    /// The domain of the generator is determined with
+    /// [`Generate::shrink_from`]. Generators that can not rebuild their
+    /// shrinkers, or that produce every item of their type, have no wrong
+    /// items to check, in which case nothing is checked nor printed.
+    ///
+    /// Each mutation generates up to [`Generates::count`] items.
+    ///
+    /// ```
+    /// use checkito::*;
+    ///
+    /// let checker = (0..10u8).checker();
+    /// // Items of 10 and more are rejected.
+    /// assert!(!checker.sanity(|value| value < 10).is_empty());
+    /// // Every item is accepted.
+    /// assert!(checker.sanity(|_| true).is_empty());
+    /// ```
+    pub fn sanity<P: Prove, F: Fn(G::Item) -> P>(&self, check: F) -> Vec<Mutation>
+    where
+        G::Item: FullGenerate<Item = G::Item>,
+    {
+        let count = self.generate.count;
+        let seed = self.generate.seed;
+        let full = G::Item::generator();
+        let mut state = State::new(0, count, self.generate.size, seed);
+        if self
+            .generator
+            .shrink_from(self.generator.generate(&mut state).item())
+            .is_none()
+        {
+            return Vec::new();
+        }
+
+        let check = hook::silent(check);
+        hook::begin();
+        let mut checked = false;
+        let mut mutations = Vec::new();
+        for mutation in [Mutation::Zero, Mutation::Maximal, Mutation::Outside] {
+            let (size, count) = match mutation {
+                Mutation::Zero => (Sizes::from(0.0), 1),
+                Mutation::Maximal => (Sizes::from(1.0), count),
+                Mutation::Outside => (self.generate.size, count),
+            };
+            for index in 0..count {
+                let mut state = State::new(index, count, size, seed);
+                let mut shrinker = full.generate(&mut state);
+                if mutation == Mutation::Zero {
+                    for _ in 0..self.shrink.count {
+                        match shrinker.shrink() {
+                            Some(shrunk) => shrinker = shrunk,
+                            None => break,
+                        }
+                    }
+                }
+                // Items that the generator may produce are valid inputs of the property.
+                if self.generator.shrink_from(shrinker.item()).is_some() {
+                    continue;
+                }
+                checked = true;
+                if handle(shrinker.item(), &check).is_err() {
+                    mutations.push(mutation);
+                    break;
+                }
+            }
+        }
+        hook::end();
+        if checked && mutations.is_empty() {
+            eprintln!(
+                "warning: the property passed for every item outside of its generator, it may \
+                 be vacuous"
+            );
+        }
+        mutations
+    }
+
+    /// Returns a [`Saturation`] if the generator has fewer distinct items
+    /// than the number of items that will be generated in a run. Repeated
+    /// runs are expected to revisit items (with other seeds), so they do not
+    /// count toward the saturation. Constant generators are never saturated.
+    pub fn saturation(&self) -> Option<Saturation> {
+        let count = self.generate.count;
+        let cardinality = self.generator.cardinality()?;
+        if self.generator.constant() || cardinality >= count as u128 {
+            None
+        } else {
+            Some(Saturation { cardinality, count })
+        }
+    }
+}
+
+impl<G: Generate + ?Sized, E, F> Checks<'_, G, E, F> {
+    /// The number of items that were skipped with [`crate::skip!`] so far,
+    /// over all runs.
+    pub const fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// The number of times that each branch of the [`crate::any()`] choices
+    /// was chosen while generating items so far, over all runs. Empty unless
+    /// [`Generates::hits`] is set.
+    pub fn hits(&self) -> &[Hits] {
+        &self.hits
+    }
+
+    /// Observes `cancel` between each check. Once it is cancelled, no more
+    /// items are generated and a shrinking failure ends immediately with a
+    /// [`Result::Fail`] that holds the smallest failing item found so far,
+    /// after which the iterator (or stream) ends. Since a property is never
+    /// interrupted, this does not require a timeout. A run that is cancelled
+    /// before any check fails ends without a result, which must not be
+    /// mistaken for a pass (see [`Checks::cancelled`]).
+    ///
+    /// ```
+    /// use checkito::{check::Cancel, *};
+    ///
+    /// let cancel = Cancel::new();
+    /// let mut checker = (0..1_000_000u32).checker();
+    /// checker.generate.size = 1.0.into();
+    /// let mut checks = checker
+    ///     .checks(|value| value < 100)
+    ///     .cancel_on(cancel.clone());
+    /// // Cancel as soon as a failing item was shrunk once.
+    /// assert!(checks.any(|result| matches!(result, check::Result::Shrunk(_))));
+    /// cancel.cancel();
+    /// let fail = checks.next().unwrap().fail(false).unwrap();
+    /// assert!(fail.item >= 100);
+    /// assert!(checks.next().is_none());
+    /// ```
+    pub fn cancel_on(mut self, cancel: Cancel) -> Self {
+        self.checker.generate.cancel = Some(cancel);
+        self
+    }
+
+    /// Whether the observed [`Cancel`] token was cancelled, in which case the
+    /// checks may have ended before every item was generated.
+    ///
+    /// ```
+    /// use checkito::{check::Cancel, *};
+    ///
+    /// let cancel = Cancel::new();
+    /// cancel.cancel();
+    /// let checker = (0..100u8).checker();
+    /// let mut checks = checker.checks(|value| value < 10).cancel_on(cancel);
+    /// assert!(checks.next().is_none());
+    /// assert!(checks.cancelled());
+    /// ```
+    pub fn cancelled(&self) -> bool {
+        self.checker
+            .generate
+            .cancel
+            .as_ref()
+            .map_or(false, Cancel::cancelled)
+    }
+
+    /// Starts the next run with a new seed, if any.
+    fn rerun(&mut self) -> bool {
+        self.run += 1;
+        if self.run < self.checker.generate.runs {
+            const GOLDEN: u64 = 0x9E37_79B9_7F4A_7C15;
+            self.checker.generate.seed = self.checker.generate.seed.wrapping_add(GOLDEN);
+            self.machine = Machine::Generate { index: 0 };
+            self.generation = Duration::ZERO;
+            self.duration = Duration::ZERO;
+            self.skips = 0;
+            self.passes = 0;
+            self.fails = 0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Runs `run` and adds its duration to the totals of the current run.
+    fn time<T>(total: &mut Duration, run: impl FnOnce() -> T) -> (T, Duration) {
+        let start = Instant::now();
+        let value = run();
+        let elapsed = start.elapsed();
+        *total += elapsed;
+        (value, elapsed)
+    }
+
+    fn progress(&self, done: usize, passes: usize, fails: usize) {
+        if let Some(progress) = self.checker.generate.progress {
+            let total = self.checker.generate.count;
+            if done % (total / 100).max(1) == 0 || done == total {
+                progress(done, total, passes, fails, self.start.elapsed());
+            }
         }
     }
 }
@@ -298,39 +1042,129 @@ impl<G: Generate + ?Sized, P: Prove, F: FnMut(G::Item) -> P> Iterator
     fn next(&mut self) -> Option<Self::Item> {
         loop {
             match replace(&mut self.machine, Machine::Done) {
-                Machine::Generate { index } if index >= self.checker.generate.count => break None,
+                Machine::Generate { .. } if self.cancelled() => break None,
+                Machine::Generate { index } if index >= self.checker.generate.count => {
+                    if self.rerun() {
+                        continue;
+                    } else {
+                        break None;
+                    }
+                }
                 Machine::Generate { index } => {
-                    let mut state = State::new(
-                        index,
-                        self.checker.generate.count,
-                        self.checker.generate.size,
-                        self.checker.generate.seed,
-                    );
-                    let shrinker = self.checker.generator.generate(&mut state);
-                    let result = handle(shrinker.item(), &mut self.check);
+                    let mut state = self
+                        .checker
+                        .generate
+                        .state(index, self.checker.generate.count);
+                    let generator = self.checker.generator;
+                    let (record, hits) = (self.checker.generate.hits, &mut self.hits);
+                    let (shrinker, generation) = Self::time(&mut self.generation, || {
+                        if record {
+                            any::record(hits, || generator.generate(&mut state))
+                        } else {
+                            generator.generate(&mut state)
+                        }
+                    });
+                    let check = &mut self.check;
+                    let (result, duration) = Self::time(&mut self.duration, || {
+                        trace::scope(&state, index, 0, || handle(shrinker.item(), check))
+                    });
+                    self.passes += usize::from(matches!(result, Ok(Ok(_))));
+                    self.fails += usize::from(result.is_err());
+                    self.progress(index + 1, self.passes, self.fails);
                     match result {
-                        Ok(proof) => {
+                        Ok(outcome) => {
                             if self.checker.generator.constant() {
                                 self.machine = Machine::Done;
                             } else {
                                 self.machine = Machine::Generate { index: index + 1 };
                             }
-                            if self.checker.generate.items {
-                                break Some(Result::Pass(Pass {
-                                    item: shrinker.item(),
-                                    generates: index,
-                                    shrinks: 0,
-                                    proof,
-                                    state,
-                                }));
+                            match outcome {
+                                Ok(proof) if self.checker.generate.items => {
+                                    break Some(Result::Pass(Pass {
+                                        item: shrinker.item(),
+                                        generates: index,
+                                        shrinks: 0,
+                                        proof,
+                                        state,
+                                        generation,
+                                        duration,
+                                    }));
+                                }
+                                Ok(_) => {}
+                                Err(reason) => {
+                                    self.skips += 1;
+                                    self.skipped += 1;
+                                    let count = self.checker.generate.count;
+                                    if self.skips as f64
+                                        > self.checker.generate.skips * count as f64
+                                    {
+                                        let fail = Fail {
+                                            item: shrinker.item(),
+                                            generates: index,
+                                            shrinks: 0,
+                                            state,
+                                            cause: Cause::Vacuous {
+                                                skips: self.skips,
+                                                count,
+                                            },
+                                            context: Vec::new(),
+                                            generation: self.generation,
+                                            duration: self.duration,
+                                            search: Search::default(),
+                                        };
+                                        self.machine = Machine::Done;
+                                        self.rerun();
+                                        break Some(Result::Fail(fail));
+                                    }
+                                    if self.checker.generate.items {
+                                        break Some(Result::Skip(Skip {
+                                            item: shrinker.item(),
+                                            reason,
+                                            generates: index,
+                                            state,
+                                        }));
+                                    }
+                                }
                             }
                         }
-                        Err(cause) => {
+                        Err((cause, context)) => {
+                            let confirm = self.checker.shrink.confirm;
+                            let mut fails = 0;
+                            for _ in 0..confirm {
+                                let check = &mut self.check;
+                                let (result, _) = Self::time(&mut self.duration, || {
+                                    handle(shrinker.item(), check)
+                                });
+                                fails += usize::from(result.is_err());
+                            }
+                            if fails < confirm {
+                                let flaky = Flaky {
+                                    fail: Fail {
+                                        item: shrinker.item(),
+                                        generates: index,
+                                        shrinks: 0,
+                                        state,
+                                        cause,
+                                        context,
+                                        generation,
+                                        duration,
+                                        search: Search::default(),
+                                    },
+                                    passes: confirm - fails,
+                                    fails,
+                                };
+                                self.rerun();
+                                break Some(Result::Flaky(flaky));
+                            }
                             self.machine = Machine::Shrink {
                                 indices: (index, 0),
                                 state,
                                 shrinker,
-                                cause,
+                                cause: Some((cause, context)),
+                                depth: 0,
+                                parents: Vec::new(),
+                                best: None,
+                                search: Search::default(),
                             };
                         }
                     }
@@ -340,64 +1174,148 @@ impl<G: Generate + ?Sized, P: Prove, F: FnMut(G::Item) -> P> Iterator
                     state,
                     mut shrinker,
                     cause,
+                    depth,
+                    mut parents,
+                    best,
+                    mut search,
                 } => {
-                    if indices.1 >= self.checker.shrink.count {
-                        self.machine = Machine::Done;
-                        break Some(Result::Fail(Fail {
-                            item: shrinker.item(),
-                            generates: indices.0,
-                            shrinks: indices.1,
-                            state,
-                            cause,
-                        }));
-                    }
-
-                    let new = match shrinker.shrink() {
+                    let strategy = self.checker.shrink.strategy;
+                    let cancelled = self.cancelled();
+                    let exhausted = cancelled
+                        || indices.1 >= self.checker.shrink.count
+                        || matches!(strategy, Strategy::Bounded(count) if indices.1 >= count);
+                    let (new, generation) = Self::time(&mut self.generation, || {
+                        if exhausted { None } else { shrinker.shrink() }
+                    });
+                    let new = match new {
                         Some(shrinker) => shrinker,
                         None => {
-                            self.machine = Machine::Done;
-                            break Some(Result::Fail(Fail {
-                                item: shrinker.item(),
-                                generates: indices.0,
-                                shrinks: indices.1,
-                                state,
-                                cause,
-                            }));
+                            let best = deepest(best, (depth, shrinker, cause));
+                            match parents.pop() {
+                                // Backtrack to the closest parent that may still have candidates.
+                                Some((depth, shrinker)) if !exhausted => {
+                                    search.backtrack();
+                                    self.machine = Machine::Shrink {
+                                        indices,
+                                        state,
+                                        shrinker,
+                                        cause: None,
+                                        depth,
+                                        parents,
+                                        best: Some(best),
+                                        search,
+                                    };
+                                    continue;
+                                }
+                                _ => {
+                                    // Shrink the components that were kept until last now that
+                                    // everything else is minimal.
+                                    let (depth, mut shrinker, cause) = best;
+                                    if !exhausted && shrinker.release() {
+                                        search.release();
+                                        self.machine = Machine::Shrink {
+                                            indices,
+                                            state,
+                                            shrinker,
+                                            cause: Some(cause),
+                                            depth,
+                                            parents,
+                                            best: None,
+                                            search,
+                                        };
+                                        continue;
+                                    }
+                                    search.stop = Some(if cancelled {
+                                        Stop::Cancelled
+                                    } else if exhausted {
+                                        Stop::Budget
+                                    } else if strategy == Strategy::Greedy {
+                                        Stop::Fixpoint
+                                    } else {
+                                        Stop::Exhausted
+                                    });
+                                    let (cause, context) = cause;
+                                    let fail = Fail {
+                                        item: shrinker.item(),
+                                        generates: indices.0,
+                                        shrinks: indices.1,
+                                        state,
+                                        cause,
+                                        context,
+                                        generation: self.generation,
+                                        duration: self.duration,
+                                        search,
+                                    };
+                                    if !cancelled {
+                                        self.rerun();
+                                    }
+                                    break Some(Result::Fail(fail));
+                                }
+                            }
                         }
                     };
-                    let result = handle(new.item(), &mut self.check);
+                    let check = &mut self.check;
+                    let (result, duration) = Self::time(&mut self.duration, || {
+                        trace::scope(&state, indices.0, indices.1 + 1, || {
+                            handle(new.item(), check)
+                        })
+                    });
+                    trace::shrunk(indices.0, indices.1 + 1, result.is_err());
                     match result {
-                        Ok(proof) => {
+                        // A skipped candidate is rejected like a passing one.
+                        Ok(outcome) => {
+                            search.reject();
                             self.machine = Machine::Shrink {
                                 indices: (indices.0, indices.1 + 1),
                                 state: state.clone(),
                                 shrinker,
                                 cause,
+                                depth,
+                                parents,
+                                best,
+                                search,
                             };
-                            if self.checker.shrink.items {
+                            if let (true, Ok(proof)) = (self.checker.shrink.items, outcome) {
                                 break Some(Result::Shrink(Pass {
                                     item: new.item(),
                                     generates: indices.0,
                                     shrinks: indices.1,
                                     proof,
                                     state,
+                                    generation,
+                                    duration,
                                 }));
                             }
                         }
                         Err(new_cause) => {
+                            if !matches!(strategy, Strategy::Greedy) {
+                                parents.push((depth, shrinker.clone()));
+                            }
+                            search.accept();
                             self.machine = Machine::Shrink {
                                 indices: (indices.0, indices.1 + 1),
                                 state: state.clone(),
                                 shrinker: new,
-                                cause: new_cause,
+                                cause: Some(new_cause),
+                                depth: depth + 1,
+                                parents,
+                                best,
+                                search: search.clone(),
                             };
-                            if self.checker.shrink.errors {
+                            // A parent that is revisited while backtracking no longer holds its cause.
+                            if let (true, Some((cause, context))) =
+                                (self.checker.shrink.errors, cause)
+                            {
                                 break Some(Result::Shrunk(Fail {
                                     item: shrinker.item(),
                                     generates: indices.0,
                                     shrinks: indices.1,
                                     cause,
+                                    context,
                                     state,
+                                    generation,
+                                    duration,
+                                    search,
                                 }));
                             }
                         }
@@ -409,11 +1327,96 @@ impl<G: Generate + ?Sized, P: Prove, F: FnMut(G::Item) -> P> Iterator
     }
 }
 
+impl<G: Generate + ?Sized, P: Prove, F: FnMut(G::Item) -> P> Iterator
+    for Failures<'_, G, P::Error, F>
+where
+    G::Item: PartialEq + Clone,
+{
+    type Item = Fail<G::Item, P::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.items.len() < self.count {
+            let (fail, flaky) = match self.checks.next()? {
+                Result::Fail(fail) => (fail, false),
+                Result::Flaky(Flaky { fail, .. }) => (fail, true),
+                _ => continue,
+            };
+            // Unless a new run started, generation resumes after the failing item.
+            if let Machine::Done = self.checks.machine {
+                self.checks.machine = Machine::Generate {
+                    index: fail.generates + 1,
+                };
+            }
+            if !flaky && !self.items.contains(&fail.item) {
+                self.items.push(fail.item.clone());
+                return Some(fail);
+            }
+        }
+        None
+    }
+}
+
+impl<G: Generate + ?Sized, P: Prove, F: FnMut(G::Item) -> P> Failures<'_, G, P::Error, F>
+where
+    G::Item: PartialEq + Clone + fmt::Debug,
+    P::Error: fmt::Debug,
+{
+    /// Collects the failures and groups them in [`Bucket`]s by their message,
+    /// with numbers normalized such that failures that differ only by the
+    /// values they mention end up together. Buckets are sorted from the most
+    /// to the least frequent.
+    pub fn triage(self) -> Vec<Bucket<G::Item, P::Error>> {
+        let mut buckets: Vec<(Bucket<_, _>, usize)> = Vec::new();
+        for fail in self {
+            let key = normalize(&fail.message());
+            let length = format!("{:?}", fail.item).len();
+            match buckets.iter_mut().find(|(bucket, _)| bucket.key == key) {
+                Some((bucket, shortest)) => {
+                    bucket.count += 1;
+                    if length < *shortest {
+                        *shortest = length;
+                        bucket.fail = fail;
+                    }
+                }
+                None => buckets.push((
+                    Bucket {
+                        key,
+                        fail,
+                        count: 1,
+                    },
+                    length,
+                )),
+            }
+        }
+        let mut buckets = buckets
+            .into_iter()
+            .map(|(bucket, _)| bucket)
+            .collect::<Vec<_>>();
+        buckets.sort_by_key(|bucket| Reverse(bucket.count));
+        buckets
+    }
+}
+
+/// Replaces each run of ascii digits in `message` with a `#`.
+fn normalize(message: &str) -> String {
+    let mut normalized = String::with_capacity(message.len());
+    for symbol in message.chars() {
+        if !symbol.is_ascii_digit() {
+            normalized.push(symbol);
+        } else if !normalized.ends_with('#') {
+            normalized.push('#');
+        }
+    }
+    normalized
+}
+
 impl<T, P: Prove> Result<T, P> {
     pub const fn seed(&self) -> u64 {
         match self {
             Result::Pass(pass) | Result::Shrink(pass) => pass.seed(),
             Result::Fail(fail) | Result::Shrunk(fail) => fail.seed(),
+            Result::Flaky(Flaky { fail, .. }) => fail.seed(),
+            Result::Skip(skip) => skip.seed(),
         }
     }
 
@@ -421,6 +1424,8 @@ impl<T, P: Prove> Result<T, P> {
         match self {
             Result::Pass(pass) | Result::Shrink(pass) => pass.size(),
             Result::Fail(fail) | Result::Shrunk(fail) => fail.size(),
+            Result::Flaky(Flaky { fail, .. }) => fail.size(),
+            Result::Skip(skip) => skip.size(),
         }
     }
 
@@ -428,6 +1433,8 @@ impl<T, P: Prove> Result<T, P> {
         match self {
             Result::Pass(pass) | Result::Shrink(pass) => &pass.item,
             Result::Fail(fail) | Result::Shrunk(fail) => &fail.item,
+            Result::Flaky(Flaky { fail, .. }) => &fail.item,
+            Result::Skip(skip) => &skip.item,
         }
     }
 
@@ -435,6 +1442,8 @@ impl<T, P: Prove> Result<T, P> {
         match self {
             Result::Pass(pass) | Result::Shrink(pass) => pass.generates,
             Result::Fail(fail) | Result::Shrunk(fail) => fail.generates,
+            Result::Flaky(Flaky { fail, .. }) => fail.generates,
+            Result::Skip(skip) => skip.generates,
         }
     }
 
@@ -442,6 +1451,8 @@ impl<T, P: Prove> Result<T, P> {
         match self {
             Result::Pass(pass) | Result::Shrink(pass) => pass.shrinks,
             Result::Fail(fail) | Result::Shrunk(fail) => fail.shrinks,
+            Result::Flaky(Flaky { fail, .. }) => fail.shrinks,
+            Result::Skip(_) => 0,
         }
     }
 
@@ -449,6 +1460,8 @@ impl<T, P: Prove> Result<T, P> {
         match self {
             Result::Pass(pass) | Result::Shrink(pass) => &pass.state,
             Result::Fail(fail) | Result::Shrunk(fail) => &fail.state,
+            Result::Flaky(Flaky { fail, .. }) => &fail.state,
+            Result::Skip(skip) => &skip.state,
         }
     }
 
@@ -456,7 +1469,11 @@ impl<T, P: Prove> Result<T, P> {
         match self {
             Result::Pass(pass) => Some(pass),
             Result::Shrink(pass) if shrink => Some(pass),
-            Result::Fail(_) | Result::Shrink(_) | Result::Shrunk(_) => None,
+            Result::Fail(_)
+            | Result::Shrink(_)
+            | Result::Shrunk(_)
+            | Result::Flaky(_)
+            | Result::Skip(_) => None,
         }
     }
 
@@ -464,7 +1481,33 @@ impl<T, P: Prove> Result<T, P> {
         match self {
             Result::Fail(fail) => Some(fail),
             Result::Shrunk(fail) if shrunk => Some(fail),
-            Result::Pass(_) | Result::Shrunk(_) | Result::Shrink(_) => None,
+            Result::Pass(_)
+            | Result::Shrunk(_)
+            | Result::Shrink(_)
+            | Result::Flaky(_)
+            | Result::Skip(_) => None,
+        }
+    }
+
+    pub fn flaky(self) -> Option<Flaky<T, P::Error>> {
+        match self {
+            Result::Flaky(flaky) => Some(flaky),
+            Result::Pass(_)
+            | Result::Shrink(_)
+            | Result::Shrunk(_)
+            | Result::Fail(_)
+            | Result::Skip(_) => None,
+        }
+    }
+
+    pub fn skip(self) -> Option<Skip<T>> {
+        match self {
+            Result::Skip(skip) => Some(skip),
+            Result::Pass(_)
+            | Result::Shrink(_)
+            | Result::Shrunk(_)
+            | Result::Fail(_)
+            | Result::Flaky(_) => None,
         }
     }
 }
@@ -479,6 +1522,16 @@ impl<T, P> Pass<T, P> {
     }
 }
 
+impl<T> Skip<T> {
+    pub const fn seed(&self) -> u64 {
+        self.state.seed()
+    }
+
+    pub const fn size(&self) -> f64 {
+        self.state.size()
+    }
+}
+
 impl<T, P> Fail<T, P> {
     pub const fn seed(&self) -> u64 {
         self.state.seed()
@@ -493,76 +1546,358 @@ impl<T, P> Fail<T, P> {
         P: fmt::Debug,
     {
         match &self.cause {
-            Cause::Panic(Some(message)) => message.clone(),
-            Cause::Panic(None) => "panicked".into(),
+            Cause::Panic(Some(message), _) => message.clone(),
+            Cause::Panic(None, _) => "panicked".into(),
             Cause::Disprove(proof) => format!("{proof:?}").into(),
+            Cause::ResourceExhausted { allocated, budget } => {
+                format!("allocated {allocated} bytes out of a budget of {budget} bytes").into()
+            }
+            Cause::Vacuous { skips, count } => {
+                format!("skipped {skips} of {count} items, the property is vacuous").into()
+            }
         }
     }
-}
 
-fn cast(error: Box<dyn Any + Send>) -> Option<Cow<'static, str>> {
-    let error = match error.downcast::<&'static str>() {
-        Ok(error) => return Some(Cow::Borrowed(*error)),
-        Err(error) => error,
-    };
-    let error = match error.downcast::<String>() {
-        Ok(error) => return Some(Cow::Owned(*error)),
-        Err(error) => error,
-    };
-    let error = match error.downcast::<Box<str>>() {
-        Ok(error) => return Some(Cow::Owned(error.to_string())),
-        Err(error) => error,
-    };
-    match error.downcast::<Cow<'static, str>>() {
-        Ok(error) => Some(*error),
-        Err(_) => None,
+    /// Builds a shell command that replays this failure for the test `name` (a
+    /// path such as `module::test`) through the environment variables that
+    /// override the [`Checker`] settings. The run that failed is replayed
+    /// alone with its seed, such that the failing item is found and shrunk
+    /// again. The variables are scoped to the test (such as
+    /// `CHECKITO_GENERATE_SEED__module__test`) and only the test with that
+    /// exact name runs, such that the other checks of the test binary are not
+    /// affected.
+    ///
+    /// The other settings that determine the items of a run (such as
+    /// [`Generates::size`], [`Generates::count`], [`Generates::ramp`],
+    /// [`Generates::mode`] or the cases of [`Generate::pinned`]) are
+    /// not part of the command and are expected to be the same when it runs.
+    /// This holds for the settings of the `#[check]` attribute, but not for
+    /// the environment variables of the failing run (other than the seed) if
+    /// they are not set again.
+    pub fn reproduce(&self, name: &str) -> String {
+        let scope = name.replace("::", "__");
+        format!(
+            "CHECKITO_GENERATE_SEED__{scope}={} CHECKITO_GENERATE_RUNS__{scope}=1 cargo test \
+             {name} -- --exact",
+            self.seed()
+        )
+    }
+
+    /// Retrieves a copy of the panic payload if the check panicked with a value
+    /// of type `U`.
+    pub fn payload<U: Clone + 'static>(&self) -> Option<U> {
+        self.cause.payload()?.downcast()
     }
 }
 
-fn handle<T, P: Prove, F: FnMut(T) -> P>(
-    item: T,
-    mut check: F,
-) -> result::Result<P::Proof, Cause<P::Error>> {
-    match catch_unwind(AssertUnwindSafe(move || check(item))) {
-        Ok(prove) => match prove.prove() {
-            Ok(ok) => Ok(ok),
-            Err(error) => Err(Cause::Disprove(error)),
-        },
-        Err(error) => Err(Cause::Panic(cast(error))),
+impl<E> Cause<E> {
+    pub const fn payload(&self) -> Option<&Payload> {
+        match self {
+            Cause::Panic(_, payload) => Some(payload),
+            Cause::Disprove(_) | Cause::ResourceExhausted { .. } | Cause::Vacuous { .. } => None,
+        }
     }
 }
 
-impl<T: fmt::Debug, E: fmt::Debug> fmt::Display for Fail<T, E> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Debug::fmt(self, f)
+impl Cancel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the [`Checks`] that observe this token or any of its clones.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
     }
 }
 
-impl<T: fmt::Debug, E: fmt::Debug> error::Error for Fail<T, E> {}
+impl Payload {
+    pub(crate) fn new(payload: Box<dyn Any + Send>) -> Self {
+        Self(Arc::new(Mutex::new(payload)))
+    }
 
-#[doc(hidden)]
-pub mod help {
-    use super::{Check, Checker, Fail, Generate, Pass, Prove, Result, environment, hook};
-    use core::{
-        any::type_name,
-        fmt::{self, Arguments},
-    };
+    /// Returns `true` if the payload is of type `T`.
+    pub fn is<T: 'static>(&self) -> bool {
+        self.with(|payload| payload.is::<T>())
+    }
 
-    struct Colors {
-        red: &'static str,
-        green: &'static str,
-        yellow: &'static str,
-        dim: &'static str,
-        bold: &'static str,
-        reset: &'static str,
+    /// Retrieves a copy of the payload if it is of type `T`.
+    pub fn downcast<T: Clone + 'static>(&self) -> Option<T> {
+        self.with(|payload| payload.downcast_ref::<T>().cloned())
     }
 
-    impl Colors {
-        pub const fn new(color: bool) -> Self {
-            if color {
-                Self {
-                    red: "\x1b[31m",
-                    green: "\x1b[32m",
+    fn with<T>(&self, with: impl FnOnce(&(dyn Any + Send)) -> T) -> T {
+        with(
+            self.0
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .as_ref(),
+        )
+    }
+}
+
+impl fmt::Debug for Payload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.with(cast) {
+            Some(message) => f.debug_tuple("Payload").field(&message).finish(),
+            None => f.write_str("Payload(..)"),
+        }
+    }
+}
+
+impl PartialEq for Payload {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Payload {}
+
+fn cast(error: &(dyn Any + Send)) -> Option<Cow<'static, str>> {
+    if let Some(error) = error.downcast_ref::<&'static str>() {
+        Some(Cow::Borrowed(*error))
+    } else if let Some(error) = error.downcast_ref::<String>() {
+        Some(Cow::Owned(error.clone()))
+    } else if let Some(error) = error.downcast_ref::<Box<str>>() {
+        Some(Cow::Owned(error.to_string()))
+    } else {
+        error.downcast_ref::<Cow<'static, str>>().cloned()
+    }
+}
+
+/// Integration with the `tracing` crate such that the logs emitted by a check
+/// can be correlated with the item that produced them.
+mod trace {
+    use crate::generate::State;
+
+    /// Runs a check within a span that identifies its item.
+    #[cfg(feature = "tracing")]
+    pub fn scope<T>(state: &State, generates: usize, shrinks: usize, run: impl FnOnce() -> T) -> T {
+        tracing::info_span!(
+            "check",
+            generates,
+            shrinks,
+            seed = state.seed(),
+            size = state.size()
+        )
+        .in_scope(run)
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    pub fn scope<T>(_: &State, _: usize, _: usize, run: impl FnOnce() -> T) -> T {
+        run()
+    }
+
+    /// Emits an event when a shrunk item is accepted (it failed the check) or
+    /// rejected (it passed the check).
+    #[cfg(feature = "tracing")]
+    pub fn shrunk(generates: usize, shrinks: usize, accepted: bool) {
+        tracing::debug!(generates, shrinks, accepted, "shrink");
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    pub fn shrunk(_: usize, _: usize, _: bool) {}
+}
+
+/// The cause of a failure along with the breadcrumbs that the check recorded.
+type Failure<E> = (Cause<E>, Vec<Cow<'static, str>>);
+
+/// The proof of a check or the reason for which it was skipped.
+type Outcome<P> = result::Result<P, Cow<'static, str>>;
+
+/// The panic payload that [`skip`] unwinds with.
+struct Skipped(Cow<'static, str>);
+
+/// Skips the check of the current item with a `reason` (see [`crate::skip!`]).
+/// This unwinds out of the check, so it aborts the process when panics abort.
+pub fn skip<R: Into<Cow<'static, str>>>(reason: R) -> ! {
+    // Unwinding does not call the panic hook, such that nothing is printed.
+    std::panic::resume_unwind(Box::new(Skipped(reason.into())))
+}
+
+/// Skips the check of the current item, such as when a precondition of the
+/// property does not hold for it. Unlike an early `return`, a skipped item is
+/// not counted as a pass and a run that skips too many items fails (see
+/// [`Generates::skips`](crate::check::Generates::skips)).
+///
+/// The reason is formatted like [`format!`] and defaults to `"skipped"`.
+///
+/// Since the check is skipped by unwinding out of it (with
+/// [`std::panic::resume_unwind`]), `skip!` aborts the process when it is
+/// compiled with `panic = "abort"`.
+///
+/// ```
+/// use checkito::*;
+///
+/// let fail = (0..100u8, 0..10u8).check(|(left, right)| {
+///     if right == 0 {
+///         skip!("{left} can not be divided by zero");
+///     }
+///     left / right * right + left % right == left
+/// });
+/// assert!(fail.is_none());
+/// ```
+#[macro_export]
+macro_rules! skip {
+    () => {
+        $crate::check::skip("skipped")
+    };
+    ($($arguments:tt)+) => {
+        $crate::check::skip(::std::format!($($arguments)+))
+    };
+}
+
+fn handle<T, P: Prove, F: FnMut(T) -> P>(
+    item: T,
+    mut check: F,
+) -> result::Result<Outcome<P::Proof>, Failure<P::Error>> {
+    let run = || catch_unwind(AssertUnwindSafe(move || check(item)));
+    #[cfg(not(feature = "allocation"))]
+    let (result, context) = crate::report::capture(run);
+    #[cfg(feature = "allocation")]
+    let (result, context) = match crate::allocation::track(|| crate::report::capture(run)) {
+        ((_, context), Some((allocated, budget))) => {
+            return Err((Cause::ResourceExhausted { allocated, budget }, context));
+        }
+        (pair, None) => pair,
+    };
+    match result {
+        Ok(prove) => match prove.prove() {
+            Ok(ok) => Ok(Ok(ok)),
+            Err(error) => Err((Cause::Disprove(error), context)),
+        },
+        Err(error) => match error.downcast::<Skipped>() {
+            Ok(skipped) => Ok(Err(skipped.0)),
+            Err(error) => Err((
+                Cause::Panic(cast(error.as_ref()), Payload::new(error)),
+                context,
+            )),
+        },
+    }
+}
+
+impl fmt::Display for Saturation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the generator has {} distinct items for {} checks per run, most checks are duplicates",
+            self.cardinality, self.count
+        )
+    }
+}
+
+impl Search {
+    fn phase(&mut self) -> &mut Phase {
+        if self.phases.is_empty() {
+            self.phases.push(Phase::default());
+        }
+        let last = self.phases.len() - 1;
+        &mut self.phases[last]
+    }
+
+    fn accept(&mut self) {
+        self.accepted += 1;
+        self.phase().accepted += 1;
+    }
+
+    fn reject(&mut self) {
+        self.rejected += 1;
+        self.phase().rejected += 1;
+    }
+
+    fn backtrack(&mut self) {
+        self.backtracks += 1;
+        self.phase().backtracks += 1;
+    }
+
+    /// Starts the phase that shrinks the components that were kept until last.
+    fn release(&mut self) {
+        self.phase();
+        self.phases.push(Phase::default());
+    }
+}
+
+impl fmt::Display for Search {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} candidates ({} accepted, {} rejected, {} backtracks",
+            self.accepted + self.rejected,
+            self.accepted,
+            self.rejected,
+            self.backtracks
+        )?;
+        // A single phase holds the same statistics as the whole search.
+        if self.phases.len() > 1 {
+            for (index, phase) in self.phases.iter().enumerate() {
+                write!(
+                    f,
+                    "; phase {}: {} accepted, {} rejected, {} backtracks",
+                    index + 1,
+                    phase.accepted,
+                    phase.rejected,
+                    phase.backtracks
+                )?;
+            }
+        }
+        write!(f, ")")?;
+        match self.stop {
+            Some(Stop::Budget) => write!(f, ", stopped by the budget"),
+            Some(Stop::Fixpoint) => write!(f, ", stopped at a fixpoint"),
+            Some(Stop::Exhausted) => write!(f, ", stopped with no more candidates"),
+            Some(Stop::Cancelled) => write!(f, ", stopped by a cancellation"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: fmt::Debug, E: fmt::Debug> fmt::Display for Fail<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl<T: fmt::Debug, E: fmt::Debug> error::Error for Fail<T, E> {}
+
+#[doc(hidden)]
+pub mod help {
+    use super::{
+        Check, Checker, Fail, Flaky, Generate, Pass, Prove, Result, Skip,
+        clock::Instant,
+        environment, hook,
+        report::{self, Reporter, Summary},
+    };
+    use crate::{any::Weight, boxed::Boxed, prelude::map};
+    use core::{
+        cell::Cell,
+        fmt,
+        marker::PhantomData,
+        ops::{Deref, DerefMut},
+        panic::AssertUnwindSafe,
+    };
+    use orn::Or2;
+    use std::{collections::VecDeque, panic::catch_unwind, time::Duration};
+
+    thread_local! { static SEED: Cell<Option<u64>> = const { Cell::new(None) }; }
+
+    struct Colors {
+        red: &'static str,
+        green: &'static str,
+        yellow: &'static str,
+        dim: &'static str,
+        bold: &'static str,
+        reset: &'static str,
+    }
+
+    impl Colors {
+        pub const fn new(color: bool) -> Self {
+            if color {
+                Self {
+                    red: "\x1b[31m",
+                    green: "\x1b[32m",
                     yellow: "\x1b[33m",
                     bold: "\x1b[1m",
                     dim: "\x1b[2m",
@@ -581,6 +1916,302 @@ pub mod help {
         }
     }
 
+    /// Holds the value of a fixture for the duration of a check run and calls
+    /// its `teardown` function when dropped, even if the run panicked.
+    pub struct Fixture<T, F: FnOnce(T)>(Option<(T, F)>);
+
+    pub fn fixture<T, S: FnOnce() -> T, F: FnOnce(T)>(setup: S, teardown: F) -> Fixture<T, F> {
+        Fixture(Some((setup(), teardown)))
+    }
+
+    impl<T, F: FnOnce(T)> Deref for Fixture<T, F> {
+        type Target = T;
+
+        fn deref(&self) -> &Self::Target {
+            &self
+                .0
+                .as_ref()
+                .expect("fixture is only taken when dropped")
+                .0
+        }
+    }
+
+    impl<T, F: FnOnce(T)> DerefMut for Fixture<T, F> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self
+                .0
+                .as_mut()
+                .expect("fixture is only taken when dropped")
+                .0
+        }
+    }
+
+    impl<T, F: FnOnce(T)> Drop for Fixture<T, F> {
+        fn drop(&mut self) {
+            if let Some((value, teardown)) = self.0.take() {
+                teardown(value);
+            }
+        }
+    }
+
+    /// The arguments of a `#[check]` function, whose `Debug` implementation
+    /// names each value after its parameter (such as `{ x: 3, y: "abc" }`).
+    #[derive(Clone)]
+    pub struct Arguments<T> {
+        pub names: &'static [&'static str],
+        pub values: T,
+    }
+
+    pub fn arguments<G: Generate>(
+        names: &'static [&'static str],
+        generator: G,
+    ) -> impl Generate<Item = Arguments<G::Item>> {
+        map(generator, move |values| Arguments { names, values })
+    }
+
+    fn fields(f: &mut fmt::Formatter, names: &[&str], values: &[&dyn fmt::Debug]) -> fmt::Result {
+        if values.is_empty() {
+            f.write_str("{}")
+        } else if f.alternate() {
+            f.write_str("{\n")?;
+            for (name, value) in names.iter().zip(values) {
+                let value = format!("{value:#?}").replace('\n', "\n    ");
+                writeln!(f, "    {name}: {value},")?;
+            }
+            f.write_str("}")
+        } else {
+            f.write_str("{ ")?;
+            for (index, (name, value)) in names.iter().zip(values).enumerate() {
+                if index > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{name}: {value:?}")?;
+            }
+            f.write_str(" }")
+        }
+    }
+
+    macro_rules! tuple {
+        ($n:ident, $c:tt $(,$p:ident, $t:ident, $i:tt)*) => {
+            impl<$($t: fmt::Debug,)*> fmt::Debug for Arguments<($($t,)*)> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    fields(f, self.names, &[$(&self.values.$i,)*])
+                }
+            }
+        };
+    }
+
+    crate::utility::tuples!(tuple);
+    #[cfg(feature = "big-tuples")]
+    crate::utility::big_tuples!(tuple);
+
+    /// Prints a single updating line with the progress of the generation
+    /// process and an estimate of the remaining time.
+    pub fn progress<const COLOR: bool>(
+        done: usize,
+        total: usize,
+        passes: usize,
+        fails: usize,
+        elapsed: Duration,
+    ) {
+        let Colors { dim, reset, .. } = Colors::new(COLOR);
+        let remaining = elapsed.mul_f64((total - done) as f64 / done.max(1) as f64);
+        eprint!(
+            "\r{dim}PROGRESS({done}/{total}) {{ passes: {passes}, fails: {fails}, elapsed: \
+             {elapsed:.1?}, remaining: {remaining:.1?} }}{reset}"
+        );
+        if done == total {
+            eprintln!();
+        }
+    }
+
+    /// A check of a function with many `#[check]` attributes that failed while
+    /// `continue = true` was set.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Failure {
+        /// The position of the `#[check]` attribute, starting at 1.
+        pub index: usize,
+        /// The seed of the first failed run, if the check reported one.
+        pub seed: Option<u64>,
+    }
+
+    /// Runs the check of the `#[check]` attribute at `index` and catches its
+    /// failure such that the checks of the next attributes still run.
+    pub fn attempt(index: usize, run: impl FnOnce()) -> Option<Failure> {
+        SEED.with(|seed| seed.set(None));
+        match catch_unwind(AssertUnwindSafe(run)) {
+            Ok(()) => None,
+            Err(_) => Some(Failure {
+                index,
+                seed: SEED.with(Cell::take),
+            }),
+        }
+    }
+
+    /// Reports the `failures` caught by [`attempt`] among the `count` checks of
+    /// the function `name` and panics if there are any.
+    #[track_caller]
+    pub fn conclude(name: &str, count: usize, failures: &[Failure]) {
+        if failures.is_empty() {
+            return;
+        }
+        eprintln!("{name}: {} of {count} checks failed", failures.len());
+        for Failure { index, seed } in failures {
+            match seed {
+                Some(seed) => eprintln!("  #[check] {index} failed with seed {seed}"),
+                None => eprintln!("  #[check] {index} failed"),
+            }
+        }
+        panic!("{} of {count} checks failed", failures.len());
+    }
+
+    /// Mixes the `literals` found in a check's body into the `generator` as
+    /// special values.
+    pub fn literals<G: Generate>(
+        generator: G,
+        literals: Vec<G::Item>,
+    ) -> impl Generate<Item = G::Item>
+    where
+        G::Item: Clone,
+    {
+        if literals.is_empty() {
+            Or2::T0(generator)
+        } else {
+            Or2::T1(
+                (
+                    Weight::new(9.0, generator),
+                    Weight::new(
+                        1.0,
+                        map(0..literals.len(), move |index| literals[index].clone()),
+                    ),
+                )
+                    .unify::<G::Item>(),
+            )
+        }
+        .unify()
+    }
+
+    /// A literal found in a check's body. Numbers are kept as their base 10
+    /// digits (including their sign) and their suffix such that they are only
+    /// converted to the types that can represent them.
+    #[derive(Clone, Copy, Debug)]
+    pub enum Literal {
+        Integer(&'static str, &'static str),
+        Float(&'static str, &'static str),
+        Char(char),
+        Str(&'static str),
+    }
+
+    /// Converts a [`Literal`] to a parameter type. The conversion is resolved
+    /// by the compiler such that type aliases and paths to primitive types are
+    /// supported.
+    pub trait FromLiteral: Sized {
+        fn from_literal(literal: Literal) -> Option<Self>;
+    }
+
+    /// Resolves the [`Literal`]s that convert to `T`. If `T` does not implement
+    /// [`FromLiteral`], method resolution falls back from [`Fit::values`] to
+    /// [`Miss::values`], which resolves no literal.
+    pub struct Literals<T>(&'static [Literal], PhantomData<T>);
+
+    pub trait Fit<T> {
+        fn values(&self) -> Vec<T>;
+    }
+
+    pub trait Miss<T> {
+        fn values(&self) -> Vec<T>;
+    }
+
+    impl<T> Literals<T> {
+        pub const fn new(literals: &'static [Literal]) -> Self {
+            Self(literals, PhantomData)
+        }
+    }
+
+    impl<T: FromLiteral> Fit<T> for Literals<T> {
+        fn values(&self) -> Vec<T> {
+            self.0
+                .iter()
+                .filter_map(|&literal| T::from_literal(literal))
+                .collect()
+        }
+    }
+
+    impl<T> Miss<T> for &Literals<T> {
+        fn values(&self) -> Vec<T> {
+            Vec::new()
+        }
+    }
+
+    macro_rules! number {
+        ($kind: ident, [$($t: ident),*]) => {
+            $(
+                impl FromLiteral for $t {
+                    fn from_literal(literal: Literal) -> Option<Self> {
+                        match literal {
+                            Literal::$kind(digits, suffix)
+                                if suffix.is_empty() || suffix == stringify!($t) =>
+                            {
+                                digits.parse().ok()
+                            }
+                            _ => None,
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    number!(
+        Integer,
+        [
+            u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+        ]
+    );
+    number!(Float, [f32, f64]);
+
+    impl FromLiteral for char {
+        fn from_literal(literal: Literal) -> Option<Self> {
+            match literal {
+                Literal::Char(value) => Some(value),
+                _ => None,
+            }
+        }
+    }
+
+    impl FromLiteral for String {
+        fn from_literal(literal: Literal) -> Option<Self> {
+            match literal {
+                Literal::Str(value) => Some(value.into()),
+                _ => None,
+            }
+        }
+    }
+
+    /// Boxes the `generator`, `update` and `check` of a check with
+    /// `erased = true` (see [`Checker::erased`]).
+    #[allow(clippy::type_complexity)]
+    pub fn erase<
+        'a,
+        G: Generate + 'static,
+        U: FnOnce(&mut Checker<Boxed<G::Item>>) + 'a,
+        P: Prove,
+        C: Fn(G::Item) -> P + 'a,
+    >(
+        generator: G,
+        update: U,
+        check: C,
+    ) -> (
+        Boxed<G::Item>,
+        Box<dyn FnOnce(&mut Checker<Boxed<G::Item>>) + 'a>,
+        Box<dyn Fn(G::Item) -> P + 'a>,
+    )
+    where
+        G::Shrink: 'static,
+    {
+        (generator.boxed(), Box::new(update), Box::new(check))
+    }
+
     #[track_caller]
     pub fn default<G: Generate, U: FnOnce(&mut Checker<G>), P: Prove, C: Fn(G::Item) -> P>(
         generator: G,
@@ -588,35 +2219,22 @@ pub mod help {
         check: C,
         color: bool,
         verbose: bool,
+        quiet: bool,
+        name: &str,
     ) where
         G::Item: fmt::Debug,
         P::Proof: fmt::Debug,
         P::Error: fmt::Debug,
     {
-        with(
+        report(
             generator,
             update,
             check,
+            report::Default,
             color,
             verbose,
-            |prefix, pass| {
-                println!(
-                    "{prefix} {{ item: {:?}, seed: {}, size: {}, proof: {:?} }}",
-                    &pass.item,
-                    pass.seed(),
-                    pass.size(),
-                    &pass.proof,
-                )
-            },
-            |prefix, fail| {
-                eprintln!(
-                    "{prefix} {{ item: {:?}, seed: {}, size: {}, message: \"{}\" }}",
-                    &fail.item,
-                    fail.seed(),
-                    fail.size(),
-                    fail.message(),
-                )
-            },
+            quiet,
+            name,
         );
     }
 
@@ -627,19 +2245,22 @@ pub mod help {
         check: C,
         color: bool,
         verbose: bool,
+        quiet: bool,
+        name: &str,
     ) where
         G::Item: fmt::Debug,
         P::Proof: fmt::Debug,
         P::Error: fmt::Debug,
     {
-        with(
+        report(
             generator,
             update,
             check,
+            report::Debug,
             color,
             verbose,
-            |prefix, pass| println!("{prefix} {pass:?}"),
-            |prefix, fail| eprintln!("{prefix} {fail:?}"),
+            quiet,
+            name,
         );
     }
 
@@ -650,55 +2271,46 @@ pub mod help {
         check: C,
         color: bool,
         verbose: bool,
+        quiet: bool,
+        name: &str,
     ) {
-        with(
+        report(
             generator,
             update,
             check,
+            report::Minimal,
             color,
             verbose,
-            |prefix, pass| {
-                println!(
-                    "{prefix} {{ type: {}, seed: {}, size: {} }}",
-                    type_name::<G::Item>(),
-                    pass.seed(),
-                    pass.size(),
-                )
-            },
-            |prefix, fail| {
-                eprintln!(
-                    "{prefix} {{ type: {}, seed: {}, size: {} }}",
-                    type_name::<G::Item>(),
-                    fail.seed(),
-                    fail.size(),
-                )
-            },
+            quiet,
+            name,
         );
     }
 
     #[track_caller]
-    fn with<
+    #[allow(clippy::too_many_arguments)]
+    pub fn report<
         G: Generate,
         U: FnOnce(&mut Checker<G>),
         P: Prove,
         C: Fn(G::Item) -> P,
-        WP: Fn(Arguments, Pass<G::Item, P::Proof>),
-        WF: Fn(Arguments, Fail<G::Item, P::Error>),
+        R: Reporter<G::Item, P::Proof, P::Error>,
     >(
         generator: G,
         update: U,
         check: C,
+        reporter: R,
         color: bool,
         verbose: bool,
-        pass: WP,
-        fail: WF,
+        quiet: bool,
+        name: &str,
     ) {
         let mut checker = generator.checker();
         checker.generate.items = verbose;
         checker.shrink.items = verbose;
         checker.shrink.errors = verbose;
-        environment::update(&mut checker);
+        environment::update(&mut checker, name);
         (update)(&mut checker);
+        let explain = checker.shrink.explain;
         let Colors {
             red,
             green,
@@ -707,18 +2319,30 @@ pub mod help {
             bold,
             reset,
         } = Colors::new(color);
+        if let (false, Some(saturation)) = (quiet, checker.saturation()) {
+            reporter.saturated(format_args!("{yellow}WARNING:{reset}"), &saturation);
+        }
 
         hook::begin();
-        for result in checker.checks(hook::silent(check)) {
+        let start = Instant::now();
+        let mut summary = Summary {
+            name,
+            failures: 0,
+            flakes: 0,
+            skips: 0,
+            cancelled: false,
+            elapsed: Duration::ZERO,
+        };
+        let mut emit = |result| {
             match result {
                 Result::Pass(value @ Pass { generates, .. }) => {
-                    pass(format_args!("{green}PASS({generates}){reset}"), value)
+                    reporter.pass(format_args!("{green}PASS({generates}){reset}"), value)
                 }
-                Result::Shrink(value @ Pass { shrinks, .. }) => pass(
+                Result::Shrink(value @ Pass { shrinks, .. }) => reporter.shrink(
                     format_args!("{dim}{yellow}SHRINK({shrinks}, {green}PASS{yellow}){reset}"),
                     value,
                 ),
-                Result::Shrunk(value @ Fail { shrinks, .. }) => fail(
+                Result::Shrunk(value @ Fail { shrinks, .. }) => reporter.shrunk(
                     format_args!("{yellow}SHRUNK({shrinks}, {red}FAIL{yellow}){reset}"),
                     value,
                 ),
@@ -727,57 +2351,691 @@ pub mod help {
                         generates, shrinks, ..
                     },
                 ) => {
-                    fail(
+                    // The test name passed to `cargo test` does not include the crate name.
+                    let test = name.split_once("::").map_or(name, |(_, test)| test);
+                    let reproduce = value.reproduce(test);
+                    let search = value.search.clone();
+                    SEED.with(|seed| {
+                        if seed.get().is_none() {
+                            seed.set(Some(value.seed()));
+                        }
+                    });
+                    reporter.fail(
                         format_args!("{bold}{red}FAIL({generates}, {shrinks}){reset}"),
                         value,
                     );
-                    hook::panic();
+                    reporter.reproduce(format_args!("{dim}REPRODUCE:{reset}"), &reproduce);
+                    if explain {
+                        reporter.explain(format_args!("{dim}EXPLAIN:{reset}"), &search);
+                    }
+                    summary.failures += 1;
+                }
+                Result::Flaky(value @ Flaky { passes, fails, .. }) => {
+                    reporter.flaky(
+                        format_args!("{bold}{yellow}FLAKY({passes}, {fails}){reset}"),
+                        value,
+                    );
+                    summary.flakes += 1;
+                }
+                Result::Skip(value @ Skip { generates, .. }) => {
+                    reporter.skip(format_args!("{dim}SKIP({generates}){reset}"), value)
+                }
+            }
+        };
+        // When quiet, the last generated results and the shrinking results of a run are kept and
+        // only reported if the run fails.
+        const TAIL: usize = 16;
+        let mut tail = VecDeque::with_capacity(TAIL);
+        let mut shrinks = Vec::new();
+        let mut checks = checker.checks(hook::silent(check));
+        for result in &mut checks {
+            if !quiet {
+                emit(result);
+                continue;
+            }
+            match result {
+                Result::Fail(_) | Result::Flaky(_) => {
+                    tail.drain(..).chain(shrinks.drain(..)).for_each(&mut emit);
+                    emit(result);
+                }
+                Result::Shrink(_) | Result::Shrunk(_) => shrinks.push(result),
+                Result::Pass(_) | Result::Skip(_) => {
+                    if let Result::Pass(Pass { generates: 0, .. })
+                    | Result::Skip(Skip { generates: 0, .. }) = result
+                    {
+                        tail.clear();
+                        shrinks.clear();
+                    }
+                    if tail.len() == TAIL {
+                        tail.pop_front();
+                    }
+                    tail.push_back(result);
                 }
             }
         }
+        summary.elapsed = start.elapsed();
+        summary.skips = checks.skipped();
+        if let (false, 1..) = (quiet, summary.skips) {
+            reporter.skipped(format_args!("{yellow}SKIPPED:{reset}"), summary.skips);
+        }
+        for hits in checks.hits() {
+            reporter.hits(format_args!("{dim}HITS:{reset}"), hits);
+        }
+        // A cancelled run did not check every item, so it must not pass.
+        summary.cancelled = checks.cancelled();
+        if summary.cancelled {
+            reporter.cancelled(format_args!("{bold}{red}CANCELLED:{reset}"));
+        }
+        let failed = summary.failures > 0 || summary.flakes > 0 || summary.cancelled;
+        reporter.summary(summary);
+        if failed {
+            hook::panic();
+        }
         hook::end();
     }
+
+    /// Selects how the error of a disproved property is described in failure
+    /// messages. Method resolution on a [`describe::Tag`] behind many
+    /// references picks the first applicable description among:
+    /// - the `Display` and `source` chain of either error of a
+    ///   [`crate::prove::Nested`] result;
+    /// - the `Display` of either error of a [`crate::prove::Nested`] result;
+    /// - the `Display` and `source` chain of the error;
+    /// - the `Display` of the error;
+    /// - the `Debug` of the error.
+    ///
+    /// This resolution must happen where the error type is concrete (in the
+    /// expansion of the `#[check]` macro), hence the traits.
+    pub mod describe {
+        use super::super::Prove;
+        use core::{fmt, marker::PhantomData};
+        use orn::Or2;
+        use std::error::Error;
+
+        pub type Format<E> = fn(&E, &mut fmt::Formatter) -> fmt::Result;
+
+        pub struct Tag<E>(PhantomData<E>);
+
+        /// A property whose error is described with a [`Format`].
+        pub struct Describe<P: Prove>(P, Format<P::Error>);
+
+        /// An error whose `Debug` implementation uses its [`Format`].
+        pub struct Described<E>(pub E, Format<E>);
+
+        pub trait ResultChain<E> {
+            fn describe(&self) -> Format<E>;
+        }
+
+        pub trait ResultDisplay<E> {
+            fn describe(&self) -> Format<E>;
+        }
+
+        pub trait Chain<E> {
+            fn describe(&self) -> Format<E>;
+        }
+
+        pub trait Display<E> {
+            fn describe(&self) -> Format<E>;
+        }
+
+        pub trait Debug<E> {
+            fn describe(&self) -> Format<E>;
+        }
+
+        pub fn tag<I, P: Prove, C: Fn(I) -> P>(_: &C) -> Tag<P::Error> {
+            Tag(PhantomData)
+        }
+
+        pub fn check<I, P: Prove, C: Fn(I) -> P>(
+            check: C,
+            format: Format<P::Error>,
+        ) -> impl Fn(I) -> Describe<P> {
+            move |item| Describe(check(item), format)
+        }
+
+        impl<E: Error, F: Error> ResultChain<Or2<E, F>> for &&&&Tag<Or2<E, F>> {
+            fn describe(&self) -> Format<Or2<E, F>> {
+                |error, f| match error {
+                    Or2::T0(error) => chain(error, f),
+                    Or2::T1(error) => chain(error, f),
+                }
+            }
+        }
+
+        impl<E: fmt::Display, F: fmt::Display> ResultDisplay<Or2<E, F>> for &&&Tag<Or2<E, F>> {
+            fn describe(&self) -> Format<Or2<E, F>> {
+                |error, f| match error {
+                    Or2::T0(error) => fmt::Display::fmt(error, f),
+                    Or2::T1(error) => fmt::Display::fmt(error, f),
+                }
+            }
+        }
+
+        impl<E: Error> Chain<E> for &&Tag<E> {
+            fn describe(&self) -> Format<E> {
+                chain
+            }
+        }
+
+        impl<E: fmt::Display> Display<E> for &Tag<E> {
+            fn describe(&self) -> Format<E> {
+                fmt::Display::fmt
+            }
+        }
+
+        impl<E: fmt::Debug> Debug<E> for Tag<E> {
+            fn describe(&self) -> Format<E> {
+                fmt::Debug::fmt
+            }
+        }
+
+        /// Writes the error followed by its sources, separated by `: `.
+        fn chain<E: Error>(error: &E, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{error}")?;
+            let mut source = error.source();
+            while let Some(error) = source {
+                write!(f, ": {error}")?;
+                source = error.source();
+            }
+            Ok(())
+        }
+
+        impl<P: Prove> Prove for Describe<P> {
+            type Error = Described<P::Error>;
+            type Proof = P::Proof;
+
+            fn prove(self) -> Result<Self::Proof, Self::Error> {
+                let Self(proof, format) = self;
+                proof.prove().map_err(|error| Described(error, format))
+            }
+        }
+
+        impl<E> fmt::Debug for Described<E> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                (self.1)(&self.0, f)
+            }
+        }
+    }
 }
 
+/// Reporters format the results of the checks run by the `#[check]` macro. A
+/// custom reporter can be used with `#[check(reporter = MyReporter)]`.
+pub mod report {
+    use super::{Cause, Fail, Flaky, Hits, Pass, Saturation, Search, Skip};
+    use core::{
+        any::type_name,
+        fmt::{self, Arguments, Write},
+    };
+    use std::time::Duration;
+
+    /// Receives the results of a check run. The `prefix` of a result is the
+    /// colored tag (such as `PASS(3)`) that built-in reporters print in front of
+    /// it.
+    pub trait Reporter<T, P, E> {
+        /// An item was generated and passed the check (only in verbose mode).
+        fn pass(&self, prefix: Arguments, pass: Pass<T, P>);
+
+        /// A shrunk item passed the check (only in verbose mode).
+        fn shrink(&self, prefix: Arguments, pass: Pass<T, P>) {
+            self.pass(prefix, pass)
+        }
+
+        /// A shrunk item failed the check (only in verbose mode).
+        fn shrunk(&self, prefix: Arguments, fail: Fail<T, E>) {
+            self.fail(prefix, fail)
+        }
+
+        /// The final item that failed the check.
+        fn fail(&self, prefix: Arguments, fail: Fail<T, E>);
+
+        /// A failure that was not reproduced consistently (see
+        /// [`super::Shrinks::confirm`]).
+        fn flaky(&self, prefix: Arguments, flaky: Flaky<T, E>) {
+            self.fail(prefix, flaky.fail)
+        }
+
+        /// A shell command that replays the last failure (see
+        /// [`Fail::reproduce`]).
+        fn reproduce(&self, prefix: Arguments, command: &str) {
+            eprintln!("{prefix} {command}");
+        }
+
+        /// The statistics of the shrinking process of the last failure (only
+        /// when [`super::Shrinks::explain`] is set).
+        fn explain(&self, prefix: Arguments, search: &Search) {
+            eprintln!("{prefix} {search}");
+        }
+
+        /// An item was generated and its check was skipped (only in verbose
+        /// mode).
+        fn skip(&self, prefix: Arguments, skip: Skip<T>) {
+            println!("{prefix} {}", skip.reason);
+        }
+
+        /// Some items were skipped over the check run (see [`crate::skip!`]).
+        /// Called once after the checks, unless in quiet mode.
+        fn skipped(&self, prefix: Arguments, skips: usize) {
+            eprintln!("{prefix} {skips} items were skipped");
+        }
+
+        /// The branches chosen by a choice over the check run (see
+        /// [`super::Generates::hits`]). Called once per choice after the
+        /// checks.
+        fn hits(&self, prefix: Arguments, hits: &Hits) {
+            eprintln!("{prefix} {hits}");
+        }
+
+        /// The generator has fewer distinct items than the number of checks
+        /// (see [`super::Checker::saturation`]). Called once before the
+        /// checks, unless in quiet mode.
+        fn saturated(&self, prefix: Arguments, saturation: &Saturation) {
+            eprintln!("{prefix} {saturation}");
+        }
+
+        /// The checks were cancelled before completing (see
+        /// [`super::Checks::cancel_on`]). Called once after the checks.
+        fn cancelled(&self, prefix: Arguments) {
+            eprintln!("{prefix} the checks were cancelled before completing");
+        }
+
+        /// Called once at the end of the check run.
+        fn summary(&self, _summary: Summary) {}
+    }
+
+    /// The outcome of a check run.
+    #[derive(Clone, Debug)]
+    pub struct Summary<'a> {
+        /// The path of the test (such as `crate::module::test`).
+        pub name: &'a str,
+        /// The number of runs that ended with a failure.
+        pub failures: usize,
+        /// The number of runs that ended with a flaky failure.
+        pub flakes: usize,
+        /// The number of items that were skipped over all runs.
+        pub skips: usize,
+        /// Whether the checks were cancelled before completing.
+        pub cancelled: bool,
+        /// The time spent checking.
+        pub elapsed: Duration,
+    }
+
+    /// Prints the item, seed, size and proof or error message of each result.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Default;
+
+    /// Prints the full [`Pass`] or [`Fail`] of each result.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Debug;
+
+    /// Prints the item type, seed and size of each result, which does not
+    /// require the item to implement [`fmt::Debug`].
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Minimal;
+
+    /// Prints each result as a JSON object on its own line, for consumption by
+    /// other tools.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Json;
+
+    impl<T: fmt::Debug, P: fmt::Debug, E: fmt::Debug> Reporter<T, P, E> for Default {
+        fn pass(&self, prefix: Arguments, pass: Pass<T, P>) {
+            println!(
+                "{prefix} {{ item: {:?}, seed: {}, size: {}, proof: {:?} }}",
+                &pass.item,
+                pass.seed(),
+                pass.size(),
+                &pass.proof,
+            )
+        }
+
+        fn fail(&self, prefix: Arguments, fail: Fail<T, E>) {
+            eprintln!(
+                "{prefix} {{ item: {:?}, seed: {}, size: {}, message: \"{}\" }}",
+                &fail.item,
+                fail.seed(),
+                fail.size(),
+                fail.message(),
+            );
+            for context in &fail.context {
+                eprintln!("  {context}");
+            }
+        }
+    }
+
+    impl<T: fmt::Debug, P: fmt::Debug, E: fmt::Debug> Reporter<T, P, E> for Debug {
+        fn pass(&self, prefix: Arguments, pass: Pass<T, P>) {
+            println!("{prefix} {pass:?}")
+        }
+
+        fn fail(&self, prefix: Arguments, fail: Fail<T, E>) {
+            eprintln!("{prefix} {fail:?}")
+        }
+    }
+
+    impl<T, P, E> Reporter<T, P, E> for Minimal {
+        fn pass(&self, prefix: Arguments, pass: Pass<T, P>) {
+            println!(
+                "{prefix} {{ type: {}, seed: {}, size: {} }}",
+                type_name::<T>(),
+                pass.seed(),
+                pass.size(),
+            )
+        }
+
+        fn fail(&self, prefix: Arguments, fail: Fail<T, E>) {
+            eprintln!(
+                "{prefix} {{ type: {}, seed: {}, size: {} }}",
+                type_name::<T>(),
+                fail.seed(),
+                fail.size(),
+            )
+        }
+    }
+
+    impl Json {
+        fn print(kind: &str, fields: &[(&str, String)]) {
+            let mut line = format!("{{\"kind\":{}", string(kind));
+            for (key, value) in fields {
+                let _ = write!(line, ",{}:{value}", string(key));
+            }
+            line.push('}');
+            println!("{line}");
+        }
+
+        fn pass<T: fmt::Debug, P: fmt::Debug>(kind: &str, pass: Pass<T, P>) {
+            Self::print(
+                kind,
+                &[
+                    ("item", string(&format!("{:?}", pass.item))),
+                    ("generates", pass.generates.to_string()),
+                    ("shrinks", pass.shrinks.to_string()),
+                    ("seed", pass.seed().to_string()),
+                    ("size", number(pass.size())),
+                    ("proof", string(&format!("{:?}", pass.proof))),
+                ],
+            )
+        }
+
+        fn fail<T: fmt::Debug, E: fmt::Debug>(
+            kind: &str,
+            fail: Fail<T, E>,
+            fields: &[(&str, String)],
+        ) {
+            let panic = matches!(fail.cause, Cause::Panic(..));
+            let mut all = vec![
+                ("item", string(&format!("{:?}", fail.item))),
+                ("generates", fail.generates.to_string()),
+                ("shrinks", fail.shrinks.to_string()),
+                ("seed", fail.seed().to_string()),
+                ("size", number(fail.size())),
+                ("panic", panic.to_string()),
+                ("message", string(&fail.message())),
+            ];
+            all.extend_from_slice(fields);
+            Self::print(kind, &all)
+        }
+    }
+
+    impl<T: fmt::Debug, P: fmt::Debug, E: fmt::Debug> Reporter<T, P, E> for Json {
+        fn pass(&self, _: Arguments, pass: Pass<T, P>) {
+            Self::pass("pass", pass)
+        }
+
+        fn shrink(&self, _: Arguments, pass: Pass<T, P>) {
+            Self::pass("shrink", pass)
+        }
+
+        fn shrunk(&self, _: Arguments, fail: Fail<T, E>) {
+            Self::fail("shrunk", fail, &[])
+        }
+
+        fn fail(&self, _: Arguments, fail: Fail<T, E>) {
+            Self::fail("fail", fail, &[])
+        }
+
+        fn flaky(&self, _: Arguments, flaky: Flaky<T, E>) {
+            let fields = [
+                ("passes", flaky.passes.to_string()),
+                ("fails", flaky.fails.to_string()),
+            ];
+            Self::fail("flaky", flaky.fail, &fields)
+        }
+
+        fn reproduce(&self, _: Arguments, command: &str) {
+            Self::print("reproduce", &[("command", string(command))])
+        }
+
+        fn explain(&self, _: Arguments, search: &Search) {
+            let stop = match search.stop {
+                Some(stop) => string(&format!("{stop:?}")),
+                None => "null".into(),
+            };
+            let phases = search
+                .phases
+                .iter()
+                .map(|phase| {
+                    format!(
+                        "{{\"accepted\":{},\"rejected\":{},\"backtracks\":{}}}",
+                        phase.accepted, phase.rejected, phase.backtracks
+                    )
+                })
+                .collect::<Vec<_>>();
+            Self::print(
+                "explain",
+                &[
+                    ("accepted", search.accepted.to_string()),
+                    ("rejected", search.rejected.to_string()),
+                    ("backtracks", search.backtracks.to_string()),
+                    ("phases", format!("[{}]", phases.join(","))),
+                    ("stop", stop),
+                ],
+            )
+        }
+
+        fn skip(&self, _: Arguments, skip: Skip<T>) {
+            Self::print(
+                "skip",
+                &[
+                    ("item", string(&format!("{:?}", skip.item))),
+                    ("generates", skip.generates.to_string()),
+                    ("seed", skip.seed().to_string()),
+                    ("size", number(skip.size())),
+                    ("reason", string(&skip.reason)),
+                ],
+            )
+        }
+
+        fn skipped(&self, _: Arguments, skips: usize) {
+            Self::print("skipped", &[("skips", skips.to_string())])
+        }
+
+        fn cancelled(&self, _: Arguments) {
+            Self::print("cancelled", &[])
+        }
+
+        fn hits(&self, _: Arguments, hits: &Hits) {
+            let counts = hits
+                .counts
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            Self::print(
+                "hits",
+                &[
+                    ("name", string(&hits.name)),
+                    ("counts", format!("[{}]", counts.join(","))),
+                ],
+            )
+        }
+
+        fn saturated(&self, _: Arguments, saturation: &Saturation) {
+            Self::print(
+                "saturated",
+                &[
+                    ("cardinality", saturation.cardinality.to_string()),
+                    ("count", saturation.count.to_string()),
+                ],
+            )
+        }
+
+        fn summary(&self, summary: Summary) {
+            Self::print(
+                "summary",
+                &[
+                    ("name", string(summary.name)),
+                    ("failures", summary.failures.to_string()),
+                    ("flakes", summary.flakes.to_string()),
+                    ("skips", summary.skips.to_string()),
+                    ("cancelled", summary.cancelled.to_string()),
+                    ("elapsed", number(summary.elapsed.as_secs_f64())),
+                ],
+            )
+        }
+    }
+
+    /// Formats `value` as a JSON string.
+    fn string(value: &str) -> String {
+        let mut buffer = String::with_capacity(value.len() + 2);
+        buffer.push('"');
+        for symbol in value.chars() {
+            match symbol {
+                '"' => buffer.push_str("\\\""),
+                '\\' => buffer.push_str("\\\\"),
+                '\n' => buffer.push_str("\\n"),
+                '\r' => buffer.push_str("\\r"),
+                '\t' => buffer.push_str("\\t"),
+                symbol if symbol.is_control() => {
+                    let _ = write!(buffer, "\\u{:04x}", symbol as u32);
+                }
+                symbol => buffer.push(symbol),
+            }
+        }
+        buffer.push('"');
+        buffer
+    }
+
+    /// Formats `value` as a JSON number. JSON has no representation for `NaN` or
+    /// infinities, so they are formatted as `null`.
+    fn number(value: f64) -> String {
+        if value.is_finite() {
+            value.to_string()
+        } else {
+            "null".into()
+        }
+    }
+}
+
+/// `std::time::Instant::now` panics on targets without a clock (such as
+/// `wasm32-unknown-unknown`), where every measured duration is zero instead.
+mod clock {
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    pub use std::time::Instant;
+
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    #[derive(Clone, Copy, Debug)]
+    pub struct Instant;
+
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    impl Instant {
+        pub const fn now() -> Self {
+            Instant
+        }
+
+        pub const fn elapsed(&self) -> std::time::Duration {
+            std::time::Duration::ZERO
+        }
+    }
+}
+
+/// A process-wide panic hook that is shared by every running check.
+///
+/// The first call to [`hook::begin`] installs a dispatcher in place of the
+/// current hook and the last call to [`hook::end`] restores it, such that
+/// checks that run concurrently or that are nested do not clobber each other's
+/// hooks. The dispatcher forwards panics to the previous hook unless they
+/// happen on a thread that is running a [`hook::silent`] function.
 mod hook {
     use core::cell::Cell;
-    use std::panic;
+    use std::{
+        panic,
+        sync::{Arc, Mutex, PoisonError, RwLock},
+    };
 
     #[rustversion::since(1.81)]
-    type Handle = Box<dyn Fn(&panic::PanicHookInfo) + 'static + Sync + Send>;
+    type Info<'a> = panic::PanicHookInfo<'a>;
     #[rustversion::before(1.81)]
-    type Handle = Box<dyn Fn(&panic::PanicInfo) + 'static + Sync + Send>;
-    thread_local! { static HOOK: Cell<Option<Handle>> = const { Cell::new(None) }; }
+    type Info<'a> = panic::PanicInfo<'a>;
+    type Handle = dyn Fn(&Info) + 'static + Sync + Send;
+
+    // `USERS` is held while the hook is replaced, but never by the dispatcher, since
+    // `std` holds its own hook lock while the dispatcher runs.
+    static USERS: Mutex<usize> = Mutex::new(0);
+    static PREVIOUS: RwLock<Option<Arc<Handle>>> = RwLock::new(None);
+    thread_local! { static SILENT: Cell<usize> = const { Cell::new(0) }; }
+
+    #[cfg(panic = "unwind")]
+    struct Silent;
+
+    #[cfg(panic = "unwind")]
+    impl Drop for Silent {
+        fn drop(&mut self) {
+            SILENT.with(|silent| silent.set(silent.get() - 1));
+        }
+    }
+
+    fn dispatch(panic: &Info) {
+        if SILENT.with(Cell::get) > 0 {
+            return;
+        }
+        let previous = PREVIOUS
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        if let Some(previous) = previous {
+            previous(panic);
+        }
+    }
 
     pub fn begin() {
-        HOOK.with(|cell| cell.set(Some(panic::take_hook())));
-        panic::set_hook(Box::new(|panic| {
-            HOOK.with(|cell| {
-                if let Some(hook) = cell.replace(None) {
-                    hook(panic);
-                    cell.set(Some(hook));
-                }
-            });
-        }));
+        let mut users = USERS.lock().unwrap_or_else(PoisonError::into_inner);
+        if *users == 0 {
+            let previous = Arc::from(panic::take_hook());
+            *PREVIOUS.write().unwrap_or_else(PoisonError::into_inner) = Some(previous);
+            panic::set_hook(Box::new(dispatch));
+        }
+        *users += 1;
     }
 
+    /// When panics abort (such as on `wasm32-unknown-unknown`), a panicking
+    /// check can not be caught and its message is the only trace of the
+    /// failure, so it is never silenced.
+    #[cfg(not(panic = "unwind"))]
+    pub fn silent<I, O>(function: impl Fn(I) -> O) -> impl Fn(I) -> O {
+        function
+    }
+
+    #[cfg(panic = "unwind")]
     pub fn silent<I, O>(function: impl Fn(I) -> O) -> impl Fn(I) -> O {
         move |input| {
-            HOOK.with(|cell| {
-                let hook = cell.replace(None);
-                let output = function(input);
-                cell.set(hook);
-                output
-            })
+            SILENT.with(|silent| silent.set(silent.get() + 1));
+            // The guard restores the count even if `function` panics.
+            let _silent = Silent;
+            function(input)
         }
     }
 
     pub fn end() {
-        HOOK.with(|cell| {
-            if let Some(hook) = cell.take() {
-                panic::set_hook(hook);
+        let mut users = USERS.lock().unwrap_or_else(PoisonError::into_inner);
+        *users = users.saturating_sub(1);
+        if *users == 0 {
+            let previous = PREVIOUS
+                .write()
+                .unwrap_or_else(PoisonError::into_inner)
+                .take();
+            if let Some(previous) = previous {
+                panic::set_hook(Box::new(move |panic| previous(panic)));
             }
-        });
+        }
     }
 
     pub fn panic() -> ! {
@@ -787,82 +3045,572 @@ mod hook {
 }
 
 mod environment {
-    use super::Checker;
-    use core::str::FromStr;
+    use super::{Checker, FromStr, Ramp};
     use std::env;
 
     mod generate {
         use super::*;
 
-        pub fn count() -> Option<usize> {
-            parse("CHECKITO_GENERATE_COUNT")
+        pub fn count(name: &str) -> Option<usize> {
+            parse("CHECKITO_GENERATE_COUNT", name)
         }
 
-        pub fn size() -> Option<f64> {
-            parse("CHECKITO_GENERATE_SIZE")
+        pub fn size(name: &str) -> Option<f64> {
+            parse("CHECKITO_GENERATE_SIZE", name)
         }
 
-        pub fn seed() -> Option<u64> {
-            parse("CHECKITO_GENERATE_SEED")
+        pub fn seed(name: &str) -> Option<u64> {
+            parse("CHECKITO_GENERATE_SEED", name)
         }
 
-        pub fn items() -> Option<bool> {
-            parse("CHECKITO_GENERATE_ITEMS")
+        pub fn items(name: &str) -> Option<bool> {
+            parse("CHECKITO_GENERATE_ITEMS", name)
         }
 
-        pub fn update<G>(checker: &mut Checker<'_, G>) {
-            if let Some(value) = size() {
+        pub fn runs(name: &str) -> Option<usize> {
+            parse("CHECKITO_GENERATE_RUNS", name)
+        }
+
+        pub fn ramp(name: &str) -> Option<Ramp> {
+            parse("CHECKITO_GENERATE_RAMP", name)
+        }
+
+        pub fn skips(name: &str) -> Option<f64> {
+            parse("CHECKITO_GENERATE_SKIPS", name)
+        }
+
+        pub fn update<G>(checker: &mut Checker<'_, G>, name: &str) {
+            if let Some(value) = size(name) {
                 checker.generate.size = (value..=value).into();
             }
-            if let Some(value) = count() {
+            if let Some(value) = count(name) {
                 checker.generate.count = value;
             }
-            if let Some(value) = seed() {
+            if let Some(value) = seed(name) {
                 checker.generate.seed = value;
             }
-            if let Some(value) = items() {
+            if let Some(value) = items(name) {
                 checker.generate.items = value;
             }
+            if let Some(value) = runs(name) {
+                checker.generate.runs = value;
+            }
+            if let Some(value) = ramp(name) {
+                checker.generate.ramp = value;
+            }
+            if let Some(value) = skips(name) {
+                checker.generate.skips = value;
+            }
         }
     }
 
     mod shrink {
         use super::*;
 
-        pub fn count() -> Option<usize> {
-            parse("CHECKITO_SHRINK_COUNT")
+        pub fn count(name: &str) -> Option<usize> {
+            parse("CHECKITO_SHRINK_COUNT", name)
+        }
+
+        pub fn items(name: &str) -> Option<bool> {
+            parse("CHECKITO_SHRINK_ITEMS", name)
+        }
+
+        pub fn errors(name: &str) -> Option<bool> {
+            parse("CHECKITO_SHRINK_ERRORS", name)
         }
 
-        pub fn items() -> Option<bool> {
-            parse("CHECKITO_SHRINK_ITEMS")
+        pub fn confirm(name: &str) -> Option<usize> {
+            parse("CHECKITO_SHRINK_CONFIRM", name)
         }
 
-        pub fn errors() -> Option<bool> {
-            parse("CHECKITO_SHRINK_ERRORS")
+        pub fn explain(name: &str) -> Option<bool> {
+            parse("CHECKITO_SHRINK_EXPLAIN", name)
         }
 
-        pub fn update<G>(checker: &mut Checker<'_, G>) {
-            if let Some(value) = count() {
+        pub fn update<G>(checker: &mut Checker<'_, G>, name: &str) {
+            if let Some(value) = count(name) {
                 checker.shrink.count = value;
             }
-            if let Some(value) = items() {
+            if let Some(value) = items(name) {
                 checker.shrink.items = value;
             }
-            if let Some(value) = errors() {
+            if let Some(value) = errors(name) {
                 checker.shrink.errors = value;
             }
+            if let Some(value) = confirm(name) {
+                checker.shrink.confirm = value;
+            }
+            if let Some(value) = explain(name) {
+                checker.shrink.explain = value;
+            }
+        }
+    }
+
+    pub fn update<G>(checker: &mut Checker<'_, G>, name: &str) {
+        generate::update(checker, name);
+        shrink::update(checker, name);
+    }
+
+    /// Parses the variable `key` scoped to the test `name` (a path such as
+    /// `module::test`) if it exists and falls back to the unscoped `key`. A
+    /// variable is scoped by appending any suffix of the test path to its key
+    /// where `::` is replaced by `__` (such as `KEY__module__test` or
+    /// `KEY__test`). The most specific scope wins.
+    fn parse<T: FromStr>(key: &str, name: &str) -> Option<T> {
+        let segments = name.split("::").collect::<Vec<_>>();
+        (0..segments.len())
+            .filter(|&index| !segments[index].is_empty())
+            .map(|index| format!("{key}__{}", segments[index..].join("__")))
+            .chain([key.to_string()])
+            .find_map(|key| env::var(key).ok())
+            .and_then(|value| value.parse().ok())
+    }
+}
+
+#[cfg(feature = "asynchronous")]
+mod asynchronous {
+    use super::{Checks, Generate, Prove, Result};
+    use core::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+    use futures_core::Stream;
+
+    // The `Checks` machine never relies on being pinned.
+    impl<G: Generate + ?Sized, E, F> Unpin for Checks<'_, G, E, F> {}
+
+    /// Allows a synchronous [`Checks`] run to be driven as a [`Stream`]. Each
+    /// call to [`Stream::poll_next`] runs the machine until it produces its
+    /// next result and is always ready.
+    impl<G: Generate + ?Sized, P: Prove, F: FnMut(G::Item) -> P> Stream for Checks<'_, G, P::Error, F> {
+        type Item = Result<G::Item, P>;
+
+        fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(Iterator::next(self.get_mut()))
+        }
+    }
+}
+
+#[cfg(feature = "bench")]
+pub mod bench {
+    use super::{Checker, Generate, Prove, Shrink, State, clock::Instant, hook};
+    use core::{cell::Cell, fmt};
+    use std::time::Duration;
+
+    /// The measurements of a phase of the checking process.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Phase {
+        /// The number of operations that were measured.
+        pub count: usize,
+        /// The time spent in the measured operations.
+        pub duration: Duration,
+    }
+
+    /// The report produced by [`Checker::bench`].
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Bench {
+        /// Generation of items (including the retrieval of the item from its
+        /// shrinker).
+        pub generate: Phase,
+        /// Calls to the property, both for generated and shrunk items.
+        pub check: Phase,
+        /// Production of shrunk candidates (excluding their checks).
+        pub shrink: Phase,
+    }
+
+    /// A generator whose generation and shrinking are measured in `bench`.
+    struct Timed<'a, G: ?Sized> {
+        generator: &'a G,
+        bench: &'a Cells,
+    }
+
+    #[derive(Clone)]
+    struct Shrinker<'a, S> {
+        shrinker: S,
+        bench: &'a Cells,
+    }
+
+    #[derive(Default)]
+    struct Cells {
+        generate: Cell<Phase>,
+        check: Cell<Phase>,
+        shrink: Cell<Phase>,
+    }
+
+    impl Phase {
+        /// The number of operations per second.
+        pub fn throughput(&self) -> f64 {
+            self.count as f64 / self.duration.as_secs_f64().max(f64::EPSILON)
+        }
+
+        fn measure<T>(phase: &Cell<Self>, run: impl FnOnce() -> T) -> T {
+            let start = Instant::now();
+            let value = run();
+            let Self { count, duration } = phase.get();
+            phase.set(Self {
+                count: count + 1,
+                duration: duration + start.elapsed(),
+            });
+            value
+        }
+    }
+
+    impl<G: Generate + ?Sized> Checker<'_, G> {
+        /// Runs the checking process (see [`Checker::checks`]) while measuring
+        /// the generation, checking and shrinking phases separately. Since the
+        /// same process is measured, every setting of the checker applies, such
+        /// as [`super::Generates::runs`], [`super::Generates::ramp`] or
+        /// [`super::Shrinks::strategy`]. Panics of the property are silenced
+        /// like in the `#[check]` macro.
+        pub fn bench<P: Prove, F: Fn(G::Item) -> P>(&self, check: F) -> Bench {
+            let bench = Cells::default();
+            let generator = Timed {
+                generator: self.generator,
+                bench: &bench,
+            };
+            let mut checker = Checker::new(&generator, self.generate.seed);
+            checker.generate = self.generate.clone();
+            checker.shrink = self.shrink.clone();
+            checker.generate.items = false;
+            checker.shrink.items = false;
+            checker.shrink.errors = false;
+            let check = |item| Phase::measure(&bench.check, || check(item));
+            hook::begin();
+            checker.checks(hook::silent(check)).for_each(drop);
+            hook::end();
+            Bench {
+                generate: bench.generate.get(),
+                check: bench.check.get(),
+                shrink: bench.shrink.get(),
+            }
         }
     }
 
-    pub fn update<G>(checker: &mut Checker<'_, G>) {
-        generate::update(checker);
-        shrink::update(checker);
+    impl<'a, G: Generate + ?Sized> Generate for Timed<'a, G> {
+        type Item = G::Item;
+        type Shrink = Shrinker<'a, G::Shrink>;
+
+        fn generate(&self, state: &mut State) -> Self::Shrink {
+            Shrinker {
+                shrinker: Phase::measure(&self.bench.generate, || self.generator.generate(state)),
+                bench: self.bench,
+            }
+        }
+
+        fn constant(&self) -> bool {
+            self.generator.constant()
+        }
+
+        fn cardinality(&self) -> Option<u128> {
+            self.generator.cardinality()
+        }
     }
 
-    fn parse<T: FromStr>(key: &str) -> Option<T> {
-        match env::var(key) {
-            Ok(value) => value.parse().ok(),
-            Err(_) => None,
+    impl<S: Shrink> Shrink for Shrinker<'_, S> {
+        type Item = S::Item;
+
+        fn item(&self) -> Self::Item {
+            self.shrinker.item()
+        }
+
+        fn shrink(&mut self) -> Option<Self> {
+            let shrinker = Phase::measure(&self.bench.shrink, || self.shrinker.shrink())?;
+            Some(Self {
+                shrinker,
+                bench: self.bench,
+            })
+        }
+
+        fn release(&mut self) -> bool {
+            self.shrinker.release()
+        }
+    }
+
+    impl fmt::Display for Phase {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "{} in {:?} ({:.0}/s)",
+                self.count,
+                self.duration,
+                self.throughput()
+            )
+        }
+    }
+
+    impl fmt::Display for Bench {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            writeln!(f, "generate: {}", self.generate)?;
+            writeln!(f, "check: {}", self.check)?;
+            write!(f, "shrink: {}", self.shrink)
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
+pub mod parallel {
+    use super::{Checker, Generate, Machine, Prove, Result, Shrink, handle, hook};
+    use core::cell::Cell;
+    use std::{
+        panic::{AssertUnwindSafe, catch_unwind, resume_unwind},
+        sync::{
+            Arc, Mutex, PoisonError, Weak,
+            atomic::{AtomicUsize, Ordering},
+            mpsc::{Receiver, Sender, channel},
+        },
+        thread::{self, JoinHandle},
+    };
+
+    /// Configures the pool of worker threads used by [`Checker::parallel`].
+    ///
+    /// Pools are created on first use and shared by every check (in the same
+    /// process) that uses an equal configuration while it runs. A pool is
+    /// released and its worker threads are joined when the last check that
+    /// uses it ends.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct Config {
+        /// Number of worker threads. A value of `0` is treated as `1`.
+        ///
+        /// Defaults to [`thread::available_parallelism`].
+        pub threads: usize,
+        /// Stack size of the worker threads in bytes.
+        ///
+        /// Defaults to `None`, which uses the standard library's default.
+        pub stack: Option<usize>,
+        /// Whether the worker threads are pinned to cores. Pinning is not
+        /// supported since it requires platform specific `unsafe` code, so
+        /// [`Checker::parallel`] panics when it is set.
+        ///
+        /// Defaults to `false`.
+        pub pin: bool,
+    }
+
+    type Job = Box<dyn FnOnce() + Send>;
+
+    struct Pool {
+        sender: Mutex<Option<Sender<Job>>>,
+        workers: Vec<JoinHandle<()>>,
+    }
+
+    static POOLS: Mutex<Vec<(Config, Weak<Pool>)>> = Mutex::new(Vec::new());
+    thread_local! { static WORKER: Cell<bool> = const { Cell::new(false) }; }
+
+    impl Default for Config {
+        fn default() -> Self {
+            Self {
+                threads: thread::available_parallelism().map_or(1, usize::from),
+                stack: None,
+                pin: false,
+            }
+        }
+    }
+
+    impl Config {
+        /// Sets [`Config::threads`].
+        #[must_use]
+        pub const fn with_threads(mut self, threads: usize) -> Self {
+            self.threads = threads;
+            self
+        }
+
+        /// Sets [`Config::stack`].
+        #[must_use]
+        pub const fn with_stack(mut self, stack: usize) -> Self {
+            self.stack = Some(stack);
+            self
+        }
+
+        /// Sets [`Config::pin`].
+        #[must_use]
+        pub const fn with_pin(mut self, pin: bool) -> Self {
+            self.pin = pin;
+            self
+        }
+
+        const fn workers(&self) -> usize {
+            if self.threads == 0 { 1 } else { self.threads }
+        }
+    }
+
+    impl Pool {
+        fn get(config: Config) -> Arc<Pool> {
+            let mut pools = POOLS.lock().unwrap_or_else(PoisonError::into_inner);
+            pools.retain(|(_, pool)| pool.strong_count() > 0);
+            let pool = pools
+                .iter()
+                .filter(|(key, _)| *key == config)
+                .find_map(|(_, pool)| pool.upgrade());
+            if let Some(pool) = pool {
+                return pool;
+            }
+
+            let (sender, receiver) = channel::<Job>();
+            let receiver = Arc::new(Mutex::new(receiver));
+            let mut workers = Vec::with_capacity(config.workers());
+            for index in 0..config.workers() {
+                let receiver = receiver.clone();
+                let mut builder = thread::Builder::new().name(format!("checkito-{index}"));
+                if let Some(stack) = config.stack {
+                    builder = builder.stack_size(stack);
+                }
+                workers.push(
+                    builder
+                        .spawn(move || work(&receiver))
+                        .expect("failed to spawn a worker thread"),
+                );
+            }
+            let pool = Arc::new(Pool {
+                sender: Mutex::new(Some(sender)),
+                workers,
+            });
+            pools.push((config, Arc::downgrade(&pool)));
+            pool
+        }
+
+        fn run(&self, job: Job) {
+            self.sender
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .as_ref()
+                .expect("the sender is only dropped with the pool")
+                .send(job)
+                .expect("the worker threads are only stopped with the pool");
+        }
+    }
+
+    impl Drop for Pool {
+        fn drop(&mut self) {
+            // Dropping the sender stops the workers once they are done with their jobs.
+            drop(
+                self.sender
+                    .get_mut()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .take(),
+            );
+            for worker in self.workers.drain(..) {
+                let _ = worker.join();
+            }
+        }
+    }
+
+    fn work(receiver: &Mutex<Receiver<Job>>) {
+        WORKER.with(|worker| worker.set(true));
+        loop {
+            // The lock is released as soon as a job is received such that other
+            // workers can receive jobs while this one runs.
+            let job = receiver
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .recv();
+            match job {
+                Ok(job) => job(),
+                Err(_) => break,
+            }
+        }
+    }
+
+    impl<G: Generate + Clone + Send + Sync + 'static> Checker<'_, G> {
+        /// Checks the generated items on a pool of worker threads configured
+        /// by `config` and then shrinks the failing item with the lowest
+        /// index, if any, on the current thread. Since items are generated
+        /// from their index, the shrinking process is the same as with
+        /// [`Checker::checks`].
+        ///
+        /// Only the final [`Result::Fail`] or [`Result::Flaky`] is produced
+        /// and only a single run is performed (see [`super::Generates::runs`]).
+        /// If it is called from a check that already runs on a worker thread,
+        /// the items are checked on that thread instead.
+        ///
+        /// # Panics
+        /// If [`Config::pin`] is set.
+        pub fn parallel<P: Prove, F: Fn(G::Item) -> P + Send + Sync + 'static>(
+            &self,
+            config: Config,
+            check: F,
+        ) -> Option<Result<G::Item, P>> {
+            assert!(
+                !config.pin,
+                "pinning worker threads to cores is not supported, since it requires platform \
+                 specific unsafe code"
+            );
+            let count = if self.generator.constant() {
+                self.generate.count.min(1)
+            } else {
+                self.generate.count
+            };
+            let generator = Arc::new(self.generator.clone());
+            let check = Arc::new(check);
+            let failed = Arc::new(AtomicUsize::new(usize::MAX));
+            // A check that runs on a worker thread can not wait for jobs queued on its
+            // own pool without risking a deadlock, so nested checks run their jobs inline.
+            let pool = if WORKER.with(Cell::get) {
+                None
+            } else {
+                Some(Pool::get(config))
+            };
+            let jobs = (config.workers() * 4).min(count.max(1));
+            let chunk = count / jobs + usize::from(count % jobs > 0);
+            let (sender, receiver) = channel();
+
+            hook::begin();
+            for job in 0..jobs {
+                let (generator, check, failed, sender) = (
+                    generator.clone(),
+                    check.clone(),
+                    failed.clone(),
+                    sender.clone(),
+                );
+                let generates = self.generate.clone();
+                let job: Job = Box::new(move || {
+                    // Panics are silenced on the worker thread that runs the check.
+                    let check = hook::silent(|item| check(item));
+                    let result = catch_unwind(AssertUnwindSafe(|| {
+                        for index in job * chunk..count.min((job + 1) * chunk) {
+                            // A failure was already found at a lower index.
+                            if index >= failed.load(Ordering::Relaxed) {
+                                break;
+                            }
+                            let mut state = generates.state(index, count);
+                            let item = generator.generate(&mut state).item();
+                            if handle(item, &check).is_err() {
+                                failed.fetch_min(index, Ordering::Relaxed);
+                                break;
+                            }
+                        }
+                    }));
+                    let _ = sender.send(result);
+                });
+                match &pool {
+                    Some(pool) => pool.run(job),
+                    None => job(),
+                }
+            }
+            drop(sender);
+            for result in receiver {
+                if let Err(payload) = result {
+                    hook::end();
+                    resume_unwind(payload);
+                }
+            }
+            // The pool is released before shrinking if no other check uses it.
+            drop(pool);
+
+            let index = failed.load(Ordering::Relaxed);
+            if index == usize::MAX {
+                hook::end();
+                return None;
+            }
+            let mut checker = self.clone();
+            checker.generate.runs = 1;
+            checker.generate.items = false;
+            checker.generate.progress = None;
+            checker.shrink.items = false;
+            checker.shrink.errors = false;
+            let mut checks = checker.checks(hook::silent(|item| check(item)));
+            checks.machine = Machine::Generate { index };
+            let result = checks.next();
+            hook::end();
+            result
         }
     }
 }
@@ -0,0 +1,160 @@
+use crate::{
+    cardinality,
+    generate::{Generate, State},
+    shrink::Shrink,
+};
+use core::mem::replace;
+
+/// Selects distinct items from a slice without replacement (see
+/// [`crate::choose()`] and [`crate::subset`]).
+#[derive(Clone, Debug)]
+pub struct Choose<'a, T> {
+    pub(crate) items: &'a [T],
+    /// The number of selected items, or `None` for any number of them.
+    pub(crate) count: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Shrinker<'a, T> {
+    items: &'a [T],
+    /// The selected indices, in ascending order.
+    indices: Vec<usize>,
+    machine: Machine,
+}
+
+#[derive(Clone, Debug)]
+enum Machine {
+    /// Removes the index at `position`.
+    Remove(usize),
+    /// Moves the index at `position` halfway toward `low`, the lowest index that
+    /// was not rejected yet.
+    Move {
+        position: usize,
+        low: usize,
+    },
+    Done,
+}
+
+impl<'a, T: Clone> Generate for Choose<'a, T> {
+    type Item = Vec<T>;
+    type Shrink = Shrinker<'a, T>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let length = self.items.len();
+        let indices = match self.count {
+            Some(count) => {
+                // A partial Fisher-Yates shuffle selects `count` indices uniformly.
+                let mut indices = Iterator::collect::<Vec<_>>(0..length);
+                for index in 0..count {
+                    let other = state.random().usize(index..length);
+                    indices.swap(index, other);
+                }
+                indices.truncate(count);
+                indices.sort_unstable();
+                indices
+            }
+            None => Iterator::filter(0..length, |_| state.random().bool()).collect::<Vec<_>>(),
+        };
+        Shrinker {
+            items: self.items,
+            indices,
+            machine: if self.count.is_some() {
+                Machine::Move {
+                    position: 0,
+                    low: 0,
+                }
+            } else {
+                Machine::Remove(0)
+            },
+        }
+    }
+
+    fn constant(&self) -> bool {
+        match self.count {
+            Some(count) => count == 0 || count == self.items.len(),
+            None => self.items.is_empty(),
+        }
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        let length = self.items.len();
+        match self.count {
+            Some(count) => cardinality::binomial(length, count),
+            None => u32::try_from(length)
+                .ok()
+                .and_then(|length| cardinality::power(Some(2), length)),
+        }
+    }
+}
+
+impl<T: Clone> Shrinker<'_, T> {
+    /// The lowest index that the index at `position` may move to while the
+    /// indices remain distinct and ascending.
+    fn floor(&self, position: usize) -> usize {
+        match position.checked_sub(1) {
+            Some(previous) => self.indices[previous] + 1,
+            None => 0,
+        }
+    }
+}
+
+impl<T: Clone> Shrink for Shrinker<'_, T> {
+    type Item = Vec<T>;
+
+    fn item(&self) -> Self::Item {
+        self.indices
+            .iter()
+            .map(|&index| self.items[index].clone())
+            .collect()
+    }
+
+    /// Removes items first (unless the number of items is fixed) and then
+    /// moves the remaining ones toward the start of the slice.
+    fn shrink(&mut self) -> Option<Self> {
+        loop {
+            match replace(&mut self.machine, Machine::Done) {
+                Machine::Remove(position) if position < self.indices.len() => {
+                    let mut indices = self.indices.clone();
+                    indices.remove(position);
+                    self.machine = Machine::Remove(position + 1);
+                    break Some(Self {
+                        items: self.items,
+                        indices,
+                        machine: Machine::Remove(position),
+                    });
+                }
+                Machine::Remove(_) => {
+                    self.machine = Machine::Move {
+                        position: 0,
+                        low: self.floor(0),
+                    }
+                }
+                Machine::Move { position, low } if position < self.indices.len() => {
+                    let current = self.indices[position];
+                    if low >= current {
+                        self.machine = Machine::Move {
+                            position: position + 1,
+                            low: self.floor(position + 1),
+                        };
+                        continue;
+                    }
+                    // A rejected target raises the lower bound such that the search
+                    // converges on the lowest accepted index.
+                    let target = low + (current - low) / 2;
+                    let mut indices = self.indices.clone();
+                    indices[position] = target;
+                    self.machine = Machine::Move {
+                        position,
+                        low: target + 1,
+                    };
+                    break Some(Self {
+                        items: self.items,
+                        indices,
+                        machine: Machine::Move { position, low },
+                    });
+                }
+                Machine::Move { .. } | Machine::Done => break None,
+            }
+        }
+    }
+}
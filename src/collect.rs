@@ -1,10 +1,11 @@
 use crate::{
-    COLLECT, all,
+    COLLECT, all, cardinality,
     generate::{FullGenerate, Generate, State},
-    primitive::{self, Direction, Full},
+    primitive::{self, Direction},
     shrink::Shrink,
 };
 use core::{marker::PhantomData, mem::replace, ops::RangeInclusive};
+use std::collections::{BinaryHeap, VecDeque};
 
 #[derive(Debug)]
 pub struct Collect<I: ?Sized, C, F: ?Sized> {
@@ -41,6 +42,58 @@ impl<G: Generate, F: FromIterator<G::Item>> Collect<G, RangeInclusive<usize>, F>
     }
 }
 
+/// The smallest length that `count` may generate, which is found by fully
+/// shrinking a length (such as the start of a range).
+pub(crate) fn minimum<C: Generate<Item = usize> + ?Sized>(count: &C) -> usize {
+    let mut shrinker = count.generate(&mut State::new(0, 1, 0.0, 0));
+    while let Some(shrunk) = shrinker.shrink() {
+        shrinker = shrunk;
+    }
+    shrinker.item()
+}
+
+/// The smallest and largest lengths that `count` may generate, if they are
+/// known. The lengths are assumed to be contiguous (as with a range) only if
+/// the generator recognizes both bounds but not the lengths just outside of
+/// them (see [`Generate::shrink_from`]).
+pub(crate) fn bounds<C: Generate<Item = usize> + ?Sized>(count: &C) -> Option<(usize, usize)> {
+    let cardinality = usize::try_from(count.cardinality()?).ok()?;
+    let minimum = minimum(count);
+    let maximum = minimum.checked_add(cardinality.checked_sub(1)?)?;
+    if cardinality == 1 {
+        return Some((minimum, maximum));
+    }
+    let below = minimum
+        .checked_sub(1)
+        .map_or(true, |length| count.shrink_from(length).is_none());
+    let above = maximum
+        .checked_add(1)
+        .map_or(true, |length| count.shrink_from(length).is_none());
+    (below && above && count.shrink_from(maximum).is_some()).then_some((minimum, maximum))
+}
+
+/// Generates a collection length using the lengths `size` of the `state` if it
+/// was overridden (see [`crate::Generate::dampen_lengths`]).
+pub(crate) fn length<C: Generate<Item = usize> + ?Sized>(count: &C, state: &mut State) -> usize {
+    counter(count, state).item()
+}
+
+/// Same as [`length`] but produces the shrinker of the length.
+pub(crate) fn counter<C: Generate<Item = usize> + ?Sized>(
+    count: &C,
+    state: &mut State,
+) -> C::Shrink {
+    match state.lengths {
+        Some(lengths) => {
+            let old = replace(&mut state.size, lengths);
+            let count = count.generate(state);
+            state.size = old;
+            count
+        }
+        None => count.generate(state),
+    }
+}
+
 impl<S: Shrink, F: FromIterator<S::Item>> Shrinker<S, F> {
     pub(crate) fn new(shrinkers: impl IntoIterator<Item = S>, minimum: Option<usize>) -> Self {
         let shrinkers = shrinkers.into_iter().collect::<Vec<_>>();
@@ -89,7 +142,7 @@ impl<G: Generate + ?Sized, C: Generate<Item = usize>, F: FromIterator<G::Item>>
     type Shrink = Shrinker<G::Shrink, F>;
 
     fn generate(&self, state: &mut State) -> Self::Shrink {
-        let count = self.count.generate(state).item();
+        let count = length(&self.count, state);
         let shrinkers = Iterator::map(0..count, |_| self.generator.generate(state));
         Shrinker::new(shrinkers, self.minimum)
     }
@@ -97,6 +150,16 @@ impl<G: Generate + ?Sized, C: Generate<Item = usize>, F: FromIterator<G::Item>>
     fn constant(&self) -> bool {
         self.count.constant() && self.generator.constant()
     }
+
+    /// The sum of the cardinalities of every length, which is only known when
+    /// the lengths are bounded by a range.
+    fn cardinality(&self) -> Option<u128> {
+        if self.count.cardinality()? == 0 {
+            return Some(0);
+        }
+        let (minimum, maximum) = bounds(&self.count)?;
+        cardinality::series(self.generator.cardinality(), minimum, maximum)
+    }
 }
 
 impl<S: Shrink, F: FromIterator<S::Item>> Shrink for Shrinker<S, F> {
@@ -157,6 +220,15 @@ impl<S: Shrink, F: FromIterator<S::Item>> Shrink for Shrinker<S, F> {
             }
         }
     }
+
+    fn release(&mut self) -> bool {
+        let mut index = 0;
+        let released = all::release(&mut self.shrinkers, &mut index);
+        if released {
+            self.machine = Machine::Shrink(index);
+        }
+        released
+    }
 }
 
 impl<G: FullGenerate> FullGenerate for Vec<G> {
@@ -168,11 +240,23 @@ impl<G: FullGenerate> FullGenerate for Vec<G> {
     }
 }
 
-impl FullGenerate for String {
-    type Generator = Collect<Full<char>, RangeInclusive<usize>, Self::Item>;
-    type Item = String;
+impl<G: FullGenerate> FullGenerate for VecDeque<G> {
+    type Generator = Collect<G::Generator, RangeInclusive<usize>, Self::Item>;
+    type Item = VecDeque<G::Item>;
+
+    fn generator() -> Self::Generator {
+        Collect::new(G::generator())
+    }
+}
+
+impl<G: FullGenerate> FullGenerate for BinaryHeap<G>
+where
+    G::Item: Ord,
+{
+    type Generator = Collect<G::Generator, RangeInclusive<usize>, Self::Item>;
+    type Item = BinaryHeap<G::Item>;
 
     fn generator() -> Self::Generator {
-        Collect::new(char::generator())
+        Collect::new(G::generator())
     }
 }
@@ -0,0 +1,76 @@
+use crate::{
+    cardinality,
+    generate::{Generate, State},
+    shrink::Shrink,
+};
+
+/// A generator that yields the items of either of its generators, which are
+/// chosen proportionally to their cardinality (see [`Generate::concat`]).
+#[derive(Clone, Debug)]
+pub struct Concat<L, R: ?Sized> {
+    pub(crate) left: L,
+    pub(crate) right: R,
+}
+
+#[derive(Clone, Debug)]
+pub enum Shrinker<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L: Generate, R: Generate<Item = L::Item> + ?Sized> Concat<L, R> {
+    /// The probability of choosing the `left` generator. When a cardinality is
+    /// unknown, both generators are equally likely.
+    fn ratio(&self) -> f64 {
+        match (self.left.cardinality(), self.right.cardinality()) {
+            (Some(0), Some(0)) | (None, _) | (_, None) => 0.5,
+            (Some(left), Some(right)) => left as f64 / (left as f64 + right as f64),
+        }
+    }
+}
+
+impl<L: Generate, R: Generate<Item = L::Item> + ?Sized> Generate for Concat<L, R> {
+    type Item = L::Item;
+    type Shrink = Shrinker<L::Shrink, R::Shrink>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        if state.ratio() < self.ratio() {
+            Shrinker::Left(self.left.generate(state))
+        } else {
+            Shrinker::Right(self.right.generate(state))
+        }
+    }
+
+    fn constant(&self) -> bool {
+        self.left.constant() && self.right.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        cardinality::sum(self.left.cardinality(), self.right.cardinality())
+    }
+}
+
+impl<L: Shrink, R: Shrink<Item = L::Item>> Shrink for Shrinker<L, R> {
+    type Item = L::Item;
+
+    fn item(&self) -> Self::Item {
+        match self {
+            Shrinker::Left(shrinker) => shrinker.item(),
+            Shrinker::Right(shrinker) => shrinker.item(),
+        }
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        match self {
+            Shrinker::Left(shrinker) => Some(Shrinker::Left(shrinker.shrink()?)),
+            Shrinker::Right(shrinker) => Some(Shrinker::Right(shrinker.shrink()?)),
+        }
+    }
+
+    fn release(&mut self) -> bool {
+        match self {
+            Shrinker::Left(shrinker) => shrinker.release(),
+            Shrinker::Right(shrinker) => shrinker.release(),
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Generators constructed from a runtime configuration.
+//!
+//! A [`FromConfig`] generator is built from a plain configuration value that
+//! is validated before the generator is constructed. Since configurations
+//! implement [`Deserialize`], a [`Configure`] generator can be read from any
+//! format supported by `serde` (such as JSON or TOML) such that distributions
+//! can be adjusted without recompiling the tests:
+//!
+//! ```
+//! use checkito::{any::Weight, config::Configure, *};
+//! use std::ops::RangeInclusive;
+//!
+//! type Lengths = Vec<Weight<RangeInclusive<usize>>>;
+//! let json = r#"[[9.0, [0, 10]], [1.0, [1000, 2000]]]"#;
+//! let generator = serde_json::from_str::<Configure<Lengths>>(json).unwrap();
+//! // Weighted choices produce an `Option` since they may be empty.
+//! assert!(generator.samples(100).all(|length| length <= Some(2000)));
+//! ```
+
+use crate::{
+    any::Weight,
+    collect::Collect,
+    generate::{Generate, State},
+    prelude,
+    same::Same,
+};
+use core::{cmp::Ordering, fmt, marker::PhantomData, ops::RangeInclusive};
+use serde::{
+    Deserialize, Deserializer,
+    de::{self, MapAccess, Visitor},
+};
+use std::error;
+
+/// Generators that can be constructed from a validated configuration.
+pub trait FromConfig: Sized {
+    type Config;
+
+    fn from_config(config: Self::Config) -> Result<Self, Error>;
+}
+
+/// A generator constructed from its configuration, which is validated when
+/// the [`Configure`] is created or deserialized.
+#[derive(Clone, Debug)]
+pub struct Configure<G>(G);
+
+/// An invalid configuration parameter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    /// The location of the invalid parameter in the configuration (such as
+    /// `[1].item.length`). Empty if the whole configuration is invalid.
+    pub path: String,
+    /// What makes the parameter invalid.
+    pub message: String,
+}
+
+/// Inclusive bounds, represented as a `[start, end]` pair.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bounds<T> {
+    pub start: T,
+    pub end: T,
+}
+
+/// The configuration of a collection generator, represented as a map with an
+/// `item` and a `length` key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Collection<C> {
+    /// The configuration of the generator of the items.
+    pub item: C,
+    /// The bounds of the number of items.
+    pub length: Bounds<usize>,
+}
+
+impl Error {
+    pub fn new<M: Into<String>>(message: M) -> Self {
+        Self {
+            path: String::new(),
+            message: message.into(),
+        }
+    }
+
+    /// Prefixes the path of the error with the given `segment`, which should
+    /// either be a field name or an index in brackets (such as `[1]`).
+    pub fn at<S: fmt::Display>(mut self, segment: S) -> Self {
+        self.path = if self.path.is_empty() || self.path.starts_with('[') {
+            format!("{segment}{}", self.path)
+        } else {
+            format!("{segment}.{}", self.path)
+        };
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl<G: FromConfig> Configure<G> {
+    pub fn new(config: G::Config) -> Result<Self, Error> {
+        Ok(Self(G::from_config(config)?))
+    }
+
+    pub fn into_inner(self) -> G {
+        self.0
+    }
+}
+
+impl<G: Generate> Generate for Configure<G> {
+    type Item = G::Item;
+    type Shrink = G::Shrink;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        self.0.generate(state)
+    }
+
+    fn constant(&self) -> bool {
+        self.0.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.0.cardinality()
+    }
+}
+
+impl<'de, G: FromConfig> Deserialize<'de> for Configure<G>
+where
+    G::Config: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::new(G::Config::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl<T: PartialOrd + fmt::Debug> FromConfig for RangeInclusive<T> {
+    type Config = Bounds<T>;
+
+    fn from_config(Bounds { start, end }: Self::Config) -> Result<Self, Error> {
+        match start.partial_cmp(&end) {
+            Some(Ordering::Less | Ordering::Equal) => Ok(start..=end),
+            Some(Ordering::Greater) => Err(Error::new(format!(
+                "start {start:?} is greater than end {end:?}"
+            ))),
+            None => Err(Error::new(format!(
+                "start {start:?} and end {end:?} are not comparable"
+            ))),
+        }
+    }
+}
+
+impl<T: Clone> FromConfig for Same<T> {
+    type Config = T;
+
+    fn from_config(config: Self::Config) -> Result<Self, Error> {
+        Ok(prelude::same(config))
+    }
+}
+
+/// Configured as a list of `[weight, configuration]` pairs.
+impl<G: FromConfig + Generate> FromConfig for Vec<Weight<G>> {
+    type Config = Vec<(f64, G::Config)>;
+
+    fn from_config(config: Self::Config) -> Result<Self, Error> {
+        if config.is_empty() {
+            return Err(Error::new("at least one weighted choice is required"));
+        }
+        config
+            .into_iter()
+            .enumerate()
+            .map(|(index, (weight, config))| {
+                if !weight.is_finite() || weight < f64::EPSILON {
+                    return Err(Error::new(format!(
+                        "weight {weight} must be finite and at least {}",
+                        f64::EPSILON
+                    ))
+                    .at(format!("[{index}]")));
+                }
+                let generator =
+                    G::from_config(config).map_err(|error| error.at(format!("[{index}]")))?;
+                Ok(Weight::new(weight, generator))
+            })
+            .collect()
+    }
+}
+
+impl<G: FromConfig + Generate, F: FromIterator<G::Item>> FromConfig
+    for Collect<G, RangeInclusive<usize>, F>
+{
+    type Config = Collection<G::Config>;
+
+    fn from_config(Collection { item, length }: Self::Config) -> Result<Self, Error> {
+        let generator = G::from_config(item).map_err(|error| error.at("item"))?;
+        let length = RangeInclusive::from_config(length).map_err(|error| error.at("length"))?;
+        let minimum = *length.start();
+        Ok(prelude::collect(generator, length, Some(minimum)))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Bounds<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (start, end) = <(T, T)>::deserialize(deserializer)?;
+        Ok(Self { start, end })
+    }
+}
+
+impl<'de, C: Deserialize<'de>> Deserialize<'de> for Collection<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const FIELDS: &[&str] = &["item", "length"];
+
+        struct Fields<C>(PhantomData<C>);
+
+        impl<'de, C: Deserialize<'de>> Visitor<'de> for Fields<C> {
+            type Value = Collection<C>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map with an `item` and a `length`")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let (mut item, mut length) = (None, None);
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "item" => item = Some(map.next_value()?),
+                        "length" => length = Some(map.next_value()?),
+                        key => return Err(de::Error::unknown_field(key, FIELDS)),
+                    }
+                }
+                Ok(Collection {
+                    item: item.ok_or_else(|| de::Error::missing_field("item"))?,
+                    length: length.ok_or_else(|| de::Error::missing_field("length"))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct("Collection", FIELDS, Fields(PhantomData))
+    }
+}
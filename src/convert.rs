@@ -24,6 +24,10 @@ impl<G: Generate + ?Sized, I: From<G::Item>> Generate for Convert<G, I> {
     fn constant(&self) -> bool {
         self.1.constant()
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.1.cardinality()
+    }
 }
 
 impl<S: Shrink, I: From<S::Item>> Shrink for Convert<S, I> {
@@ -36,4 +40,74 @@ impl<S: Shrink, I: From<S::Item>> Shrink for Convert<S, I> {
     fn shrink(&mut self) -> Option<Self> {
         Some(Self(PhantomData, self.1.shrink()?))
     }
+
+    fn release(&mut self) -> bool {
+        self.1.release()
+    }
+}
+
+/// Converts the items of a generator with [`TryFrom`] (see
+/// [`Generate::try_convert`]). Items that fail to convert are generated again
+/// with an increasingly large size, up to a number of retries, like the items
+/// of [`Generate::filter_map`].
+#[derive(Debug)]
+pub struct TryConvert<G: ?Sized, I: ?Sized> {
+    pub(crate) retries: usize,
+    pub(crate) marker: PhantomData<I>,
+    pub(crate) generator: G,
+}
+
+#[derive(Debug)]
+pub struct TryShrinker<S, I: ?Sized>(PhantomData<I>, Option<S>);
+
+impl<G: Clone, I> Clone for TryConvert<G, I> {
+    fn clone(&self) -> Self {
+        Self {
+            retries: self.retries,
+            marker: PhantomData,
+            generator: self.generator.clone(),
+        }
+    }
+}
+
+impl<S: Clone, I> Clone for TryShrinker<S, I> {
+    fn clone(&self) -> Self {
+        Self(PhantomData, self.1.clone())
+    }
+}
+
+impl<G: Generate + ?Sized, I: TryFrom<G::Item>> Generate for TryConvert<G, I> {
+    type Item = Option<I>;
+    type Shrink = TryShrinker<G::Shrink, I>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let shrinker = state.retry_with_growth(&self.generator, self.retries, |inner| {
+            I::try_from(inner.item()).is_ok()
+        });
+        TryShrinker(PhantomData, shrinker)
+    }
+
+    fn constant(&self) -> bool {
+        self.generator.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.generator.cardinality()
+    }
+}
+
+impl<S: Shrink, I: TryFrom<S::Item>> Shrink for TryShrinker<S, I> {
+    type Item = Option<I>;
+
+    fn item(&self) -> Self::Item {
+        I::try_from(self.1.as_ref()?.item()).ok()
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        Some(Self(PhantomData, Some(self.1.as_mut()?.shrink()?)))
+    }
+
+    fn release(&mut self) -> bool {
+        self.1.as_mut().map_or(false, Shrink::release)
+    }
 }
@@ -0,0 +1,227 @@
+//! An opt-in registry of failing items shared between properties.
+//!
+//! When a property wrapped with [`record`] fails, its failing item is
+//! serialized and registered under the name of its type. Every [`Corpus`]
+//! generator over the same type then yields the registered items as its first
+//! items (like [`crate::Generate::pinned`]) before generating new ones, such
+//! that a bug found by one property is also presented to its siblings.
+//!
+//! Entries live in memory for the duration of the process. If the
+//! `CHECKITO_CORPUS` environment variable is set to a directory, entries are
+//! also written to and read from that directory such that they are shared
+//! between test binaries and between runs.
+
+use crate::{
+    generate::{Generate, State},
+    pinned::Shrinker,
+    prove::Prove,
+    same::Same,
+};
+use core::any::type_name;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{env, fs, path::PathBuf, sync::Mutex, thread::panicking};
+
+struct Entry {
+    key: &'static str,
+    name: String,
+    json: String,
+}
+
+static ENTRIES: Mutex<Vec<Entry>> = Mutex::new(Vec::new());
+
+/// A generator that yields the corpus entries registered for its item type
+/// before generating items with the wrapped generator.
+#[derive(Clone, Debug)]
+pub struct Corpus<G: ?Sized> {
+    entries: Vec<String>,
+    generator: G,
+}
+
+/// The result of a property wrapped with [`record`] that registers the item in
+/// the corpus if it is disproved.
+#[derive(Debug)]
+pub struct Record<T: Serialize, P> {
+    proof: P,
+    guard: Guard<T>,
+}
+
+#[derive(Debug)]
+struct Guard<T: Serialize> {
+    name: &'static str,
+    item: Option<T>,
+}
+
+impl<G: Generate> Corpus<G>
+where
+    G::Item: DeserializeOwned,
+{
+    /// Loads the entries registered for `G::Item` when it is created. Entries
+    /// registered afterwards will not be yielded by this instance.
+    pub fn new(generator: G) -> Self {
+        Self {
+            entries: entries(type_name::<G::Item>()),
+            generator,
+        }
+    }
+}
+
+impl<G: Generate + ?Sized> Generate for Corpus<G>
+where
+    G::Item: DeserializeOwned + Clone,
+{
+    type Item = G::Item;
+    type Shrink = Shrinker<G::Item, G::Shrink>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        match self
+            .entries
+            .get(state.index)
+            .and_then(|json| serde_json::from_str(json).ok())
+        {
+            Some(item) => Shrinker::Case(Same(item)),
+            None => Shrinker::Generator(self.generator.generate(state)),
+        }
+    }
+
+    fn constant(&self) -> bool {
+        self.entries.is_empty() && self.generator.constant()
+    }
+}
+
+/// Wraps a property such that its failing items (either disproved or
+/// panicking) are registered in the corpus under the property's `name`.
+///
+/// Each failing item replaces the previous entry of the property, such that
+/// the entry is the *last* failing item that the property has seen. Within a
+/// single shrinking phase, this is the minimal failing item since shrinking
+/// only fails on smaller and smaller items. However, this does not hold
+/// across phases: with multiple runs (see [`crate::check::Generates::runs`]) or with
+/// the [`crate::check::Strategy::Exhaustive`] and
+/// [`crate::check::Strategy::Bounded`] strategies, a later failing item may
+/// be larger than an earlier one.
+pub fn record<T: Serialize + Clone, P: Prove, F: Fn(T) -> P>(
+    name: &'static str,
+    check: F,
+) -> impl Fn(T) -> Record<T, P> {
+    move |item| {
+        // The guard registers the item if `check` panics.
+        let guard = Guard {
+            name,
+            item: Some(item.clone()),
+        };
+        let proof = check(item);
+        Record { proof, guard }
+    }
+}
+
+/// Registers `item` in the corpus under the property `name`. A previous entry
+/// for the same type and property is replaced.
+pub fn register<T: Serialize>(name: &str, item: &T) {
+    let Ok(json) = serde_json::to_string(item) else {
+        return;
+    };
+    let key = type_name::<T>();
+    if let Some(path) = path(key, name) {
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let _ = fs::write(path, &json);
+    }
+
+    let mut entries = ENTRIES.lock().unwrap_or_else(|error| error.into_inner());
+    match entries
+        .iter_mut()
+        .find(|entry| entry.key == key && entry.name == name)
+    {
+        Some(entry) => entry.json = json,
+        None => entries.push(Entry {
+            key,
+            name: name.into(),
+            json,
+        }),
+    }
+}
+
+/// Retrieves the entries registered for the type `T`, ordered by property name.
+pub fn load<T: DeserializeOwned>() -> Vec<T> {
+    entries(type_name::<T>())
+        .iter()
+        .filter_map(|json| serde_json::from_str(json).ok())
+        .collect()
+}
+
+fn entries(key: &str) -> Vec<String> {
+    let mut entries = Vec::new();
+    if let Some(directory) = directory() {
+        if let Ok(files) = fs::read_dir(directory.join(sanitize(key))) {
+            for file in files.flatten() {
+                let name = file.file_name().to_string_lossy().into_owned();
+                if let (Some(name), Ok(json)) =
+                    (name.strip_suffix(".json"), fs::read_to_string(file.path()))
+                {
+                    entries.push((name.to_string(), json));
+                }
+            }
+        }
+    }
+
+    let registered = ENTRIES.lock().unwrap_or_else(|error| error.into_inner());
+    for entry in registered.iter().filter(|entry| entry.key == key) {
+        let name = sanitize(&entry.name);
+        match entries.iter_mut().find(|(file, _)| *file == name) {
+            Some((_, json)) => json.clone_from(&entry.json),
+            None => entries.push((name, entry.json.clone())),
+        }
+    }
+    entries.sort_by(|left, right| left.0.cmp(&right.0));
+    entries.into_iter().map(|(_, json)| json).collect()
+}
+
+fn directory() -> Option<PathBuf> {
+    env::var_os("CHECKITO_CORPUS").map(PathBuf::from)
+}
+
+fn path(key: &str, name: &str) -> Option<PathBuf> {
+    Some(
+        directory()?
+            .join(sanitize(key))
+            .join(format!("{}.json", sanitize(name))),
+    )
+}
+
+/// Type names and property names contain characters (such as `::`, `<` or
+/// `>`) that are not valid in file names on every platform.
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|value| {
+            if value.is_ascii_alphanumeric() || value == '_' || value == '-' {
+                value
+            } else {
+                '.'
+            }
+        })
+        .collect()
+}
+
+impl<T: Serialize, P: Prove> Prove for Record<T, P> {
+    type Error = P::Error;
+    type Proof = P::Proof;
+
+    fn prove(mut self) -> Result<Self::Proof, Self::Error> {
+        let result = self.proof.prove();
+        if let (Err(_), Some(item)) = (&result, self.guard.item.take()) {
+            register(self.guard.name, &item);
+        }
+        result
+    }
+}
+
+impl<T: Serialize> Drop for Guard<T> {
+    fn drop(&mut self) {
+        if panicking() {
+            if let Some(item) = self.item.take() {
+                register(self.name, &item);
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    collect,
+    generate::{Generate, State},
+    shrink::Shrink,
+};
+use core::marker::PhantomData;
+
+/// Collects a number of items given by an arbitrary `count` generator (see
+/// [`crate::Generate::count_with`]).
+#[derive(Debug)]
+pub struct Count<I: ?Sized, C, F: ?Sized> {
+    pub(crate) _marker: PhantomData<F>,
+    pub(crate) count: C,
+    pub(crate) generator: I,
+}
+
+#[derive(Debug)]
+pub struct Shrinker<S, C, F: ?Sized> {
+    /// The shrinker of the length. It is `None` once it is exhausted.
+    count: Option<C>,
+    items: collect::Shrinker<S, F>,
+}
+
+impl<I: Clone, C: Clone, F> Clone for Count<I, C, F> {
+    fn clone(&self) -> Self {
+        Self {
+            _marker: PhantomData,
+            count: self.count.clone(),
+            generator: self.generator.clone(),
+        }
+    }
+}
+
+impl<S: Clone, C: Clone, F> Clone for Shrinker<S, C, F> {
+    fn clone(&self) -> Self {
+        Self {
+            count: self.count.clone(),
+            items: self.items.clone(),
+        }
+    }
+}
+
+impl<G: Generate + ?Sized, C: Generate<Item = usize>, F: FromIterator<G::Item>> Generate
+    for Count<G, C, F>
+{
+    type Item = F;
+    type Shrink = Shrinker<G::Shrink, C::Shrink, F>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let count = collect::counter(&self.count, state);
+        let length = count.item();
+        let shrinkers = Iterator::map(0..length, |_| self.generator.generate(state));
+        Shrinker {
+            count: Some(count),
+            // The length is only ever shrunk through the `count` shrinker.
+            items: collect::Shrinker::new(shrinkers, Some(length)),
+        }
+    }
+
+    fn constant(&self) -> bool {
+        self.count.constant() && self.generator.constant()
+    }
+}
+
+impl<S: Shrink, C: Shrink<Item = usize>, F: FromIterator<S::Item>> Shrink for Shrinker<S, C, F> {
+    type Item = F;
+
+    fn item(&self) -> Self::Item {
+        self.items.item()
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        if let Some(count) = &mut self.count {
+            // Shrink the length first, skipping the candidates that would require
+            // new items to be generated.
+            while let Some(new) = count.shrink() {
+                let length = new.item();
+                if length <= self.items.shrinkers.len() {
+                    let shrinkers = self.items.shrinkers[..length].to_vec();
+                    return Some(Self {
+                        count: Some(new),
+                        items: collect::Shrinker::new(shrinkers, Some(length)),
+                    });
+                }
+            }
+            self.count = None;
+        }
+        Some(Self {
+            count: None,
+            items: self.items.shrink()?,
+        })
+    }
+
+    fn release(&mut self) -> bool {
+        self.items.release()
+    }
+}
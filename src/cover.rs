@@ -0,0 +1,119 @@
+use crate::{
+    adaptive::Branch,
+    any::Shrinker,
+    cardinality,
+    generate::{Generate, State},
+    random::Random,
+};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Chooses among branches such that each branch is chosen at least once before
+/// any weight is considered (see [`crate::cover_all_branches`]).
+///
+/// Counts are shared between the clones of a [`Cover`] such that they
+/// accumulate over a whole run, even when the generator is cloned. They are
+/// only recorded for inspection and never influence the chosen branch.
+#[derive(Clone, Debug)]
+pub struct Cover<C: ?Sized> {
+    pub(crate) counts: Arc<Mutex<Vec<usize>>>,
+    pub(crate) branches: C,
+}
+
+impl<C: ?Sized> Cover<C> {
+    /// A snapshot of the number of times that each branch was chosen, in the
+    /// order of the branches. A branch with a count of `0` was never
+    /// exercised.
+    pub fn counts(&self) -> Vec<usize> {
+        self.counts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+/// Selects the branch at the index of the state in the order of this choice, if
+/// any, or else a branch with a probability proportional to its weight. The
+/// choice only depends on the state such that generating with the same state
+/// always yields the same item.
+fn select<B: Branch>(branches: &[B], state: &mut State) -> usize {
+    let choice = state.cover();
+    if state.index < branches.len() {
+        return order(branches.len(), state.index, state.seed(), choice);
+    }
+    let total = branches
+        .iter()
+        .map(Branch::weight)
+        .sum::<f64>()
+        .min(f64::MAX);
+    let mut random = state.ratio() * total;
+    for (index, branch) in branches.iter().enumerate() {
+        if random < branch.weight() {
+            return index;
+        }
+        random -= branch.weight();
+    }
+    branches.len() - 1
+}
+
+/// The branch at `index` in a permutation of `count` branches that is drawn
+/// from the seed of the run and the number of the choice within its item, such
+/// that every choice goes through all of its branches over the first `count`
+/// items without being correlated with the other choices of the item.
+fn order(count: usize, index: usize, seed: u64, choice: usize) -> usize {
+    let mut random = Random::new(seed ^ (choice as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    let mut branches = Vec::from_iter(0..count);
+    for position in 0..=index {
+        branches.swap(position, random.usize(position..count));
+    }
+    branches[index]
+}
+
+macro_rules! slice {
+    ($t:ty, [$($n:ident)?]) => {
+        impl<B: Branch, $(const $n: usize)?> Generate for Cover<$t> {
+            type Item = Option<<B::Generator as Generate>::Item>;
+            type Shrink = Shrinker<<B::Generator as Generate>::Shrink>;
+
+            fn generate(&self, state: &mut State) -> Self::Shrink {
+                let branches = as_slice(&self.branches);
+                if branches.is_empty() {
+                    return Shrinker(None);
+                }
+                let index = select(branches, state);
+                {
+                    let mut counts = self.counts.lock().unwrap_or_else(PoisonError::into_inner);
+                    counts.resize(branches.len(), 0);
+                    counts[index] += 1;
+                }
+                Shrinker(Some(branches[index].generator().generate(state)))
+            }
+
+            fn constant(&self) -> bool {
+                match as_slice(&self.branches) {
+                    [] => true,
+                    [branch] => branch.generator().constant(),
+                    _ => false,
+                }
+            }
+
+            fn cardinality(&self) -> Option<u128> {
+                let branches = as_slice(&self.branches);
+                if branches.is_empty() {
+                    Some(1)
+                } else {
+                    branches.iter().try_fold(0, |sum, branch| {
+                        cardinality::sum(Some(sum), branch.generator().cardinality())
+                    })
+                }
+            }
+        }
+    };
+}
+
+const fn as_slice<T>(slice: &[T]) -> &[T] {
+    slice
+}
+
+slice!([B], []);
+slice!([B; N], [N]);
+slice!(Vec<B>, []);
@@ -8,6 +8,8 @@ pub struct Dampen<G: ?Sized> {
     pub(crate) pressure: f64,
     pub(crate) deepest: usize,
     pub(crate) limit: usize,
+    /// Whether only the lengths of collections are dampened.
+    pub(crate) lengths: bool,
     pub(crate) generator: G,
 }
 
@@ -16,19 +18,35 @@ impl<G: Generate + ?Sized> Generate for Dampen<G> {
     type Shrink = G::Shrink;
 
     fn generate(&self, state: &mut State) -> Self::Shrink {
-        let old = state.size;
+        let old = if self.lengths {
+            state.lengths.unwrap_or(state.size)
+        } else {
+            state.size
+        };
         let new = if state.depth as usize >= self.deepest || state.limit as usize >= self.limit {
             0.0
         } else {
             old.start() / (state.depth as f64 * self.pressure).max(1.0)
         };
-        state.size = Sizes::from(new..=old.end());
-        let shrinker = self.generator.generate(state);
-        state.size = old;
-        shrinker
+        let new = Sizes::from(new..=old.end());
+        if self.lengths {
+            let old = state.lengths.replace(new);
+            let shrinker = self.generator.generate(state);
+            state.lengths = old;
+            shrinker
+        } else {
+            state.size = new;
+            let shrinker = self.generator.generate(state);
+            state.size = old;
+            shrinker
+        }
     }
 
     fn constant(&self) -> bool {
         self.generator.constant()
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.generator.cardinality()
+    }
 }
@@ -0,0 +1,185 @@
+//! Generators for the fixed precision decimals of [`rust_decimal`].
+//!
+//! A range of [`Decimal`] generates decimals with the largest scale of its
+//! bounds such that `0.00..=100.00` generates amounts with 2 decimal places.
+//! Generated decimals are shrunk toward `0` (or the bound of the range that is
+//! closest to `0`) while preserving their scale.
+//!
+//! ```
+//! use checkito::{decimal::rust_decimal::Decimal, *};
+//!
+//! let start = Decimal::new(-10_000, 2);
+//! let end = Decimal::new(10_000, 2);
+//! (start..=end).check(|amount| amount.scale() == 2);
+//! ```
+
+use crate::{
+    cardinality,
+    generate::{FullGenerate, Generate, State},
+    primitive::{self, Full},
+    shrink::Shrink,
+};
+use core::ops::{self, Bound};
+pub use rust_decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// Shrinks the mantissa of a [`Decimal`] at a fixed scale.
+#[derive(Clone, Debug)]
+pub struct Shrinker {
+    scale: u32,
+    mantissa: primitive::Shrinker<i128>,
+}
+
+/// The largest mantissa of a [`Decimal`] (96 bits).
+const MANTISSA: i128 = (1 << 96) - 1;
+
+/// Resolves the bounds of `range` as mantissas at a shared scale.
+///
+/// The scale is the largest scale of the bounds, unless a bound cannot be
+/// represented at that scale, in which case the bounds are rounded inward at
+/// the largest scale that can represent them.
+fn range<R: ops::RangeBounds<Decimal>>(range: &R) -> (i128, i128, u32) {
+    let (start, start_excluded) = match range.start_bound() {
+        Bound::Included(&bound) => (bound, false),
+        Bound::Excluded(&bound) => (bound, true),
+        Bound::Unbounded => (Decimal::MIN, false),
+    };
+    let (end, end_excluded) = match range.end_bound() {
+        Bound::Included(&bound) => (bound, false),
+        Bound::Excluded(&bound) => (bound, true),
+        Bound::Unbounded => (Decimal::MAX, false),
+    };
+    let mut scale = start.scale().max(end.scale());
+    let (mut low, mut high) = loop {
+        let mut low = start.round_dp_with_strategy(scale, RoundingStrategy::ToPositiveInfinity);
+        let mut high = end.round_dp_with_strategy(scale, RoundingStrategy::ToNegativeInfinity);
+        low.rescale(scale);
+        high.rescale(scale);
+        if low.scale() == scale && high.scale() == scale {
+            break (low.mantissa(), high.mantissa());
+        }
+        scale = low.scale().min(high.scale());
+    };
+    if low == high {
+        return (low, high, scale);
+    }
+    if low > high {
+        (low, high) = (high, low);
+    }
+    if start_excluded {
+        low = (low + 1).min(MANTISSA);
+    }
+    if end_excluded {
+        high = (high - 1).max(-MANTISSA);
+    }
+    (low.min(high), high.max(low), scale)
+}
+
+impl Generate for Decimal {
+    type Item = Self;
+    type Shrink = Self;
+
+    fn generate(&self, _: &mut State) -> Self::Shrink {
+        *self
+    }
+
+    fn constant(&self) -> bool {
+        true
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        Some(1)
+    }
+}
+
+impl Shrink for Decimal {
+    type Item = Self;
+
+    fn item(&self) -> Self::Item {
+        *self
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        None
+    }
+}
+
+impl FullGenerate for Decimal {
+    type Generator = Full<Decimal>;
+    type Item = Decimal;
+
+    fn generator() -> Self::Generator {
+        Full::NEW
+    }
+}
+
+/// Decimals of any scale and mantissa.
+impl Generate for Full<Decimal> {
+    type Item = Decimal;
+    type Shrink = Shrinker;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let scale = state.random().u32(..=Decimal::MAX_SCALE);
+        Shrinker {
+            scale,
+            mantissa: (-MANTISSA..=MANTISSA).generate(state),
+        }
+    }
+
+    fn constant(&self) -> bool {
+        false
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        let scales = Some(Decimal::MAX_SCALE as u128 + 1);
+        cardinality::product(scales, (-MANTISSA..=MANTISSA).cardinality())
+    }
+}
+
+impl Shrink for Shrinker {
+    type Item = Decimal;
+
+    fn item(&self) -> Self::Item {
+        Decimal::from_i128_with_scale(self.mantissa.item(), self.scale)
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        Some(Self {
+            scale: self.scale,
+            mantissa: self.mantissa.shrink()?,
+        })
+    }
+}
+
+macro_rules! range {
+    ($r:ty) => {
+        impl Generate for $r {
+            type Item = Decimal;
+            type Shrink = Shrinker;
+
+            fn generate(&self, state: &mut State) -> Self::Shrink {
+                let (start, end, scale) = range(self);
+                Shrinker {
+                    scale,
+                    mantissa: (start..=end).generate(state),
+                }
+            }
+
+            fn constant(&self) -> bool {
+                let (start, end, _) = range(self);
+                start == end
+            }
+
+            fn cardinality(&self) -> Option<u128> {
+                let (start, end, _) = range(self);
+                (start..=end).cardinality()
+            }
+        }
+    };
+}
+
+range!(ops::Range<Decimal>);
+range!(ops::RangeInclusive<Decimal>);
+range!(ops::RangeFrom<Decimal>);
+range!(ops::RangeTo<Decimal>);
+range!(ops::RangeToInclusive<Decimal>);
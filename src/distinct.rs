@@ -0,0 +1,261 @@
+use crate::{
+    COLLECT, RETRIES, cardinality,
+    check::Sizes,
+    collect::{self, Shrinker},
+    generate::{FullGenerate, Generate, State},
+    shrink::Shrink,
+};
+use core::{
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+    ops::RangeInclusive,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// Generates a collection of distinct items (such as a
+/// [`std::collections::HashSet`] or a [`std::collections::BTreeSet`]).
+#[derive(Debug)]
+pub struct SetOf<G: ?Sized, C, F: ?Sized> {
+    pub(crate) _marker: PhantomData<F>,
+    pub(crate) count: C,
+    pub(crate) minimum: Option<usize>,
+    pub(crate) generator: G,
+}
+
+/// Generates a collection of entries with distinct keys (such as a
+/// [`std::collections::HashMap`] or a [`std::collections::BTreeMap`]).
+#[derive(Debug)]
+pub struct MapOf<K, V, C, F: ?Sized> {
+    pub(crate) _marker: PhantomData<F>,
+    pub(crate) count: C,
+    pub(crate) minimum: Option<usize>,
+    pub(crate) key: K,
+    pub(crate) value: V,
+}
+
+/// Collections that [`SetOf`] and [`MapOf`] can generate, along with the set
+/// of [`Keys`] that they use to reject duplicate keys (the items of a set or
+/// the keys of a map).
+///
+/// Hashed and ordered collections reject duplicates in constant or
+/// logarithmic time using the bounds that their items already satisfy, while
+/// sequences fall back to comparing every pair of keys.
+pub trait Distinct<K> {
+    type Keys: Keys<K>;
+}
+
+/// The keys generated so far by [`SetOf`] or [`MapOf`].
+pub trait Keys<K>: Default {
+    /// Inserts the `key` and returns whether it was absent.
+    fn insert(&mut self, key: K) -> bool;
+}
+
+impl<K: Hash + Eq> Keys<K> for HashSet<K> {
+    fn insert(&mut self, key: K) -> bool {
+        HashSet::insert(self, key)
+    }
+}
+
+impl<K: Ord> Keys<K> for BTreeSet<K> {
+    fn insert(&mut self, key: K) -> bool {
+        BTreeSet::insert(self, key)
+    }
+}
+
+impl<K: PartialEq> Keys<K> for Vec<K> {
+    fn insert(&mut self, key: K) -> bool {
+        if self.contains(&key) {
+            false
+        } else {
+            self.push(key);
+            true
+        }
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Distinct<T> for HashSet<T, S> {
+    type Keys = HashSet<T>;
+}
+
+impl<T: Ord> Distinct<T> for BTreeSet<T> {
+    type Keys = BTreeSet<T>;
+}
+
+impl<T: PartialEq> Distinct<T> for Vec<T> {
+    type Keys = Vec<T>;
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Distinct<K> for HashMap<K, V, S> {
+    type Keys = HashSet<K>;
+}
+
+impl<K: Ord, V> Distinct<K> for BTreeMap<K, V> {
+    type Keys = BTreeSet<K>;
+}
+
+impl<K: PartialEq, V> Distinct<K> for Vec<(K, V)> {
+    type Keys = Vec<K>;
+}
+
+impl<G: Clone, C: Clone, F> Clone for SetOf<G, C, F> {
+    fn clone(&self) -> Self {
+        Self {
+            _marker: PhantomData,
+            count: self.count.clone(),
+            minimum: self.minimum,
+            generator: self.generator.clone(),
+        }
+    }
+}
+
+impl<K: Clone, V: Clone, C: Clone, F> Clone for MapOf<K, V, C, F> {
+    fn clone(&self) -> Self {
+        Self {
+            _marker: PhantomData,
+            count: self.count.clone(),
+            minimum: self.minimum,
+            key: self.key.clone(),
+            value: self.value.clone(),
+        }
+    }
+}
+
+/// Generates up to `count` shrinkers whose keys are distinct. Each duplicate
+/// grows the size such that small sizes do not starve the key space. Since the
+/// key space may be smaller than `count`, generation gives up after [`RETRIES`]
+/// consecutive duplicates.
+fn distinct<S: Shrink, K, T: Keys<K>>(
+    count: usize,
+    state: &mut State,
+    mut generate: impl FnMut(&mut State) -> S,
+    key: impl Fn(S::Item) -> K,
+) -> Vec<S> {
+    let mut shrinkers = Vec::with_capacity(count);
+    let mut keys = T::default();
+    let mut retries = 0;
+    let old = state.size;
+    while shrinkers.len() < count && retries < RETRIES {
+        let shrinker = generate(state);
+        if !keys.insert(key(shrinker.item())) {
+            retries += 1;
+            let start = state.size.start();
+            let start = start + (1.0 - start) * retries as f64 / RETRIES as f64;
+            state.size = Sizes::from(start..=state.size.end().max(start));
+        } else {
+            retries = 0;
+            shrinkers.push(shrinker);
+        }
+    }
+    state.size = old;
+    shrinkers
+}
+
+impl<G: Generate + ?Sized, C: Generate<Item = usize>, F: FromIterator<G::Item> + Distinct<G::Item>>
+    Generate for SetOf<G, C, F>
+{
+    type Item = F;
+    type Shrink = Shrinker<G::Shrink, F>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let count = collect::length(&self.count, state);
+        let shrinkers = distinct::<_, _, F::Keys>(
+            count,
+            state,
+            |state| self.generator.generate(state),
+            |item| item,
+        );
+        // The key space may be too small to reach the minimum.
+        let minimum = self.minimum.map(|minimum| minimum.min(shrinkers.len()));
+        Shrinker::new(shrinkers, minimum)
+    }
+
+    fn constant(&self) -> bool {
+        self.count.constant() && self.generator.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        // Every subset of the items is a candidate set.
+        let exponent = self.generator.cardinality()?.try_into().ok()?;
+        cardinality::power(Some(2), exponent)
+    }
+}
+
+impl<
+    K: Generate,
+    V: Generate,
+    C: Generate<Item = usize>,
+    F: FromIterator<(K::Item, V::Item)> + Distinct<K::Item>,
+> Generate for MapOf<K, V, C, F>
+{
+    type Item = F;
+    type Shrink = Shrinker<<(K, V) as Generate>::Shrink, F>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let count = collect::length(&self.count, state);
+        let entry = (&self.key, &self.value);
+        let shrinkers =
+            distinct::<_, _, F::Keys>(count, state, |state| entry.generate(state), |(key, _)| key);
+        // The key space may be too small to reach the minimum.
+        let minimum = self.minimum.map(|minimum| minimum.min(shrinkers.len()));
+        Shrinker::new(shrinkers, minimum)
+    }
+
+    fn constant(&self) -> bool {
+        self.count.constant() && self.key.constant() && self.value.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        // Every key is either absent or associated with one of the values.
+        let exponent = self.key.cardinality()?.try_into().ok()?;
+        cardinality::power(
+            cardinality::sum(self.value.cardinality(), Some(1)),
+            exponent,
+        )
+    }
+}
+
+/// The default maximum length of a collection of distinct items, which is
+/// bounded by the number of distinct keys such that a small key space does not
+/// spend the generation on duplicates.
+fn maximum(cardinality: Option<u128>) -> usize {
+    cardinality.map_or(COLLECT, |cardinality| {
+        cardinality.min(COLLECT as u128) as usize
+    })
+}
+
+impl<G: FullGenerate> FullGenerate for BTreeSet<G>
+where
+    G::Item: Ord,
+{
+    type Generator = SetOf<G::Generator, RangeInclusive<usize>, Self::Item>;
+    type Item = BTreeSet<G::Item>;
+
+    fn generator() -> Self::Generator {
+        let generator = G::generator();
+        SetOf {
+            _marker: PhantomData,
+            count: 0..=maximum(generator.cardinality()),
+            minimum: Some(0),
+            generator,
+        }
+    }
+}
+
+impl<K: FullGenerate, V: FullGenerate> FullGenerate for BTreeMap<K, V>
+where
+    K::Item: Ord,
+{
+    type Generator = MapOf<K::Generator, V::Generator, RangeInclusive<usize>, Self::Item>;
+    type Item = BTreeMap<K::Item, V::Item>;
+
+    fn generator() -> Self::Generator {
+        let key = K::generator();
+        MapOf {
+            _marker: PhantomData,
+            count: 0..=maximum(key.cardinality()),
+            minimum: Some(0),
+            key,
+            value: V::generator(),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Generation of items from within the body of a property with the
+//! [`crate::gen!`] macro.
+//!
+//! A `#[check]` function that uses [`crate::gen!`] in its body is given a
+//! [`Draws`] generator in addition to the generators of its parameters. The
+//! generated [`Draw`] holds the state of the check such that every item drawn
+//! within the property is reproducible, and shrinking a [`Draw`] reduces its
+//! size such that the drawn items become smaller.
+
+use crate::{
+    check::Sizes,
+    generate::{Generate, State},
+    primitive::{self, Direction},
+    shrink::Shrink,
+};
+use core::{cell::RefCell, fmt};
+
+thread_local! { static STATE: RefCell<Option<State>> = const { RefCell::new(None) }; }
+
+/// A generator of [`Draw`] states for properties that generate their items
+/// with [`crate::gen!`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Draws;
+
+/// The state from which the items of a property are drawn.
+#[derive(Clone)]
+pub struct Draw(State);
+
+#[derive(Clone, Debug)]
+pub struct Shrinker {
+    state: State,
+    size: primitive::Shrinker<f64>,
+}
+
+impl Generate for Draws {
+    type Item = Draw;
+    type Shrink = Shrinker;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let size = state.size();
+        Shrinker {
+            state: state.clone(),
+            size: primitive::Shrinker {
+                start: 0.0,
+                end: size,
+                item: size,
+                direction: Direction::None,
+            },
+        }
+    }
+}
+
+impl Shrink for Shrinker {
+    type Item = Draw;
+
+    /// The random state is restored on every item such that the same items are
+    /// drawn for a given size.
+    fn item(&self) -> Self::Item {
+        let mut state = self.state.clone();
+        state.size = Sizes::from(self.size.item());
+        Draw(state)
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        Some(Self {
+            state: self.state.clone(),
+            size: self.size.shrink()?,
+        })
+    }
+}
+
+impl fmt::Debug for Draw {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Draw")
+            .field("seed", &self.0.seed())
+            .field("size", &self.0.size())
+            .finish()
+    }
+}
+
+/// Runs `run` such that [`crate::gen!`] draws its items from `draw`.
+pub fn scope<T>(draw: Draw, run: impl FnOnce() -> T) -> T {
+    struct Restore(Option<State>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            STATE.with(|state| *state.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore = Restore(STATE.with(|state| state.borrow_mut().replace(draw.0)));
+    run()
+}
+
+/// Draws an item from `generator` within the current [`scope`].
+///
+/// # Panics
+/// If called outside of a [`scope`], such as outside of a `#[check]` function.
+pub fn draw<G: Generate + ?Sized>(generator: &G) -> G::Item {
+    // The state is taken out while generating such that generators may draw as well.
+    let mut state = STATE
+        .with(|state| state.borrow_mut().take())
+        .expect("'gen!' must be used within a '#[check]' function");
+    let item = generator.generate(&mut state).item();
+    STATE.with(|cell| *cell.borrow_mut() = Some(state));
+    item
+}
+
+/// Draws an item from a generator within the body of a `#[check]` function
+/// that has no generator for it.
+///
+/// ```
+/// # #[cfg(feature = "check")]
+/// # mod test {
+/// use checkito::*;
+///
+/// #[check]
+/// fn is_sorted() {
+///     let mut items = gen!(u8::generator().collect::<Vec<_>>());
+///     let index = gen!(0..=items.len());
+///     items.sort();
+///     assert!(items[..index].windows(2).all(|pair| pair[0] <= pair[1]));
+/// }
+/// # }
+/// ```
+///
+/// Since `gen` is a reserved keyword in the 2024 edition, it must be invoked
+/// as `r#gen!` there.
+#[macro_export]
+macro_rules! gen {
+    ($generator:expr) => {
+        $crate::draw::draw(&$generator)
+    };
+}
@@ -0,0 +1,385 @@
+//! Exploration of generators from a textual expression.
+//!
+//! An [`Explorer`] holds named generators and parses expressions that combine
+//! them such that the items of a generator can be inspected without writing a
+//! test (the `explore` binary is a thin command line wrapper around it). Items
+//! are rendered with their `Debug` representation.
+//!
+//! The expressions follow this grammar:
+//! - `name`: a registered generator (such as `u8`, `bool` or `email`).
+//! - `start..end` or `start..=end`: an integer range (`-5..=5`) or a floating
+//!   point range (`0.0..1.0`).
+//! - `(a, b, ...)`: a tuple of expressions.
+//! - `[a]` or `[a; start..end]`: a vector of items with a length in the range.
+//! - `a?`: an optional item.
+//! - `"pattern"`: a string that matches a regular expression (requires the
+//!   `regex` feature).
+//!
+//! ```
+//! use checkito::{explore::Explorer, *};
+//!
+//! let explorer = Explorer::new();
+//! let generator = explorer.parse("(bool, [0..=9; 1..4], char?)").unwrap();
+//! assert!(generator.samples(10).all(|item| item.starts_with("(")));
+//! assert!(explorer.parse("(u8,").is_err());
+//! ```
+
+use crate::{
+    boxed::{Boxed, DynGenerate},
+    generate::{FullGenerate, Generate},
+    prelude,
+    standard::option,
+};
+use core::{fmt, str::FromStr};
+use std::{error, rc::Rc};
+
+/// A set of named generators from which expressions are parsed.
+pub struct Explorer {
+    entries: Vec<(String, Rc<dyn DynGenerate<String>>)>,
+}
+
+/// An error in an expression along with the byte offset at which it occurred.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    pub offset: usize,
+    pub message: String,
+}
+
+struct Parser<'a> {
+    explorer: &'a Explorer,
+    source: &'a str,
+    offset: usize,
+}
+
+enum Number {
+    Integer(i128),
+    Floating(f64),
+}
+
+impl Explorer {
+    /// An explorer with the primitive types (`bool`, `char`, `u8`, `f64`,
+    /// `String`, ...) and the text generators (`letter`, `digit`, `ascii`,
+    /// `word`, `hostname`, `email` and `url`) registered under their name.
+    pub fn new() -> Self {
+        macro_rules! full {
+            ($explorer: expr, $($t: ident),*) => {
+                $explorer$(.register(stringify!($t), $t::generator()))*
+            };
+        }
+
+        let explorer = full!(
+            Self::empty(),
+            bool,
+            char,
+            u8,
+            u16,
+            u32,
+            u64,
+            u128,
+            usize,
+            i8,
+            i16,
+            i32,
+            i64,
+            i128,
+            isize,
+            f32,
+            f64,
+            String
+        );
+        explorer
+            .register("letter", prelude::letter())
+            .register("digit", prelude::digit())
+            .register("ascii", prelude::ascii())
+            .register("word", prelude::word(prelude::letter()))
+            .register("hostname", prelude::hostname())
+            .register("email", prelude::email())
+            .register("url", prelude::url())
+    }
+
+    /// An explorer without any registered generator.
+    pub const fn empty() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Registers `generator` under `name`, replacing any generator previously
+    /// registered under the same name.
+    pub fn register<G: Generate + 'static>(mut self, name: &str, generator: G) -> Self
+    where
+        G::Item: fmt::Debug,
+        G::Shrink: 'static,
+    {
+        let generator: Rc<dyn DynGenerate<String>> =
+            Rc::new(prelude::map(generator, |item| format!("{item:?}")));
+        match self.entries.iter_mut().find(|(key, _)| key == name) {
+            Some(entry) => entry.1 = generator,
+            None => self.entries.push((name.into(), generator)),
+        }
+        self
+    }
+
+    /// The names of the registered generators, in registration order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Parses `expression` into a generator of the `Debug` representation of
+    /// its items.
+    pub fn parse(&self, expression: &str) -> Result<Boxed<String>, Error> {
+        let mut parser = Parser {
+            explorer: self,
+            source: expression,
+            offset: 0,
+        };
+        let generator = parser.expression()?;
+        parser.skip();
+        if parser.offset < expression.len() {
+            Err(parser.error("unexpected trailing input"))
+        } else {
+            Ok(generator)
+        }
+    }
+}
+
+impl Default for Explorer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Explorer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.message, self.offset)
+    }
+}
+
+impl error::Error for Error {}
+
+fn boxed<G: Generate + 'static>(generator: G) -> Boxed<String>
+where
+    G::Item: fmt::Debug,
+    G::Shrink: 'static,
+{
+    prelude::boxed(Box::new(prelude::map(generator, |item| {
+        format!("{item:?}")
+    })))
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> Error {
+        Error {
+            offset: self.offset,
+            message: message.into(),
+        }
+    }
+
+    fn rest(&self) -> &str {
+        &self.source[self.offset..]
+    }
+
+    fn skip(&mut self) {
+        let rest = self.rest();
+        self.offset += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip();
+        if self.rest().starts_with(token) {
+            self.offset += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), Error> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{token}`")))
+        }
+    }
+
+    fn expression(&mut self) -> Result<Boxed<String>, Error> {
+        let mut generator = self.atom()?;
+        while self.eat("?") {
+            generator = prelude::boxed(Box::new(prelude::map(
+                option::Generator(generator),
+                |item| match item {
+                    Some(item) => format!("Some({item})"),
+                    None => "None".into(),
+                },
+            )));
+        }
+        Ok(generator)
+    }
+
+    fn atom(&mut self) -> Result<Boxed<String>, Error> {
+        self.skip();
+        match self.rest().chars().next() {
+            Some('(') => self.tuple(),
+            Some('[') => self.vector(),
+            Some('"') => self.pattern(),
+            Some(letter) if letter.is_alphabetic() || letter == '_' => self.name(),
+            Some(_) => self.range(),
+            None => Err(self.error("expected an expression")),
+        }
+    }
+
+    fn tuple(&mut self) -> Result<Boxed<String>, Error> {
+        self.expect("(")?;
+        let mut generators = Vec::new();
+        while !self.eat(")") {
+            generators.push(self.expression()?);
+            if !self.eat(",") {
+                self.expect(")")?;
+                break;
+            }
+        }
+        let single = generators.len() == 1;
+        Ok(prelude::boxed(Box::new(prelude::map(
+            generators,
+            move |items: Vec<String>| {
+                if single {
+                    format!("({},)", items[0])
+                } else {
+                    format!("({})", items.join(", "))
+                }
+            },
+        ))))
+    }
+
+    fn vector(&mut self) -> Result<Boxed<String>, Error> {
+        self.expect("[")?;
+        let generator = self.expression()?;
+        let count = if self.eat(";") {
+            self.skip();
+            let offset = self.offset;
+            match (self.number()?, self.bounds()?, self.number()?) {
+                (Number::Integer(start), inclusive, Number::Integer(end)) => {
+                    let start = usize::try_from(start).ok();
+                    let end = usize::try_from(end - i128::from(!inclusive)).ok();
+                    match (start, end) {
+                        (Some(start), Some(end)) if start <= end => start..=end,
+                        _ => {
+                            self.offset = offset;
+                            return Err(self.error("expected a non-empty length range"));
+                        }
+                    }
+                }
+                _ => {
+                    self.offset = offset;
+                    return Err(self.error("expected an integer length range"));
+                }
+            }
+        } else {
+            0..=64
+        };
+        self.expect("]")?;
+        Ok(prelude::boxed(Box::new(prelude::map(
+            prelude::collect(generator, count, None),
+            |items: Vec<String>| format!("[{}]", items.join(", ")),
+        ))))
+    }
+
+    #[cfg(feature = "regex")]
+    fn pattern(&mut self) -> Result<Boxed<String>, Error> {
+        self.expect("\"")?;
+        let offset = self.offset;
+        let length = self
+            .rest()
+            .find('"')
+            .ok_or_else(|| self.error("unterminated pattern"))?;
+        let pattern = &self.source[offset..offset + length];
+        self.offset += length + 1;
+        match prelude::regex(pattern, None) {
+            Ok(regex) => Ok(boxed(regex)),
+            Err(_) => Err(Error {
+                offset,
+                message: "invalid pattern".into(),
+            }),
+        }
+    }
+
+    #[cfg(not(feature = "regex"))]
+    fn pattern(&mut self) -> Result<Boxed<String>, Error> {
+        Err(self.error("patterns require the `regex` feature"))
+    }
+
+    fn name(&mut self) -> Result<Boxed<String>, Error> {
+        let rest = self.rest();
+        let length = rest
+            .find(|letter: char| !letter.is_alphanumeric() && letter != '_')
+            .unwrap_or(rest.len());
+        let name = &rest[..length];
+        match self.explorer.entries.iter().find(|(key, _)| key == name) {
+            Some((_, generator)) => {
+                self.offset += length;
+                Ok(prelude::boxed(Box::new(generator.clone())))
+            }
+            None => Err(self.error(&format!("unknown generator `{name}`"))),
+        }
+    }
+
+    fn range(&mut self) -> Result<Boxed<String>, Error> {
+        let offset = self.offset;
+        match (self.number()?, self.bounds()?, self.number()?) {
+            (Number::Integer(start), true, Number::Integer(end)) => Ok(boxed(start..=end)),
+            (Number::Integer(start), false, Number::Integer(end)) => Ok(boxed(start..end)),
+            (Number::Floating(start), true, Number::Floating(end)) => Ok(boxed(start..=end)),
+            (Number::Floating(start), false, Number::Floating(end)) => Ok(boxed(start..end)),
+            _ => {
+                self.offset = offset;
+                Err(self.error("expected bounds of the same kind"))
+            }
+        }
+    }
+
+    /// Parses `..` or `..=` and returns whether the range is inclusive.
+    fn bounds(&mut self) -> Result<bool, Error> {
+        self.expect("..")?;
+        Ok(self.eat("="))
+    }
+
+    fn number(&mut self) -> Result<Number, Error> {
+        self.skip();
+        let rest = self.rest();
+        let bytes = rest.as_bytes();
+        let mut length = usize::from(bytes.first() == Some(&b'-'));
+        let digits = |length: usize| {
+            length
+                + bytes[length..]
+                    .iter()
+                    .take_while(|byte| byte.is_ascii_digit())
+                    .count()
+        };
+        length = digits(length);
+        // A `.` followed by a digit is a decimal point rather than the start of `..`.
+        let floating = bytes.get(length) == Some(&b'.')
+            && bytes.get(length + 1).map_or(false, u8::is_ascii_digit);
+        if floating {
+            length = digits(length + 1);
+        }
+        let text = &rest[..length];
+        let number = if floating {
+            f64::from_str(text).ok().map(Number::Floating)
+        } else {
+            i128::from_str(text).ok().map(Number::Integer)
+        };
+        match number {
+            Some(number) => {
+                self.offset += length;
+                Ok(number)
+            }
+            None => Err(self.error("expected a number")),
+        }
+    }
+}
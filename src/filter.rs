@@ -1,5 +1,5 @@
 use crate::{
-    generate::{self, Generate, State},
+    generate::{Generate, State},
     shrink::Shrink,
 };
 
@@ -21,22 +21,11 @@ impl<G: Generate + ?Sized, F: Fn(&G::Item) -> bool + Clone> Generate for Filter<
     type Shrink = Shrinker<G::Shrink, F>;
 
     fn generate(&self, state: &mut State) -> Self::Shrink {
-        let mut outer = None;
-        let size = state.size;
-        for i in 0..=self.retries {
-            state.size = generate::size(i, self.retries, size);
-            let inner = self.generator.generate(state);
-            let item = inner.item();
-            if (self.filter)(&item) {
-                outer = Some(inner);
-                break;
-            } else if self.constant() {
-                break;
-            }
-        }
-        state.size = size;
+        let shrinker = state.retry_with_growth(&self.generator, self.retries, |inner| {
+            (self.filter)(&inner.item())
+        });
         Shrinker {
-            shrinker: outer,
+            shrinker,
             filter: self.filter.clone(),
         }
     }
@@ -44,6 +33,10 @@ impl<G: Generate + ?Sized, F: Fn(&G::Item) -> bool + Clone> Generate for Filter<
     fn constant(&self) -> bool {
         self.generator.constant()
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.generator.cardinality()
+    }
 }
 
 impl<S: Shrink, F: Fn(&S::Item) -> bool + Clone> Shrink for Shrinker<S, F> {
@@ -64,4 +57,8 @@ impl<S: Shrink, F: Fn(&S::Item) -> bool + Clone> Shrink for Shrinker<S, F> {
             shrinker: Some(self.shrinker.as_mut()?.shrink()?),
         })
     }
+
+    fn release(&mut self) -> bool {
+        self.shrinker.as_mut().map_or(false, Shrink::release)
+    }
 }
@@ -65,4 +65,47 @@ impl<I: Generate, O: Shrink<Item = I>> Shrink for Shrinker<I::Shrink, O> {
 
         None
     }
+
+    fn release(&mut self) -> bool {
+        let outer = self.outer.release();
+        let inner = self.inner.release();
+        outer || inner
+    }
+}
+
+/// Builds a generator of dependent records where the generator of each field
+/// may refer to the fields that precede it by name. The generated item is a
+/// tuple of all the fields in declaration order.
+///
+/// Each field is compiled to a nested [`Generate::flat_map`] such that shrinking
+/// a field regenerates the fields that depend on it.
+///
+/// ```
+/// use checkito::*;
+///
+/// let generator = record! {
+///     length in 0..10usize,
+///     data in u8::generator().collect_with::<_, Vec<_>>(length),
+/// };
+/// for (length, data) in generator.samples(100) {
+///     assert_eq!(length, data.len());
+/// }
+/// ```
+#[macro_export]
+macro_rules! record {
+    ($($name:ident in $generator:expr),+ $(,)?) => {
+        $crate::record!(@next [] $($name in $generator,)+)
+    };
+    (@next [$($bound:ident)*] $name:ident in $generator:expr,) => {
+        $crate::Generate::map($generator, move |$name| {
+            $(let $bound = ::core::clone::Clone::clone(&$bound);)*
+            ($($bound,)* $name,)
+        })
+    };
+    (@next [$($bound:ident)*] $name:ident in $generator:expr, $($rest:tt)+) => {
+        $crate::Generate::flat_map($generator, move |$name| {
+            $(let $bound = ::core::clone::Clone::clone(&$bound);)*
+            $crate::record!(@next [$($bound)* $name] $($rest)+)
+        })
+    };
 }
@@ -4,41 +4,74 @@ use crate::{
     array::Array,
     boxed::Boxed,
     check::Sizes,
-    collect::Collect,
-    convert::Convert,
+    collect::{self, Collect},
+    concat::Concat,
+    convert::{Convert, TryConvert},
+    count::Count,
     dampen::Dampen,
     filter::Filter,
     filter_map::FilterMap,
     flatten::Flatten,
-    keep::Keep,
-    map::Map,
+    indices::Indices,
+    inspect::{self, Deterministic, Inspect, Trace},
+    keep::{Keep, KeepUntilLast},
+    map::{Invertible, Map},
+    matrix::Matrix,
+    or::Or,
+    order::{Components, Order},
+    pinned::Pinned,
     prelude,
     random::{self, Random},
-    sample::Sample,
+    shared::Shared,
     shrink::Shrink,
     size::Size,
     unify::Unify,
 };
 use core::{
+    fmt,
+    hash::Hash,
     iter::{FromIterator, FusedIterator},
+    mem::replace,
     ops::{self, RangeInclusive},
 };
 
 #[derive(Clone, Debug)]
 pub struct State {
     seed: u64,
+    pub(crate) index: usize,
     pub(crate) size: Sizes,
     pub(crate) limit: u32,
     pub(crate) depth: u32,
+    pub(crate) stratum: Option<(usize, usize)>,
+    /// When set, overrides the `size` used to generate collection lengths.
+    pub(crate) lengths: Option<Sizes>,
+    /// The addresses of the [`crate::Generate::pinned`] generators that
+    /// already yielded their case with this state.
+    pub(crate) pins: Vec<usize>,
+    /// The number of [`crate::cover_all_branches`] choices already made with
+    /// this state.
+    pub(crate) covers: usize,
     random: Random,
 }
 
+/// Overrides parameters of a [`State`] until it is dropped (see
+/// [`State::with`]). Only the overridden parameters are restored, such that
+/// changes made by generators to the other ones are preserved.
+#[derive(Debug)]
+pub struct With<'a> {
+    state: &'a mut State,
+    size: Option<Sizes>,
+    depth: Option<u32>,
+    limit: Option<u32>,
+}
+
 #[derive(Debug, Clone)]
 pub struct States {
     indices: ops::Range<usize>,
     count: usize,
     size: Sizes,
     seed: u64,
+    grid: bool,
 }
 
 /// When implemented for a type `T`, this allows to retrieve a generator for `T`
@@ -69,6 +102,27 @@ pub trait Generate {
         false
     }
 
+    /// Returns the number of distinct items that this generator may produce, if
+    /// it is known. This is an upper bound; generators such as [`Filter`] or
+    /// [`Map`] may produce fewer distinct items than reported.
+    ///
+    /// Implementations should combine cardinalities using the overflow-safe
+    /// functions of the [`crate::cardinality`] module.
+    fn cardinality(&self) -> Option<u128> {
+        None
+    }
+
+    /// Builds a shrinker that starts from `item` rather than from a generated
+    /// item, such that an item that comes from elsewhere (such as a pinned case
+    /// or an item mapped back through an inverse) can be shrunk within the
+    /// constraints of this generator. Returns `None` if `item` can not be
+    /// produced by this generator or if the generator can not rebuild its
+    /// shrinkers, which is the default.
+    fn shrink_from(&self, item: Self::Item) -> Option<Self::Shrink> {
+        let _ = item;
+        None
+    }
+
     /// Wraps `self` in a boxed [`Generate`]. This is notably relevant for
     /// recursive [`Generate`] implementations where the type would
     /// otherwise be infinite.
@@ -119,6 +173,36 @@ pub trait Generate {
         prelude::map(self, map)
     }
 
+    /// Same as [`Generate::map`] but with a partial inverse `backward` of the
+    /// `forward` function such that shrinking operates directly on the mapped
+    /// items. The mapped item is shrunk with the shrinker of its
+    /// [`FullGenerate`] generator and each candidate is mapped back by
+    /// `backward` to continue shrinking the inner generator from there (see
+    /// [`Generate::shrink_from`]). If the mapped item has no such shrinker, the
+    /// inner items are shrunk like with [`Generate::map`] instead. Candidates
+    /// that can not be mapped back by
+    /// `backward` are skipped such that the reported intermediate items always
+    /// stay in the domain of the inverse.
+    ///
+    /// ```
+    /// use checkito::*;
+    ///
+    /// // Shrinking the inner item of `reverse_bits` does not shrink the mapped item.
+    /// let generator = u16::generator().map_invertible(u16::reverse_bits, |&item| Some(item.reverse_bits()));
+    /// let fail = generator.check(|item| item < 1000).unwrap();
+    /// assert_eq!(fail.item, 1000);
+    /// ```
+    fn map_invertible<T, F: Fn(Self::Item) -> T + Clone, B: Fn(&T) -> Option<Self::Item> + Clone>(
+        self,
+        forward: F,
+        backward: B,
+    ) -> Invertible<Self, F, B>
+    where
+        Self: Sized,
+    {
+        prelude::map_invertible(self, forward, backward)
+    }
+
     /// Same as [`Generate::filter_with`] but with a predefined number of
     /// `retries`.
     fn filter<F: Fn(&Self::Item) -> bool + Clone>(self, filter: F) -> Filter<Self, F>
@@ -155,6 +239,16 @@ pub trait Generate {
         prelude::filter_map(self, filter, RETRIES)
     }
 
+    /// Substitutes an item of the `fallback` generator whenever this generator
+    /// produces a `None` (such as a [`Generate::filter`] that ran out of
+    /// retries), such that the item is a `T` instead of an `Option<T>`.
+    fn or<T, F: Generate<Item = T>>(self, fallback: F) -> Or<Self, F>
+    where
+        Self: Sized + Generate<Item = Option<T>>,
+    {
+        prelude::or(self, fallback)
+    }
+
     /// Combines [`Generate::map`] and [`Generate::filter`] in a single
     /// [`Generate`] implementation where the map function is considered to
     /// satisfy the filter when a [`Some<T>`] is produced.
@@ -219,6 +313,32 @@ pub trait Generate {
         prelude::any(self)
     }
 
+    /// Yields the items of either this generator or the `other` one, chosen
+    /// proportionally to their [`Generate::cardinality`] such that the items
+    /// of both are equally likely. If a cardinality is unknown, both generators
+    /// are chosen with equal probability. The cardinality of the concatenation
+    /// is the sum of both cardinalities.
+    ///
+    /// Since the choice is proportional, a small generator is rarely chosen
+    /// next to a large one. To favor it, use [`Generate::any`] with
+    /// [`any::Weight`](crate::any::Weight)s instead.
+    ///
+    /// ```
+    /// use checkito::*;
+    ///
+    /// // Every value of either range is equally likely, so values of `0..10` are
+    /// // chosen a third of the time.
+    /// let generator = (0..10u32).concat(100..120u32);
+    /// assert_eq!(generator.cardinality(), Some(30));
+    /// assert!(generator.samples(100).all(|value| value < 10 || (100..120).contains(&value)));
+    /// ```
+    fn concat<G: Generate<Item = Self::Item>>(self, other: G) -> Concat<Self, G>
+    where
+        Self: Sized,
+    {
+        prelude::concat(self, other)
+    }
+
     /// Generates `N` items and fills an array with it.
     fn array<const N: usize>(self) -> Array<Self, N>
     where
@@ -227,6 +347,28 @@ pub trait Generate {
         prelude::array(self)
     }
 
+    /// Generates rows of items where all rows have the same number of columns
+    /// (see [`crate::matrix()`]).
+    fn matrix<R: Generate<Item = usize>, C: Generate<Item = usize>>(
+        self,
+        rows: R,
+        columns: C,
+    ) -> Matrix<Self, R, C>
+    where
+        Self: Sized,
+    {
+        prelude::matrix(rows, columns, self)
+    }
+
+    /// Generates indices that are in bounds of the generated container (see
+    /// [`crate::with_indices`]).
+    fn with_indices<T: PartialEq, C: Generate<Item = usize>>(self, count: C) -> Indices<Self, C>
+    where
+        Self: Generate<Item = Vec<T>> + Sized,
+    {
+        prelude::with_indices(self, count)
+    }
+
     /// Same as [`Generate::collect_with`] but with a predefined `count`.
     fn collect<F: FromIterator<Self::Item>>(self) -> Collect<Self, RangeInclusive<usize>, F>
     where
@@ -238,6 +380,11 @@ pub trait Generate {
     /// Generates a variable number of items based on the provided `count`
     /// [`Generate`] and then builds a value of type `F` based on its
     /// implementation of [`FromIterator`].
+    ///
+    /// When shrinking, the collection may be truncated down to the smallest
+    /// length that `count` can produce (such as the start of a range),
+    /// regardless of how `count` shrinks. See [`Generate::count_with`] to
+    /// shrink the length through `count` instead.
     fn collect_with<C: Generate<Item = usize>, F: FromIterator<Self::Item>>(
         self,
         count: C,
@@ -245,10 +392,24 @@ pub trait Generate {
     where
         Self: Sized,
     {
-        let minimum = count.sample(0.0);
+        let minimum = collect::minimum(&count);
         prelude::collect(self, count, Some(minimum))
     }
 
+    /// Same as [`Generate::collect_with`] but the length is shrunk through the
+    /// shrinker of `count` such that shrunk lengths stay within the domain of
+    /// `count` (for example, lengths drawn from a custom distribution or only
+    /// even lengths). Items are then shrunk without changing the length.
+    fn count_with<C: Generate<Item = usize>, F: FromIterator<Self::Item>>(
+        self,
+        count: C,
+    ) -> Count<Self, C, F>
+    where
+        Self: Sized,
+    {
+        prelude::count_with(self, count)
+    }
+
     /// Maps the current `size` of the generation process to a different one.
     /// The `size` is a value in the range `[0.0..1.0]` that represents *how
     /// big* the generated items are based on the generator's constraints. The
@@ -284,6 +445,17 @@ pub trait Generate {
         prelude::dampen(self, 1.0, 8, 8192)
     }
 
+    /// Same as [`Generate::dampen`] but only the `size` of the lengths of
+    /// collections is dampened. Other items (such as numbers) keep their
+    /// regular `size` such that, for example, a tree stays small while its
+    /// payload values remain realistic.
+    fn dampen_lengths(self) -> Dampen<Self>
+    where
+        Self: Sized,
+    {
+        prelude::dampen_lengths(self, 1.0, 8, 8192)
+    }
+
     /// Dampens the `size` (see [`Generate::size`] for more information about
     /// `size`) as items are generated.
     /// - The `pressure` can be thought of as *how fast* will the `size` be
@@ -314,6 +486,111 @@ pub trait Generate {
         prelude::keep(self)
     }
 
+    /// Keeps the generated items intact until every other part of the item is
+    /// minimal and then shrinks them in a final pass. This is useful when a
+    /// component (such as an operation kind) would otherwise be shrunk first
+    /// and hide the failure that the other components reproduce.
+    fn keep_until_last(self) -> KeepUntilLast<Self>
+    where
+        Self: Sized,
+    {
+        prelude::keep_until_last(self)
+    }
+
+    /// Generates and shrinks every item twice from the same [`State`] and
+    /// panics if the results differ. This catches generators that read
+    /// external randomness or global state, which break the replay of seeds
+    /// and shrinking.
+    fn assert_deterministic(self) -> Deterministic<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq + fmt::Debug,
+    {
+        prelude::assert_deterministic(self)
+    }
+
+    /// Calls `inspect` with every generated item, which is useful to debug a
+    /// composite generator without changing its items, bounds or shrinking.
+    fn inspect<F: Fn(&Self::Item)>(self, inspect: F) -> Inspect<Self, F>
+    where
+        Self: Sized,
+    {
+        prelude::inspect(self, inspect)
+    }
+
+    /// Writes every generated item to `stderr` along with the `size` and
+    /// `depth` that produced it. See [`Generate::trace_with`] to use a
+    /// callback instead.
+    fn trace(self) -> Trace<Self, fn(&Self::Item, &State)>
+    where
+        Self: Sized,
+        Self::Item: fmt::Debug,
+    {
+        prelude::trace(self, inspect::log::<Self::Item>)
+    }
+
+    /// Calls `trace` with every generated item and the [`State`] that produced
+    /// it.
+    fn trace_with<F: Fn(&Self::Item, &State)>(self, trace: F) -> Trace<Self, F>
+    where
+        Self: Sized,
+    {
+        prelude::trace(self, trace)
+    }
+
+    /// Yields the given `cases` as the first items of a run of
+    /// [`crate::Check::check`] (or [`crate::Sample::samples`]) before any
+    /// item is generated by this generator. This allows to combine known
+    /// regression inputs with random exploration in a single property.
+    ///
+    /// Pinned cases are shrunk by this generator when it can resume shrinking
+    /// from them (see [`Generate::shrink_from`]) and are otherwise reported
+    /// as is. A case is yielded once per item by each pinned generator, such
+    /// that a pinned generator nested in a collection only pins its first
+    /// element. Note that if the number of generated items is smaller than
+    /// the number of cases, the remaining cases will not be yielded.
+    fn pinned<const N: usize>(self, cases: [Self::Item; N]) -> Pinned<Self, Self::Item, N>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        prelude::pinned(self, cases)
+    }
+
+    /// Shrinks the components of a composite item (such as a tuple) in the given
+    /// `order` of indices rather than from first to last, such that the most
+    /// impactful components are shrunk first. Components that are not listed
+    /// are shrunk afterwards in their default order.
+    ///
+    /// ```
+    /// use checkito::*;
+    ///
+    /// // Shrink the collection before the scalars.
+    /// let generator = (u8::generator(), u16::generator(), Vec::<u8>::generator()).shrink_order([2, 0, 1]);
+    /// ```
+    fn shrink_order<const N: usize>(self, order: [usize; N]) -> Order<Self, N>
+    where
+        Self: Sized,
+        Self::Shrink: Components,
+    {
+        prelude::shrink_order(self, order)
+    }
+
+    /// Wraps the generated items in an [`Arc`](std::sync::Arc) and interns
+    /// structurally equal items within a run such that they share a single
+    /// allocation. This saves memory when large equal sub-trees are generated
+    /// repeatedly and allows properties to compare them by pointer (with
+    /// [`Arc::ptr_eq`](std::sync::Arc::ptr_eq)) before comparing them by value.
+    ///
+    /// Shrinking operates on the underlying items, which are interned again.
+    fn shared(self) -> Shared<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Hash + Eq,
+    {
+        prelude::shared(self)
+    }
+
     fn unify<T>(self) -> Unify<Self, T>
     where
         Self: Sized,
@@ -327,14 +604,57 @@ pub trait Generate {
     {
         prelude::convert(self)
     }
+
+    /// Same as [`Generate::try_convert_with`] but with a predefined number of
+    /// `retries`.
+    ///
+    /// ```
+    /// use checkito::*;
+    ///
+    /// let generator = (0..0x11_0000u32).try_convert::<char>();
+    /// assert!(generator.samples(100).all(|item| item.is_some()));
+    /// ```
+    fn try_convert<T: TryFrom<Self::Item>>(self) -> TryConvert<Self, T>
+    where
+        Self: Sized,
+    {
+        prelude::try_convert(self, RETRIES)
+    }
+
+    /// Converts the items of this generator with [`TryFrom`], such as a `u32`
+    /// to a `char` or a `usize` to a [`core::num::NonZeroUsize`]. An item that
+    /// fails to convert is generated again with an increasingly large `size`,
+    /// up to the maximum number of `retries`.
+    ///
+    /// Since the conversion is not guaranteed to succeed, the item type is a
+    /// [`Option<T>`] where a [`None`] represents the failure to convert. With
+    /// `0` retries, every conversion failure is surfaced as a [`None`].
+    fn try_convert_with<T: TryFrom<Self::Item>>(self, retries: usize) -> TryConvert<Self, T>
+    where
+        Self: Sized,
+    {
+        prelude::try_convert(self, retries)
+    }
 }
 
 impl State {
+    /// The version of the reproducibility contract of [`State::draw_u64`] and
+    /// [`State::draw_choice`]. It is incremented whenever a release changes the
+    /// values they draw for a given seed. The values are drawn with a
+    /// generator that is implemented in this crate, such that they do not
+    /// change with the version of a dependency.
+    pub const VERSION: u32 = 1;
+
     pub(crate) fn new<S: Into<Sizes>>(index: usize, count: usize, size: S, seed: u64) -> Self {
         Self {
             size: self::size(index, count, size.into()),
+            index,
             depth: 0,
             limit: 0,
+            stratum: None,
+            lengths: None,
+            pins: Vec::new(),
+            covers: 0,
             seed,
             random: Random::new(seed.wrapping_add(index as _)),
         }
@@ -348,9 +668,240 @@ impl State {
         self.seed
     }
 
+    pub const fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// The number of nested generators that have been flattened while
+    /// generating the current item (see [`Generate::flatten`] and
+    /// [`Generate::dampen_with`]).
+    pub const fn limit(&self) -> u32 {
+        self.limit
+    }
+
+    /// Starts overriding the `size`, `depth` or `limit` of this state for the
+    /// lifetime of the returned [`With`], which dereferences to this state.
+    ///
+    /// ```
+    /// use checkito::{generate::State, *};
+    ///
+    /// fn half<G: Generate>(generator: &G, state: &mut State) -> G::Shrink {
+    ///     let size = state.size() / 2.0;
+    ///     generator.generate(&mut state.with().size(size))
+    /// }
+    /// ```
+    pub fn with(&mut self) -> With<'_> {
+        With {
+            state: self,
+            size: None,
+            depth: None,
+            limit: None,
+        }
+    }
+
+    /// Generates with `generator` until `accept` returns `true` for a
+    /// shrinker, at most `retries + 1` times. The size grows across retries
+    /// from the size of this state to its maximum size, like the retries of
+    /// [`Generate::filter`] and [`Generate::filter_map`], such that custom
+    /// rejection sampling combinators behave like the built-in ones.
+    ///
+    /// Returns `None` if no shrinker was accepted or as soon as a shrinker of a
+    /// constant generator is rejected.
+    pub fn retry_with_growth<G: Generate + ?Sized>(
+        &mut self,
+        generator: &G,
+        retries: usize,
+        mut accept: impl FnMut(&G::Shrink) -> bool,
+    ) -> Option<G::Shrink> {
+        let sizes = self.size;
+        for index in 0..=retries {
+            let mut state = self.with().size(size(index, retries, sizes));
+            let shrinker = generator.generate(&mut state);
+            if accept(&shrinker) {
+                return Some(shrinker);
+            } else if generator.constant() {
+                break;
+            }
+        }
+        None
+    }
+
     pub fn random(&mut self) -> &mut Random {
         &mut self.random
     }
+
+    /// Draws a `u64` uniformly within `range`.
+    ///
+    /// Unlike the methods of [`State::random`], the values drawn by this method
+    /// are part of the reproducibility contract identified by
+    /// [`State::VERSION`]: for a given seed and sequence of draws, they will not
+    /// change unless the version changes. Custom [`Generate`] implementations
+    /// that must yield the same items across releases should only draw with
+    /// [`State::draw_u64`] and [`State::draw_choice`].
+    ///
+    /// # Panics
+    /// If `range` is empty.
+    pub fn draw_u64<R: ops::RangeBounds<u64>>(&mut self, range: R) -> u64 {
+        let start = match range.start_bound() {
+            ops::Bound::Included(&start) => start,
+            ops::Bound::Excluded(&start) => start.checked_add(1).expect("empty range"),
+            ops::Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            ops::Bound::Included(&end) => end,
+            ops::Bound::Excluded(&end) => end.checked_sub(1).expect("empty range"),
+            ops::Bound::Unbounded => u64::MAX,
+        };
+        assert!(start <= end, "empty range");
+        match (end - start).checked_add(1) {
+            // Lemire's multiply-shift with rejection keeps the draw unbiased.
+            Some(count) => loop {
+                let product = self.random.wyrand() as u128 * count as u128;
+                if product as u64 >= count.wrapping_neg() % count {
+                    break start + (product >> 64) as u64;
+                }
+            },
+            None => self.random.wyrand(),
+        }
+    }
+
+    /// Draws an index uniformly in `0..count`. It follows the same
+    /// reproducibility contract as [`State::draw_u64`].
+    ///
+    /// # Panics
+    /// If `count` is `0`.
+    pub fn draw_choice(&mut self, count: usize) -> usize {
+        self.draw_u64(..count as u64) as usize
+    }
+
+    /// Assigns one of `count` strata to this state. Strata are visited in a
+    /// seeded permuted order such that consecutive indices do not map to
+    /// adjacent strata.
+    pub(crate) fn stratified(mut self, index: usize, count: usize) -> Self {
+        if count > 1 {
+            let mut step = (self.seed % count as u64) as usize | 1;
+            while gcd(step, count) != 1 {
+                step += 1;
+            }
+            let offset = (self.seed % count as u64) as usize;
+            // The product may overflow a `usize` for large counts.
+            let stratum = ((index % count) as u128 * step as u128 + offset as u128) % count as u128;
+            let stratum = stratum as usize;
+            self.stratum = Some((stratum, count));
+        }
+        self
+    }
+
+    /// Assigns one of `count` strata to this state following the base 2 van der
+    /// Corput sequence, such that any prefix of consecutive indices covers the
+    /// strata evenly.
+    pub(crate) fn grid(mut self, index: usize, count: usize) -> Self {
+        if count > 1 {
+            let point = (index as u64).reverse_bits();
+            let stratum = ((point as u128 * count as u128) >> 64) as usize;
+            self.stratum = Some((stratum, count));
+        }
+        self
+    }
+
+    /// Takes the stratum of this state as a `[low, high)` ratio range. Only the
+    /// first caller receives the stratum.
+    pub(crate) fn stratum(&mut self) -> Option<(f64, f64)> {
+        let (stratum, count) = self.stratum.take()?;
+        Some((
+            stratum as f64 / count as f64,
+            (stratum + 1) as f64 / count as f64,
+        ))
+    }
+
+    /// Registers the pinned generator at `address` with this state. Only the
+    /// first registration of a given generator succeeds such that its case is
+    /// yielded once per item even if it is generated many times (such as
+    /// within a collection).
+    pub(crate) fn pin(&mut self, address: usize) -> bool {
+        if self.pins.contains(&address) {
+            false
+        } else {
+            self.pins.push(address);
+            true
+        }
+    }
+
+    /// Registers a [`crate::cover_all_branches`] choice with this state and
+    /// returns the number of choices registered before it. Since items are
+    /// generated in the same order, a given nested choice gets the same number
+    /// for every item of a run.
+    pub(crate) fn cover(&mut self) -> usize {
+        self.covers += 1;
+        self.covers - 1
+    }
+
+    /// Draws a random ratio in `[0.0, 1.0)` that is within the stratum of this
+    /// state if it has one.
+    pub(crate) fn ratio(&mut self) -> f64 {
+        match self.stratum() {
+            Some((low, high)) => low + self.random.f64() * (high - low),
+            None => self.random.f64(),
+        }
+    }
+}
+
+impl With<'_> {
+    /// Overrides the `size` of the state (see [`State::size`]).
+    pub fn size<S: Into<Sizes>>(mut self, size: S) -> Self {
+        let size = replace(&mut self.state.size, size.into());
+        self.size.get_or_insert(size);
+        self
+    }
+
+    /// Overrides the `depth` of the state (see [`State::depth`]).
+    pub fn depth(mut self, depth: u32) -> Self {
+        let depth = replace(&mut self.state.depth, depth);
+        self.depth.get_or_insert(depth);
+        self
+    }
+
+    /// Overrides the `limit` of the state (see [`State::limit`]).
+    pub fn limit(mut self, limit: u32) -> Self {
+        let limit = replace(&mut self.state.limit, limit);
+        self.limit.get_or_insert(limit);
+        self
+    }
+}
+
+impl ops::Deref for With<'_> {
+    type Target = State;
+
+    fn deref(&self) -> &Self::Target {
+        self.state
+    }
+}
+
+impl ops::DerefMut for With<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.state
+    }
+}
+
+impl Drop for With<'_> {
+    fn drop(&mut self) {
+        if let Some(size) = self.size {
+            self.state.size = size;
+        }
+        if let Some(depth) = self.depth {
+            self.state.depth = depth;
+        }
+        if let Some(limit) = self.limit {
+            self.state.limit = limit;
+        }
+    }
+}
+
+const fn gcd(mut left: usize, mut right: usize) -> usize {
+    while right != 0 {
+        (left, right) = (right, left % right);
+    }
+    left
 }
 
 impl States {
@@ -360,6 +911,23 @@ impl States {
             count,
             size: size.into(),
             seed: seed.unwrap_or_else(random::seed),
+            grid: false,
+        }
+    }
+
+    /// Spreads the first random value drawn by each state over a
+    /// low-discrepancy sequence (see [`crate::Sample::grid`]).
+    pub(crate) const fn grid(mut self) -> Self {
+        self.grid = true;
+        self
+    }
+
+    fn state(&self, index: usize) -> State {
+        let state = State::new(index, self.count, self.size, self.seed);
+        if self.grid {
+            state.grid(index, self.count)
+        } else {
+            state
         }
     }
 }
@@ -368,12 +936,8 @@ impl Iterator for States {
     type Item = State;
 
     fn next(&mut self) -> Option<Self::Item> {
-        Some(State::new(
-            self.indices.next()?,
-            self.count,
-            self.size,
-            self.seed,
-        ))
+        let index = self.indices.next()?;
+        Some(self.state(index))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -385,21 +949,13 @@ impl Iterator for States {
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        Some(State::new(
-            self.indices.nth(n)?,
-            self.count,
-            self.size,
-            self.seed,
-        ))
+        let index = self.indices.nth(n)?;
+        Some(self.state(index))
     }
 
     fn last(mut self) -> Option<Self::Item> {
-        Some(State::new(
-            self.indices.next()?,
-            self.count,
-            self.size,
-            self.seed,
-        ))
+        let index = self.indices.next()?;
+        Some(self.state(index))
     }
 }
 
@@ -411,21 +967,13 @@ impl ExactSizeIterator for States {
 
 impl DoubleEndedIterator for States {
     fn next_back(&mut self) -> Option<Self::Item> {
-        Some(State::new(
-            self.indices.next_back()?,
-            self.count,
-            self.size,
-            self.seed,
-        ))
+        let index = self.indices.next_back()?;
+        Some(self.state(index))
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        Some(State::new(
-            self.indices.nth_back(n)?,
-            self.count,
-            self.size,
-            self.seed,
-        ))
+        let index = self.indices.nth_back(n)?;
+        Some(self.state(index))
     }
 }
 
@@ -439,7 +987,7 @@ pub(crate) fn size(index: usize, count: usize, size: Sizes) -> Sizes {
         let range = end - start;
         // This size calculation ensures that 25% of samples are fully sized.
         let ratio = index as f64 / count as f64 * 1.25;
-        Sizes::from(start + ratio * range..=end)
+        Sizes::from((start + ratio * range).min(end)..=end)
     }
 }
 
@@ -454,6 +1002,14 @@ impl<G: Generate + ?Sized> Generate for &G {
     fn constant(&self) -> bool {
         G::constant(self)
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        G::cardinality(self)
+    }
+
+    fn shrink_from(&self, item: Self::Item) -> Option<Self::Shrink> {
+        G::shrink_from(self, item)
+    }
 }
 
 impl<G: Generate + ?Sized> Generate for &mut G {
@@ -467,4 +1023,12 @@ impl<G: Generate + ?Sized> Generate for &mut G {
     fn constant(&self) -> bool {
         G::constant(self)
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        G::cardinality(self)
+    }
+
+    fn shrink_from(&self, item: Self::Item) -> Option<Self::Shrink> {
+        G::shrink_from(self, item)
+    }
 }
@@ -0,0 +1,157 @@
+use crate::{
+    collect,
+    generate::{Generate, State},
+    primitive,
+    shrink::Shrink,
+};
+use core::mem::replace;
+
+/// Generates a container along with indices that are in bounds of it (see
+/// [`crate::with_indices`]).
+#[derive(Clone, Debug)]
+pub struct Indices<G: ?Sized, C> {
+    pub(crate) count: C,
+    pub(crate) generator: G,
+}
+
+#[derive(Clone, Debug)]
+pub struct Shrinker<S> {
+    container: S,
+    indices: Indexer,
+    machine: Machine,
+}
+
+type Indexer = collect::Shrinker<primitive::Shrinker<usize>, Vec<usize>>;
+
+#[derive(Clone, Debug)]
+enum Machine {
+    Container,
+    Indices,
+    Done,
+}
+
+impl<T: PartialEq, G: Generate<Item = Vec<T>> + ?Sized, C: Generate<Item = usize>> Generate
+    for Indices<G, C>
+{
+    type Item = (Vec<T>, Vec<usize>);
+    type Shrink = Shrinker<G::Shrink>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let container = self.generator.generate(state);
+        let length = container.item().len();
+        let (count, minimum) = if length == 0 {
+            (0, 0)
+        } else {
+            let count = collect::length(&self.count, state);
+            (count, collect::minimum(&self.count).min(count))
+        };
+        let indices = Iterator::map(0..count, |_| (0..length).generate(state));
+        Shrinker {
+            container,
+            indices: collect::Shrinker::new(indices, Some(minimum)),
+            machine: Machine::Container,
+        }
+    }
+}
+
+impl<T: PartialEq, S: Shrink<Item = Vec<T>>> Shrinker<S> {
+    /// Maps the indices onto the items of a shrunk `container`. The removed
+    /// items are the ones between the common prefix and suffix of the items,
+    /// such that indices past them are shifted back onto the same items and
+    /// indices of removed items are removed. Returns `None` if fewer indices
+    /// than their minimum would remain.
+    fn remap(&self, container: &S) -> Option<Indexer> {
+        let old = self.container.item();
+        let new = container.item();
+        if new.len() >= old.len() {
+            return Some(self.indices.clone());
+        }
+        let prefix = old
+            .iter()
+            .zip(&new)
+            .take_while(|(old, new)| old == new)
+            .count();
+        let suffix = old
+            .iter()
+            .rev()
+            .zip(new.iter().rev())
+            .take_while(|(old, new)| old == new)
+            .count();
+        let removed = old.len() - new.len();
+        // When items are modified along with the removal, only the indices that
+        // remain in bounds are kept.
+        let start = if prefix + suffix >= new.len() {
+            new.len() - suffix
+        } else {
+            new.len()
+        };
+        let indices = self
+            .indices
+            .shrinkers
+            .iter()
+            .filter_map(|shrinker| match shrinker.item() {
+                index if index < start => Some(index),
+                index if index < start + removed => None,
+                index => Some(index - removed),
+            })
+            .filter(|&index| index < new.len())
+            .filter_map(|index| (0..new.len()).shrink_from(index))
+            .collect::<Vec<_>>();
+        (indices.len() >= self.indices.minimum)
+            .then(|| collect::Shrinker::new(indices, Some(self.indices.minimum)))
+    }
+}
+
+impl<T: PartialEq, S: Shrink<Item = Vec<T>>> Shrink for Shrinker<S> {
+    type Item = (Vec<T>, Vec<usize>);
+
+    /// Indices are remapped when the container shrinks, so they are always in
+    /// bounds. Any index that would not be is removed as a safeguard.
+    fn item(&self) -> Self::Item {
+        let container = self.container.item();
+        let mut indices = self.indices.item();
+        indices.retain(|&index| index < container.len());
+        (container, indices)
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        loop {
+            match replace(&mut self.machine, Machine::Done) {
+                Machine::Container => match self.container.shrink() {
+                    Some(container) => {
+                        self.machine = Machine::Container;
+                        // Candidates that would remove too many indices are skipped.
+                        if let Some(indices) = self.remap(&container) {
+                            break Some(Self {
+                                container,
+                                indices,
+                                machine: Machine::Container,
+                            });
+                        }
+                    }
+                    None => self.machine = Machine::Indices,
+                },
+                Machine::Indices => match self.indices.shrink() {
+                    Some(indices) => {
+                        self.machine = Machine::Indices;
+                        break Some(Self {
+                            container: self.container.clone(),
+                            indices,
+                            machine: Machine::Indices,
+                        });
+                    }
+                    None => self.machine = Machine::Done,
+                },
+                Machine::Done => break None,
+            }
+        }
+    }
+
+    fn release(&mut self) -> bool {
+        let released = self.container.release();
+        if released {
+            self.machine = Machine::Container;
+        }
+        released
+    }
+}
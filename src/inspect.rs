@@ -0,0 +1,131 @@
+use crate::{
+    generate::{Generate, State},
+    shrink::Shrink,
+};
+use core::{any::type_name, fmt};
+
+/// Calls a function with every generated item. Shrinking is left untouched.
+#[derive(Debug, Clone)]
+pub struct Inspect<T: ?Sized, F>(pub(crate) F, pub(crate) T);
+
+/// Calls a function with every generated item and the [`State`] that
+/// generated it. Shrinking is left untouched.
+#[derive(Debug, Clone)]
+pub struct Trace<T: ?Sized, F>(pub(crate) F, pub(crate) T);
+
+/// Generates and shrinks every item twice from the same [`State`] and panics if
+/// the results differ. Shrinking is left untouched otherwise.
+#[derive(Debug, Clone)]
+pub struct Deterministic<T: ?Sized>(pub(crate) T);
+
+impl<G: Generate + ?Sized, F: Fn(&G::Item)> Generate for Inspect<G, F> {
+    type Item = G::Item;
+    type Shrink = G::Shrink;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let shrinker = self.1.generate(state);
+        self.0(&crate::Shrink::item(&shrinker));
+        shrinker
+    }
+
+    fn constant(&self) -> bool {
+        self.1.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.1.cardinality()
+    }
+}
+
+impl<G: Generate + ?Sized, F: Fn(&G::Item, &State)> Generate for Trace<G, F> {
+    type Item = G::Item;
+    type Shrink = G::Shrink;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let shrinker = self.1.generate(state);
+        self.0(&crate::Shrink::item(&shrinker), state);
+        shrinker
+    }
+
+    fn constant(&self) -> bool {
+        self.1.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.1.cardinality()
+    }
+}
+
+impl<G: Generate + ?Sized> Generate for Deterministic<G>
+where
+    G::Item: PartialEq + fmt::Debug,
+{
+    type Item = G::Item;
+    type Shrink = Deterministic<G::Shrink>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let mut other = state.clone();
+        let shrinker = self.0.generate(state);
+        let (left, right) = (shrinker.item(), self.0.generate(&mut other).item());
+        assert!(
+            left == right,
+            "generator '{}' is not deterministic, it generated '{left:?}' and then '{right:?}' \
+             from the same state",
+            type_name::<G>(),
+        );
+        assert_eq!(
+            left,
+            shrinker.item(),
+            "shrinker '{}' is not deterministic, it produced different items",
+            type_name::<G::Shrink>(),
+        );
+        Deterministic(shrinker)
+    }
+
+    fn constant(&self) -> bool {
+        self.0.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.0.cardinality()
+    }
+}
+
+impl<S: Shrink> Shrink for Deterministic<S>
+where
+    S::Item: PartialEq + fmt::Debug,
+{
+    type Item = S::Item;
+
+    fn item(&self) -> Self::Item {
+        self.0.item()
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        let left = self.0.clone().shrink().map(|shrinker| shrinker.item());
+        let shrinker = self.0.shrink();
+        let right = shrinker.as_ref().map(S::item);
+        assert!(
+            left == right,
+            "shrinker '{}' is not deterministic, it shrunk to '{left:?}' and then '{right:?}' from \
+             the same shrinker",
+            type_name::<S>(),
+        );
+        Some(Deterministic(shrinker?))
+    }
+
+    fn release(&mut self) -> bool {
+        self.0.release()
+    }
+}
+
+/// Writes an item to `stderr` along with the `size` and `depth` of its
+/// [`State`].
+pub fn log<T: fmt::Debug>(item: &T, state: &State) {
+    eprintln!(
+        "{} {{ item: {item:?}, size: {}, depth: {} }}",
+        type_name::<T>(),
+        state.size(),
+        state.depth(),
+    );
+}
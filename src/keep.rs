@@ -2,6 +2,7 @@ use crate::{
     generate::{Generate, State},
     shrink::Shrink,
 };
+use core::mem::replace;
 
 #[derive(Clone, Debug)]
 pub struct Keep<T: ?Sized>(pub(crate) T);
@@ -17,6 +18,10 @@ impl<G: Generate + ?Sized> Generate for Keep<G> {
     fn constant(&self) -> bool {
         self.0.constant()
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.0.cardinality()
+    }
 }
 
 impl<S: Shrink> Shrink for Keep<S> {
@@ -30,3 +35,57 @@ impl<S: Shrink> Shrink for Keep<S> {
         None
     }
 }
+
+/// Keeps the generated items intact until everything else is minimal (see
+/// [`crate::keep_until_last`]).
+#[derive(Clone, Debug)]
+pub struct KeepUntilLast<T: ?Sized>(pub(crate) T);
+
+#[derive(Clone, Debug)]
+pub struct Shrinker<S> {
+    pub(crate) shrinker: S,
+    pub(crate) released: bool,
+}
+
+impl<G: Generate + ?Sized> Generate for KeepUntilLast<G> {
+    type Item = G::Item;
+    type Shrink = Shrinker<G::Shrink>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        Shrinker {
+            shrinker: self.0.generate(state),
+            released: false,
+        }
+    }
+
+    fn constant(&self) -> bool {
+        self.0.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.0.cardinality()
+    }
+}
+
+impl<S: Shrink> Shrink for Shrinker<S> {
+    type Item = S::Item;
+
+    fn item(&self) -> Self::Item {
+        self.shrinker.item()
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        if self.released {
+            Some(Self {
+                shrinker: self.shrinker.shrink()?,
+                released: true,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn release(&mut self) -> bool {
+        !replace(&mut self.released, true)
+    }
+}
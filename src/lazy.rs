@@ -0,0 +1,55 @@
+use crate::generate::{Generate, State};
+use std::sync::{Arc, Mutex};
+
+/// A generator that is constructed on first use and then reused by every
+/// subsequent call (see [`crate::lazy_once`]).
+///
+/// The constructed generator is shared by all clones of this [`Lazy`] and
+/// construction is synchronized such that it happens exactly once, even when
+/// items are generated from multiple threads at the same time. If the
+/// construction panics, it will be retried on the next use.
+#[derive(Debug)]
+pub struct Lazy<G, F> {
+    pub(crate) function: F,
+    pub(crate) generator: Arc<Mutex<Option<Arc<G>>>>,
+}
+
+impl<G, F: Clone> Clone for Lazy<G, F> {
+    fn clone(&self) -> Self {
+        Self {
+            function: self.function.clone(),
+            generator: self.generator.clone(),
+        }
+    }
+}
+
+impl<G, F: Fn() -> G> Lazy<G, F> {
+    fn generator(&self) -> Arc<G> {
+        // The lock is only held while constructing or retrieving the generator
+        // such that items are generated concurrently.
+        let mut generator = self
+            .generator
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        generator
+            .get_or_insert_with(|| Arc::new((self.function)()))
+            .clone()
+    }
+}
+
+impl<G: Generate, F: Fn() -> G> Generate for Lazy<G, F> {
+    type Item = G::Item;
+    type Shrink = G::Shrink;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        self.generator().generate(state)
+    }
+
+    fn constant(&self) -> bool {
+        self.generator().constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.generator().cardinality()
+    }
+}
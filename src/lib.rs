@@ -1,32 +1,72 @@
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "allocation"), forbid(unsafe_code))]
+// A global allocator can only be implemented with `unsafe`, which `forbid` would
+// not let the `allocation` module allow.
+#![cfg_attr(feature = "allocation", deny(unsafe_code))]
 
+pub mod adaptive;
 pub mod all;
+#[cfg(feature = "allocation")]
+pub mod allocation;
 pub mod any;
 pub mod array;
+#[cfg(feature = "bigint")]
+pub mod bigint;
 pub mod boxed;
+pub mod cardinality;
 pub mod check;
+pub mod choose;
 pub mod collect;
+pub mod concat;
+#[cfg(feature = "config")]
+pub mod config;
 pub mod convert;
+#[cfg(feature = "corpus")]
+pub mod corpus;
+pub mod count;
+pub mod cover;
 pub mod dampen;
+#[cfg(feature = "decimal")]
+pub mod decimal;
+pub mod distinct;
+pub mod draw;
+#[cfg(feature = "explore")]
+pub mod explore;
 pub mod filter;
 pub mod filter_map;
 pub mod flatten;
 pub mod generate;
+pub mod indices;
+pub mod inspect;
 pub mod keep;
+pub mod lazy;
 pub mod map;
+pub mod matrix;
 pub mod nudge;
+pub mod or;
+pub mod order;
+pub mod pinned;
 mod prelude;
 pub mod primitive;
 pub mod prove;
 pub mod random;
 pub mod regex;
+pub mod registry;
+pub mod report;
 pub mod same;
 pub mod sample;
+pub mod shared;
 pub mod shrink;
 pub mod size;
+#[cfg(feature = "insta")]
+pub mod snapshot;
+pub mod sorted;
 pub mod standard;
+pub mod tagged;
+pub mod text;
+pub mod transitions;
 pub mod unify;
 mod utility;
+pub mod with_state;
 
 pub use check::Check;
 #[cfg(feature = "check")]
@@ -35,7 +75,9 @@ pub use checkito_macro::check;
 pub use checkito_macro::regex;
 pub use generate::{FullGenerate, Generate};
 pub use prelude::*;
+pub use primitive::number::{Except, ShrinkToward};
 pub use prove::Prove;
+pub use report::context;
 pub use sample::Sample;
 pub use shrink::Shrink;
 
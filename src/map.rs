@@ -1,5 +1,5 @@
 use crate::{
-    generate::{Generate, State},
+    generate::{FullGenerate, Generate, State},
     shrink::Shrink,
 };
 
@@ -17,6 +17,10 @@ impl<G: Generate + ?Sized, T, F: Fn(G::Item) -> T + Clone> Generate for Map<G, F
     fn constant(&self) -> bool {
         self.1.constant()
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.1.cardinality()
+    }
 }
 
 impl<S: Shrink, T, F: Fn(S::Item) -> T + Clone> Shrink for Map<S, F> {
@@ -29,4 +33,148 @@ impl<S: Shrink, T, F: Fn(S::Item) -> T + Clone> Shrink for Map<S, F> {
     fn shrink(&mut self) -> Option<Self> {
         Some(Self(self.0.clone(), self.1.shrink()?))
     }
+
+    fn release(&mut self) -> bool {
+        self.1.release()
+    }
+}
+
+/// Maps the items of a generator with a function that has a partial inverse
+/// (see [`crate::map_invertible`]).
+#[derive(Debug, Clone)]
+pub struct Invertible<T: ?Sized, F, B> {
+    pub(crate) forward: F,
+    pub(crate) backward: B,
+    pub(crate) inner: T,
+}
+
+/// Shrinks the mapped items of an [`Invertible`] generator with the shrinker of
+/// their [`FullGenerate`] generator, mapping each candidate back through the
+/// inverse and continuing with the inner shrinker from there. The inner
+/// shrinker only shrinks on its own when the mapped items can not be shrunk
+/// directly since its candidates may undo the progress made on the mapped
+/// items.
+#[derive(Debug, Clone)]
+pub struct Shrinker<G, S, O, F, B> {
+    generator: G,
+    inner: S,
+    phase: Phase<O>,
+    forward: F,
+    backward: B,
+}
+
+#[derive(Debug, Clone)]
+enum Phase<O> {
+    /// The shrinker of the mapped item is not built yet.
+    Start,
+    Output(O),
+    Inner,
+}
+
+impl<
+    G: Generate + Clone,
+    T: FullGenerate<Item = T>,
+    F: Fn(G::Item) -> T + Clone,
+    B: Fn(&T) -> Option<G::Item> + Clone,
+> Generate for Invertible<G, F, B>
+{
+    type Item = T;
+    type Shrink = Shrinker<G, G::Shrink, <T::Generator as Generate>::Shrink, F, B>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        self.shrinker(self.inner.generate(state))
+    }
+
+    fn constant(&self) -> bool {
+        self.inner.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.inner.cardinality()
+    }
+
+    fn shrink_from(&self, item: Self::Item) -> Option<Self::Shrink> {
+        let inner = self.inner.shrink_from((self.backward)(&item)?)?;
+        Some(self.shrinker(inner))
+    }
+}
+
+impl<G: Clone, F: Clone, B: Clone> Invertible<G, F, B> {
+    fn shrinker<S, O>(&self, inner: S) -> Shrinker<G, S, O, F, B> {
+        Shrinker {
+            generator: self.inner.clone(),
+            inner,
+            phase: Phase::Start,
+            forward: self.forward.clone(),
+            backward: self.backward.clone(),
+        }
+    }
+}
+
+impl<
+    G: Generate<Shrink = S> + Clone,
+    S: Shrink<Item = G::Item>,
+    O: Shrink<Item = T>,
+    T: FullGenerate<Item = T>,
+    F: Fn(G::Item) -> T + Clone,
+    B: Fn(&T) -> Option<G::Item> + Clone,
+> Shrink for Shrinker<G, S, O, F, B>
+where
+    T::Generator: Generate<Shrink = O>,
+{
+    type Item = T;
+
+    fn item(&self) -> Self::Item {
+        (self.forward)(self.inner.item())
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        loop {
+            match &mut self.phase {
+                Phase::Start => {
+                    self.phase = match T::generator().shrink_from(self.item()) {
+                        Some(output) => Phase::Output(output),
+                        None => Phase::Inner,
+                    }
+                }
+                Phase::Output(output) => {
+                    let output = output.shrink()?;
+                    // Candidates that fall outside of the domain of the inverse or of the
+                    // inner generator are skipped.
+                    let inner = (self.backward)(&output.item())
+                        .and_then(|item| self.generator.shrink_from(item));
+                    if let Some(inner) = inner {
+                        break Some(Self {
+                            generator: self.generator.clone(),
+                            inner,
+                            phase: Phase::Output(output),
+                            forward: self.forward.clone(),
+                            backward: self.backward.clone(),
+                        });
+                    }
+                }
+                Phase::Inner => {
+                    let inner = self.inner.shrink()?;
+                    let item = (self.forward)(inner.item());
+                    if (self.backward)(&item).is_some() {
+                        break Some(Self {
+                            generator: self.generator.clone(),
+                            inner,
+                            phase: Phase::Inner,
+                            forward: self.forward.clone(),
+                            backward: self.backward.clone(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    fn release(&mut self) -> bool {
+        let released = self.inner.release();
+        if released {
+            self.phase = Phase::Inner;
+        }
+        released
+    }
 }
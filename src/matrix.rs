@@ -0,0 +1,155 @@
+use crate::{
+    all, cardinality, collect,
+    generate::{Generate, State},
+    shrink::Shrink,
+};
+use core::mem::replace;
+
+/// Generates rows of items that all have the same number of columns (see
+/// [`crate::matrix()`]).
+#[derive(Clone, Debug)]
+pub struct Matrix<G: ?Sized, R, C> {
+    pub(crate) rows: R,
+    pub(crate) columns: C,
+    pub(crate) minimum: (usize, usize),
+    pub(crate) generator: G,
+}
+
+#[derive(Clone, Debug)]
+pub struct Shrinker<S> {
+    /// The shrinkers of the items in row-major order.
+    shrinkers: Vec<S>,
+    rows: usize,
+    columns: usize,
+    minimum: (usize, usize),
+    machine: Machine,
+}
+
+#[derive(Clone, Debug)]
+enum Machine {
+    Rows(usize),
+    Columns(usize),
+    Shrink(usize),
+    Done,
+}
+
+impl<G: Generate + ?Sized, R: Generate<Item = usize>, C: Generate<Item = usize>> Generate
+    for Matrix<G, R, C>
+{
+    type Item = Vec<Vec<G::Item>>;
+    type Shrink = Shrinker<G::Shrink>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let rows = collect::length(&self.rows, state);
+        let columns = collect::length(&self.columns, state);
+        Shrinker {
+            shrinkers: Iterator::map(0..rows * columns, |_| self.generator.generate(state))
+                .collect(),
+            rows,
+            columns,
+            minimum: self.minimum,
+            machine: Machine::Rows(0),
+        }
+    }
+
+    fn constant(&self) -> bool {
+        self.rows.constant() && self.columns.constant() && self.generator.constant()
+    }
+
+    /// Like collections, the cardinality is only known when both dimensions
+    /// are fixed.
+    fn cardinality(&self) -> Option<u128> {
+        match (self.rows.cardinality()?, self.columns.cardinality()?) {
+            (0, _) | (_, 0) => Some(0),
+            (1, 1) => {
+                let count =
+                    collect::minimum(&self.rows).checked_mul(collect::minimum(&self.columns))?;
+                cardinality::power(self.generator.cardinality(), u32::try_from(count).ok()?)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<S: Shrink> Shrink for Shrinker<S> {
+    type Item = Vec<Vec<S::Item>>;
+
+    fn item(&self) -> Self::Item {
+        Iterator::map(0..self.rows, |row| {
+            self.shrinkers[row * self.columns..(row + 1) * self.columns]
+                .iter()
+                .map(S::item)
+                .collect()
+        })
+        .collect()
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        loop {
+            match replace(&mut self.machine, Machine::Done) {
+                // Dimensions are shrunk before items such that the shape of the matrix is
+                // minimized without having to shrink its irrelevant items first.
+                Machine::Rows(row) => {
+                    if row < self.rows && self.minimum.0 < self.rows {
+                        let mut shrinkers = self.shrinkers.clone();
+                        shrinkers.drain(row * self.columns..(row + 1) * self.columns);
+                        self.machine = Machine::Rows(row + 1);
+                        break Some(Self {
+                            shrinkers,
+                            rows: self.rows - 1,
+                            columns: self.columns,
+                            minimum: self.minimum,
+                            machine: Machine::Rows(row),
+                        });
+                    } else {
+                        self.machine = Machine::Columns(0);
+                    }
+                }
+                Machine::Columns(column) => {
+                    if column < self.columns && self.minimum.1 < self.columns {
+                        let shrinkers = self
+                            .shrinkers
+                            .iter()
+                            .enumerate()
+                            .filter(|(index, _)| index % self.columns != column)
+                            .map(|(_, shrinker)| shrinker.clone())
+                            .collect();
+                        self.machine = Machine::Columns(column + 1);
+                        break Some(Self {
+                            shrinkers,
+                            rows: self.rows,
+                            columns: self.columns - 1,
+                            minimum: self.minimum,
+                            machine: Machine::Columns(column),
+                        });
+                    } else {
+                        self.machine = Machine::Shrink(0);
+                    }
+                }
+                Machine::Shrink(mut index) => match all::shrink(&mut self.shrinkers, &mut index) {
+                    Some(shrinkers) => {
+                        self.machine = Machine::Shrink(index);
+                        break Some(Self {
+                            shrinkers,
+                            rows: self.rows,
+                            columns: self.columns,
+                            minimum: self.minimum,
+                            machine: Machine::Shrink(index),
+                        });
+                    }
+                    None => self.machine = Machine::Done,
+                },
+                Machine::Done => break None,
+            }
+        }
+    }
+
+    fn release(&mut self) -> bool {
+        let mut index = 0;
+        let released = all::release(&mut self.shrinkers, &mut index);
+        if released {
+            self.machine = Machine::Shrink(index);
+        }
+        released
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    cardinality,
+    generate::{Generate, State},
+    shrink::Shrink,
+};
+use orn::Or2;
+
+/// Substitutes the items of a `fallback` generator for the `None` items of a
+/// generator of [`Option`]s (such as a [`crate::filter::Filter`]).
+#[derive(Clone, Debug)]
+pub struct Or<G: ?Sized, F> {
+    pub(crate) fallback: F,
+    pub(crate) generator: G,
+}
+
+#[derive(Clone, Debug)]
+pub struct Shrinker<S, F>(Or2<S, F>);
+
+impl<T, G: Generate<Item = Option<T>> + ?Sized, F: Generate<Item = T>> Generate for Or<G, F> {
+    type Item = T;
+    type Shrink = Shrinker<G::Shrink, F::Shrink>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let shrinker = self.generator.generate(state);
+        if shrinker.item().is_some() {
+            Shrinker(Or2::T0(shrinker))
+        } else {
+            Shrinker(Or2::T1(self.fallback.generate(state)))
+        }
+    }
+
+    fn constant(&self) -> bool {
+        self.generator.constant() && self.fallback.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        cardinality::sum(self.generator.cardinality(), self.fallback.cardinality())
+    }
+}
+
+impl<T, S: Shrink<Item = Option<T>>, F: Shrink<Item = T>> Shrink for Shrinker<S, F> {
+    type Item = T;
+
+    fn item(&self) -> Self::Item {
+        match &self.0 {
+            Or2::T0(shrinker) => shrinker
+                .item()
+                .expect("primary shrinkers are only kept with a `Some` item"),
+            Or2::T1(shrinker) => shrinker.item(),
+        }
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        match &mut self.0 {
+            // Candidates that produce a `None` are skipped.
+            Or2::T0(shrinker) => loop {
+                let shrinker = shrinker.shrink()?;
+                if shrinker.item().is_some() {
+                    break Some(Self(Or2::T0(shrinker)));
+                }
+            },
+            Or2::T1(shrinker) => Some(Self(Or2::T1(shrinker.shrink()?))),
+        }
+    }
+
+    fn release(&mut self) -> bool {
+        match &mut self.0 {
+            Or2::T0(shrinker) => shrinker.release(),
+            Or2::T1(shrinker) => shrinker.release(),
+        }
+    }
+}
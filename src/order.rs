@@ -0,0 +1,86 @@
+use crate::{
+    generate::{Generate, State},
+    shrink::Shrink,
+};
+
+/// A shrinker of a composite item (such as a tuple) that can shrink each of
+/// its components independently.
+pub trait Components: Shrink {
+    /// Shrinks only the component at `index`. Returns `None` if the component
+    /// can not be shrunk further or if there is no such component.
+    fn shrink_component(&mut self, index: usize) -> Option<Self>;
+}
+
+#[derive(Clone, Debug)]
+pub struct Order<G: ?Sized, const N: usize> {
+    pub(crate) order: [usize; N],
+    pub(crate) generator: G,
+}
+
+#[derive(Clone, Debug)]
+pub struct Shrinker<S, const N: usize> {
+    order: [usize; N],
+    index: usize,
+    shrinker: S,
+}
+
+impl<G: Generate + ?Sized, const N: usize> Generate for Order<G, N>
+where
+    G::Shrink: Components,
+{
+    type Item = G::Item;
+    type Shrink = Shrinker<G::Shrink, N>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        Shrinker {
+            order: self.order,
+            index: 0,
+            shrinker: self.generator.generate(state),
+        }
+    }
+
+    fn constant(&self) -> bool {
+        self.generator.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.generator.cardinality()
+    }
+}
+
+impl<S: Components, const N: usize> Shrink for Shrinker<S, N> {
+    type Item = S::Item;
+
+    fn item(&self) -> Self::Item {
+        self.shrinker.item()
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        // The listed components are fully shrunk in order, then the remaining ones
+        // are shrunk in the default order of the wrapped shrinker.
+        while let Some(&component) = self.order.get(self.index) {
+            if let Some(shrinker) = self.shrinker.shrink_component(component) {
+                return Some(Self {
+                    order: self.order,
+                    index: self.index,
+                    shrinker,
+                });
+            }
+            self.index += 1;
+        }
+        Some(Self {
+            order: self.order,
+            index: self.index,
+            shrinker: self.shrinker.shrink()?,
+        })
+    }
+
+    /// Released components are shrunk in the listed order again.
+    fn release(&mut self) -> bool {
+        let released = self.shrinker.release();
+        if released {
+            self.index = 0;
+        }
+        released
+    }
+}
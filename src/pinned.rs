@@ -0,0 +1,70 @@
+use crate::{
+    cardinality,
+    generate::{Generate, State},
+    same::Same,
+    shrink::Shrink,
+};
+
+#[derive(Clone, Debug)]
+pub struct Pinned<G: ?Sized, T, const N: usize> {
+    pub(crate) cases: [T; N],
+    pub(crate) generator: G,
+}
+
+#[derive(Clone, Debug)]
+pub enum Shrinker<T, S> {
+    Case(Same<T>),
+    Generator(S),
+}
+
+impl<G: Generate + ?Sized, const N: usize> Generate for Pinned<G, G::Item, N>
+where
+    G::Item: Clone,
+{
+    type Item = G::Item;
+    type Shrink = Shrinker<G::Item, G::Shrink>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let address = self as *const Self as *const () as usize;
+        match self.cases.get(state.index) {
+            Some(case) if state.pin(address) => match self.generator.shrink_from(case.clone()) {
+                Some(shrinker) => Shrinker::Generator(shrinker),
+                None => Shrinker::Case(Same(case.clone())),
+            },
+            _ => Shrinker::Generator(self.generator.generate(state)),
+        }
+    }
+
+    fn constant(&self) -> bool {
+        N == 0 && self.generator.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        cardinality::sum(Some(N as u128), self.generator.cardinality())
+    }
+}
+
+impl<T: Clone, S: Shrink<Item = T>> Shrink for Shrinker<T, S> {
+    type Item = T;
+
+    fn item(&self) -> Self::Item {
+        match self {
+            Shrinker::Case(case) => case.item(),
+            Shrinker::Generator(shrinker) => shrinker.item(),
+        }
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        match self {
+            Shrinker::Case(_) => None,
+            Shrinker::Generator(shrinker) => Some(Shrinker::Generator(shrinker.shrink()?)),
+        }
+    }
+
+    fn release(&mut self) -> bool {
+        match self {
+            Shrinker::Case(_) => false,
+            Shrinker::Generator(shrinker) => shrinker.release(),
+        }
+    }
+}
@@ -1,10 +1,45 @@
 use crate::{
-    any::Any, array::Array, boxed::Boxed, check::Sizes, collect::Collect, convert::Convert,
-    dampen::Dampen, filter::Filter, filter_map::FilterMap, flatten::Flatten, generate::Generate,
-    keep::Keep, map::Map, primitive::number::Number, same::Same, shrink::Shrinker, size::Size,
+    adaptive::{Adaptive, Branch},
+    any::{Any, Weight},
+    array::Array,
+    boxed::Boxed,
+    check::Sizes,
+    choose::Choose,
+    collect::{self, Collect},
+    concat::Concat,
+    convert::{Convert, TryConvert},
+    count::Count,
+    cover::Cover,
+    dampen::Dampen,
+    distinct::{Distinct, MapOf, SetOf},
+    filter::Filter,
+    filter_map::FilterMap,
+    flatten::Flatten,
+    generate::{Generate, State},
+    indices::Indices,
+    inspect::{Deterministic, Inspect, Trace},
+    keep::{Keep, KeepUntilLast},
+    lazy::Lazy,
+    map::{Invertible, Map},
+    matrix::Matrix,
+    or::Or,
+    order::Order,
+    pinned::Pinned,
+    primitive::number::{Except, Excluding, Floating, Number, ShrinkToward, Toward, Ulp},
+    same::Same,
+    shared::{Shared, Table},
+    shrink::Shrinker,
+    size::Size,
+    sorted::Sorted,
+    standard::option,
+    tagged::{TaggedUnion, Width},
+    text::LOREM,
+    transitions::Transitions,
     unify::Unify,
+    with_state::WithState,
 };
-use core::marker::PhantomData;
+use core::{fmt, hash::Hash, marker::PhantomData};
+use std::sync::{Arc, Mutex};
 
 #[inline]
 pub const fn same<T: Clone>(value: T) -> Same<T> {
@@ -16,6 +51,115 @@ pub const fn any<G: Generate>(generators: G) -> Any<G> {
     Any(generators)
 }
 
+/// Chooses among `branches` (generators or [`Weight`]s) with a UCB1 bandit
+/// policy that is rewarded by the `novelty` of the generated items, a value
+/// within `0.0..=1.0`. Branches that produce novel items (such as items that
+/// reach an unseen label or state) are chosen more often as a run progresses,
+/// while the other branches keep being explored. The weight of a [`Weight`]
+/// scales the exploration of its branch.
+///
+/// The statistics start over with each run and the ties between branches are
+/// broken with the random number generator of the [`State`], such that a run
+/// is reproduced by its seed (see [`Adaptive`] for the limits of this). The
+/// `novelty` function should only depend on the items of the current run.
+///
+/// Like [`any`], the item is `None` if there are no branches.
+///
+/// ```
+/// use checkito::*;
+/// use std::{collections::HashSet, sync::Mutex};
+///
+/// let seen = Mutex::new(HashSet::new());
+/// let generator = adaptive([0..4u8, 0..255u8], |&value| {
+///     // Items that were never seen are novel.
+///     seen.lock().unwrap().insert(value) as u8 as f64
+/// });
+/// generator.check(|_| true);
+/// let arms = generator.arms();
+/// assert!(arms[1].pulls > arms[0].pulls);
+/// ```
+pub fn adaptive<B: Branch, C: AsRef<[B]>, F: Fn(&<B::Generator as Generate>::Item) -> f64>(
+    branches: C,
+    novelty: F,
+) -> Adaptive<C, F> {
+    Adaptive {
+        statistics: Mutex::default(),
+        novelty,
+        branches,
+    }
+}
+
+/// Chooses among `branches` (generators or [`Weight`]s) such that each branch
+/// is chosen at least once, in order, before the choice follows the weights.
+/// This ensures that rare branches (such as the `Err` branch of a result with a
+/// low weight) are exercised at least once in a run that generates at least as
+/// many items as there are branches, while keeping their rarity afterwards.
+///
+/// The branch is forced by the index of the generated item within its run: the
+/// items at the first indices go through a permutation of the branches that is
+/// drawn from the seed. When nested (such as in a collection or a tuple), each
+/// choice of an item draws its own permutation, such that the choices are not
+/// correlated. Since the choice only depends on the [`State`], replaying a seed
+/// yields the same items.
+///
+/// The number of times that each branch was chosen is shared between the clones
+/// of the generator and can be inspected with [`Cover::counts`].
+///
+/// Like [`any`], the item is `None` if there are no branches.
+///
+/// ```
+/// use checkito::{any::Weight, *};
+///
+/// let generator = cover_all_branches([
+///     Weight::new(1000.0, map(0..10u8, Ok::<u8, u8>).boxed()),
+///     Weight::new(0.001, map(0..10u8, Err::<u8, u8>).boxed()),
+/// ]);
+/// generator.check(|_| true);
+/// assert!(generator.counts().iter().all(|&count| count > 0));
+/// ```
+pub fn cover_all_branches<B: Branch, C: AsRef<[B]>>(branches: C) -> Cover<C> {
+    Cover {
+        counts: Arc::new(Mutex::new(Vec::new())),
+        branches,
+    }
+}
+
+/// Chooses among the listed `values`, such as the variants of a C-like enum,
+/// with a cardinality equal to the number of values.
+///
+/// Shrinks toward the first listed value. Panics if `values` is empty.
+#[inline]
+pub const fn enum_of<T: Clone>(values: &[T]) -> impl Generate<Item = T> + '_ {
+    assert!(!values.is_empty(), "enum_of requires at least one value");
+    let generator = map(0..values.len(), move |index| values[index].clone());
+    #[allow(clippy::let_and_return)]
+    generator
+}
+
+/// Converts the discriminants in `range` with `from`, in the style of
+/// `FromPrimitive`, and chooses among the resulting values. Discriminants that
+/// do not map to a value are skipped such that the cardinality is the number
+/// of valid discriminants.
+///
+/// Shrinks toward the value of the first valid discriminant. Panics if no
+/// discriminant is valid.
+pub fn enum_range<R: IntoIterator, T: Clone, F: FnMut(R::Item) -> Option<T>>(
+    range: R,
+    from: F,
+) -> impl Generate<Item = T> {
+    let values = range.into_iter().filter_map(from).collect::<Vec<_>>();
+    assert!(
+        !values.is_empty(),
+        "enum_range requires at least one valid discriminant"
+    );
+    map(0..values.len(), move |index| values[index].clone())
+}
+
+#[inline]
+pub const fn concat<L: Generate, R: Generate<Item = L::Item>>(left: L, right: R) -> Concat<L, R> {
+    Concat { left, right }
+}
+
 #[inline]
 pub const fn unify<G: Generate, T>(generator: G) -> Unify<G, T> {
     Unify(PhantomData, generator)
@@ -31,6 +175,24 @@ pub const fn map<G: Generate, T, F: Fn(G::Item) -> T + Clone>(generator: G, map:
     Map(map, generator)
 }
 
+#[inline]
+pub const fn map_invertible<
+    G: Generate,
+    T,
+    F: Fn(G::Item) -> T + Clone,
+    B: Fn(&T) -> Option<G::Item> + Clone,
+>(
+    generator: G,
+    forward: F,
+    backward: B,
+) -> Invertible<G, F, B> {
+    Invertible {
+        forward,
+        backward,
+        inner: generator,
+    }
+}
+
 #[inline]
 pub const fn flat_map<G: Generate, T: Generate, F: Fn(G::Item) -> T + Clone>(
     generator: G,
@@ -60,6 +222,17 @@ pub const fn filter<G: Generate, F: Fn(&G::Item) -> bool + Clone>(
     }
 }
 
+#[inline]
+pub const fn or<T, G: Generate<Item = Option<T>>, F: Generate<Item = T>>(
+    generator: G,
+    fallback: F,
+) -> Or<G, F> {
+    Or {
+        fallback,
+        generator,
+    }
+}
+
 #[inline]
 pub const fn filter_map<G: Generate, T, F: Fn(G::Item) -> Option<T> + Clone>(
     generator: G,
@@ -90,6 +263,18 @@ pub const fn array<G: Generate, const N: usize>(generator: G) -> Array<G, N> {
     Array(generator)
 }
 
+#[inline]
+pub const fn count_with<G: Generate, C: Generate<Item = usize>, F: FromIterator<G::Item>>(
+    generator: G,
+    count: C,
+) -> Count<G, C, F> {
+    Count {
+        _marker: PhantomData,
+        count,
+        generator,
+    }
+}
+
 #[inline]
 pub const fn collect<G: Generate, C: Generate<Item = usize>, F: FromIterator<G::Item>>(
     generator: G,
@@ -104,6 +289,232 @@ pub const fn collect<G: Generate, C: Generate<Item = usize>, F: FromIterator<G::
     }
 }
 
+/// Generates a matrix as rows of items where the number of rows and the number
+/// of columns are determined by `rows` and `columns`. All rows have the same
+/// number of columns by construction.
+///
+/// Shrinking removes whole rows, then whole columns, then shrinks the remaining
+/// items, such that the invariant holds for every shrunk matrix.
+pub fn matrix<G: Generate, R: Generate<Item = usize>, C: Generate<Item = usize>>(
+    rows: R,
+    columns: C,
+    generator: G,
+) -> Matrix<G, R, C> {
+    Matrix {
+        minimum: (collect::minimum(&rows), collect::minimum(&columns)),
+        rows,
+        columns,
+        generator,
+    }
+}
+
+/// Generates a container with `generator` along with a number of indices
+/// determined by `count` that are all in bounds of the container. No index is
+/// generated for an empty container.
+///
+/// Shrinking shrinks the container first, then removes and shrinks the indices
+/// down to the minimum of `count`. When container items are removed, the
+/// indices past them are shifted such that they still refer to the same items
+/// and the indices of the removed items are removed, unless fewer indices than
+/// the minimum of `count` would remain, in which case the removal is skipped.
+#[inline]
+pub const fn with_indices<T: PartialEq, G: Generate<Item = Vec<T>>, C: Generate<Item = usize>>(
+    generator: G,
+    count: C,
+) -> Indices<G, C> {
+    Indices { count, generator }
+}
+
+/// Selects `count` distinct items from `items`, in the order in which they
+/// appear in `items`. Every selection is equally likely.
+///
+/// Shrinking moves the selected items toward the start of `items`. Panics if
+/// `count` is greater than the number of items.
+///
+/// ```
+/// use checkito::*;
+///
+/// let items = ['a', 'b', 'c', 'd', 'e'];
+/// let fail = choose(2, &items).check(|pair| pair[1] < 'd').unwrap();
+/// assert_eq!(fail.item, ['a', 'd']);
+/// ```
+#[inline]
+pub const fn choose<T: Clone>(count: usize, items: &[T]) -> Choose<'_, T> {
+    assert!(
+        count <= items.len(),
+        "choose requires at least as many items as the count"
+    );
+    Choose {
+        items,
+        count: Some(count),
+    }
+}
+
+/// Selects a subset of `items`, in the order in which they appear in `items`.
+/// Every subset is equally likely.
+///
+/// Shrinking removes the selected items and then moves the remaining ones
+/// toward the start of `items`.
+#[inline]
+pub const fn subset<T: Clone>(items: &[T]) -> Choose<'_, T> {
+    Choose { items, count: None }
+}
+
+/// Generates a sequence of items in ascending order where the number of items
+/// is determined by `count`. The order holds by construction, which avoids
+/// filtering random sequences for sortedness.
+///
+/// Shrinking removes and shrinks items and restores the order.
+pub fn sorted<G: Generate, C: Generate<Item = usize>>(
+    generator: G,
+    count: C,
+) -> Sorted<Collect<G, C, Vec<G::Item>>>
+where
+    G::Item: Ord,
+{
+    Sorted {
+        strict: false,
+        generator: generator.collect_with(count),
+    }
+}
+
+/// Generates a sequence of distinct items in strictly ascending order where the
+/// number of items is determined by `count`. If the `generator` can not produce
+/// enough distinct items, the sequence may be shorter than requested.
+///
+/// Shrinking removes and shrinks items and restores the order. Items that
+/// shrink to an equal value are merged.
+pub fn strictly_increasing<G: Generate, C: Generate<Item = usize>>(
+    generator: G,
+    count: C,
+) -> Sorted<SetOf<G, C, Vec<G::Item>>>
+where
+    G::Item: Ord,
+{
+    Sorted {
+        strict: true,
+        generator: set_of(generator, count),
+    }
+}
+
+/// Generates sequences of states that start with `initial` where each following
+/// state is generated by one of the `successors` of the state before it, chosen
+/// uniformly. A state without successors ends the sequence.
+///
+/// Shrinking removes steps and shrinks the remaining ones. The steps that
+/// follow a removed or shrunk step are generated again from their new previous
+/// state such that every shrunk sequence is a valid chain of transitions.
+///
+/// ```
+/// use checkito::*;
+///
+/// // A counter that can be incremented up to 10 or reset.
+/// let generator = transitions(0u8, |&count: &u8| {
+///     let mut successors = vec![same(0)];
+///     if count < 10 {
+///         successors.push(same(count + 1));
+///     }
+///     successors
+/// });
+/// for states in generator.samples(100) {
+///     assert!(states.windows(2).all(|pair| pair[1] == 0 || pair[1] == pair[0] + 1));
+/// }
+/// ```
+/// Generates the frames of a wire protocol where each variant is identified by
+/// a tag and carries a payload of bytes generated by its generator. The bytes
+/// of a frame are its tag encoded with `tag`, followed by the length of its
+/// payload encoded with `length` (if any), followed by its payload.
+///
+/// Shrinking first shrinks the payload within the selected variant and then
+/// switches to the smallest payload of the variants listed before it, such
+/// that simpler variants should be listed first. Payload generators of
+/// different types can be unified with [`Generate::boxed`].
+///
+/// Panics when generating if there are no `variants`.
+///
+/// ```
+/// use checkito::{tagged::Width, *};
+///
+/// let ping = same(Vec::new()).boxed();
+/// let data = Vec::<u8>::generator().boxed();
+/// let generator = tagged_union([(1, ping), (2, data)], Width::U8, Some(Width::Varint));
+/// generator.check(|frame| {
+///     let (length, used) = Width::Varint.decode(&frame.bytes[1..]).unwrap();
+///     frame.bytes[0] as u64 == frame.tag && frame.bytes[1 + used..].len() as u64 == length
+/// });
+/// ```
+pub fn tagged_union<G: Generate, I: IntoIterator<Item = (u64, G)>>(
+    variants: I,
+    tag: Width,
+    length: Option<Width>,
+) -> TaggedUnion<G>
+where
+    G::Item: AsRef<[u8]>,
+{
+    TaggedUnion {
+        variants: variants.into_iter().collect(),
+        tag,
+        length,
+    }
+}
+
+#[inline]
+pub const fn transitions<S: Clone, G: Generate<Item = S>, F: Fn(&S) -> Vec<G> + Clone>(
+    initial: S,
+    successors: F,
+) -> Transitions<S, F> {
+    Transitions {
+        initial,
+        successors,
+    }
+}
+
+/// Generates a collection of distinct items where the number of items is
+/// determined by `count`. If the `generator` can not produce enough distinct
+/// items, the collection may be smaller than requested.
+///
+/// Shrinking removes items and shrinks the remaining ones.
+pub fn set_of<
+    G: Generate,
+    C: Generate<Item = usize>,
+    F: FromIterator<G::Item> + Distinct<G::Item>,
+>(
+    generator: G,
+    count: C,
+) -> SetOf<G, C, F> {
+    SetOf {
+        _marker: PhantomData,
+        minimum: Some(collect::minimum(&count)),
+        count,
+        generator,
+    }
+}
+
+/// Generates a collection of entries with distinct keys where keys and values
+/// are generated independently by `key` and `value` and the number of entries
+/// is determined by `count`. If the `key` generator can not produce enough
+/// distinct keys, the collection may be smaller than requested.
+///
+/// Shrinking removes entries and shrinks the remaining keys and values.
+pub fn map_of<
+    K: Generate,
+    V: Generate,
+    C: Generate<Item = usize>,
+    F: FromIterator<(K::Item, V::Item)> + Distinct<K::Item>,
+>(
+    key: K,
+    value: V,
+    count: C,
+) -> MapOf<K, V, C, F> {
+    MapOf {
+        _marker: PhantomData,
+        minimum: Some(collect::minimum(&count)),
+        count,
+        key,
+        value,
+    }
+}
+
 #[inline]
 pub const fn size<G: Generate, S: Into<Sizes>, F: Fn(Sizes) -> S>(
     generator: G,
@@ -123,28 +534,119 @@ pub const fn dampen<G: Generate>(
         pressure,
         deepest,
         limit,
+        lengths: false,
         generator,
     }
 }
 
+#[inline]
+pub const fn dampen_lengths<G: Generate>(
+    generator: G,
+    pressure: f64,
+    deepest: usize,
+    limit: usize,
+) -> Dampen<G> {
+    Dampen {
+        pressure,
+        deepest,
+        limit,
+        lengths: true,
+        generator,
+    }
+}
+
+#[inline]
+pub const fn inspect<G: Generate, F: Fn(&G::Item)>(generator: G, inspect: F) -> Inspect<G, F> {
+    Inspect(inspect, generator)
+}
+
+#[inline]
+pub const fn trace<G: Generate, F: Fn(&G::Item, &State)>(generator: G, trace: F) -> Trace<G, F> {
+    Trace(trace, generator)
+}
+
+#[inline]
+pub const fn assert_deterministic<G: Generate>(generator: G) -> Deterministic<G>
+where
+    G::Item: PartialEq + fmt::Debug,
+{
+    Deterministic(generator)
+}
+
 #[inline]
 pub const fn keep<G: Generate>(generator: G) -> Keep<G> {
     Keep(generator)
 }
 
+#[inline]
+pub const fn keep_until_last<G: Generate>(generator: G) -> KeepUntilLast<G> {
+    KeepUntilLast(generator)
+}
+
+#[inline]
+pub const fn pinned<G: Generate, const N: usize>(
+    generator: G,
+    cases: [G::Item; N],
+) -> Pinned<G, G::Item, N>
+where
+    G::Item: Clone,
+{
+    Pinned { cases, generator }
+}
+
+#[inline]
+pub const fn shrink_order<G: Generate, const N: usize>(
+    generator: G,
+    order: [usize; N],
+) -> Order<G, N> {
+    Order { order, generator }
+}
+
+#[inline]
+pub fn shared<G: Generate>(generator: G) -> Shared<G, G::Item>
+where
+    G::Item: Hash + Eq,
+{
+    Shared {
+        table: Arc::new(Mutex::new(Table::new())),
+        generator,
+    }
+}
+
 #[inline]
 pub const fn convert<G: Generate, T: From<G::Item>>(generator: G) -> Convert<G, T> {
     Convert(PhantomData, generator)
 }
 
+#[inline]
+pub const fn try_convert<G: Generate, T: TryFrom<G::Item>>(
+    generator: G,
+    retries: usize,
+) -> TryConvert<G, T> {
+    TryConvert {
+        retries,
+        marker: PhantomData,
+        generator,
+    }
+}
+
 #[cfg(feature = "regex")]
-use crate::regex::{Error, Regex};
+use crate::regex::{Bytes, Error, Regex};
 #[cfg(feature = "regex")]
 #[inline]
 pub fn regex(pattern: &str, repeats: Option<u32>) -> Result<Regex, Error> {
     Regex::new(pattern, repeats)
 }
 
+/// Byte strings that match `pattern`. Unlike [`regex`], the pattern may use the
+/// `(?-u)` byte mode to match bytes that are not valid UTF-8 (such as
+/// `(?-u:\xFF)` or `(?-u:[\x80-\xFF])`).
+#[cfg(feature = "regex")]
+#[inline]
+pub fn regex_bytes(pattern: &str, repeats: Option<u32>) -> Result<Bytes, Error> {
+    Bytes::new(pattern, repeats)
+}
+
 /// From `MIN..=MAX`.
 #[inline]
 pub const fn number<T: Number>() -> impl Generate<Item = T> {
@@ -163,6 +665,53 @@ pub const fn negative<T: Number>() -> impl Generate<Item = T> {
     T::NEGATIVE
 }
 
+/// Floating point numbers without `NaN` or infinities.
+#[inline]
+pub const fn finite<T: Floating>() -> impl Generate<Item = T> {
+    T::FINITE
+}
+
+/// Normal floating point numbers (see [`f64::is_normal`]).
+#[inline]
+pub const fn normal<T: Floating>() -> impl Generate<Item = T> {
+    T::NORMAL
+}
+
+/// Subnormal floating point numbers (see [`f64::is_subnormal`]).
+#[inline]
+pub const fn subnormal<T: Floating>() -> impl Generate<Item = T> {
+    T::SUBNORMAL
+}
+
+/// Floating point numbers that are at most `ulps` units in the last place away
+/// from `value` (bounded by `MIN` and `MAX`). Shrinks toward `value`.
+///
+/// # Panics
+/// When generating if `value` is not finite.
+#[inline]
+pub const fn ulp_near<T: Floating>(value: T, ulps: u64) -> Ulp<T> {
+    Ulp { value, ulps }
+}
+
+/// Integers of `range` other than the `values`. Unlike [`Generate::filter`],
+/// items are never rejected and the cardinality is exact. Use [`Except::except`]
+/// to remove whole sub-ranges.
+///
+/// # Panics
+/// When generating if every value has been excluded.
+#[inline]
+pub fn excluding<T, R: Except<T>>(range: R, values: &[T]) -> Excluding<T> {
+    range.excluding(values)
+}
+
+/// Numbers of `range` that shrink toward `target` rather than toward `0`, such
+/// as `1900` for years or `1.0` for scale factors. A `target` outside of the
+/// range is clamped into it (see [`ShrinkToward::shrink_toward`]).
+#[inline]
+pub fn shrink_toward<T, R: ShrinkToward<T>>(range: R, target: T) -> Toward<T> {
+    range.shrink_toward(target)
+}
+
 /// Ascii letters.
 #[inline]
 pub const fn letter() -> impl Generate<Item = char> {
@@ -187,6 +736,167 @@ pub const fn ascii() -> impl Generate<Item = char> {
     generator
 }
 
+/// Hostnames that are valid by construction (RFC 1123): 1 to 3 labels
+/// separated by `.` where each label has 1 to 63 lowercase ascii letters,
+/// digits or `-` and does not start or end with `-`.
+///
+/// Shrinks toward `a`.
+pub fn hostname() -> impl Generate<Item = String> {
+    map(
+        label().collect_with::<_, Vec<_>>(1..=3usize),
+        |labels: Vec<String>| labels.join("."),
+    )
+}
+
+/// Email addresses that are valid by construction (RFC 5321): a dot-atom local
+/// part of at most 64 characters, followed by `@` and a domain made of 1 to 2
+/// labels and an alphabetic top-level label.
+///
+/// Shrinks toward `a@a.aa`.
+pub fn email() -> impl Generate<Item = String> {
+    const SPECIALS: [char; 19] = [
+        '!', '#', '$', '%', '&', '\'', '*', '+', '-', '/', '=', '?', '^', '_', '`', '{', '|', '}',
+        '~',
+    ];
+    // A single index range (rather than an `any`) keeps single character atoms
+    // shrinking from a special character back to `a`.
+    let atom = map(0..36 + SPECIALS.len(), |index| match index {
+        0..=25 => (b'a' + index as u8) as char,
+        26..=35 => (b'0' + index as u8 - 26) as char,
+        _ => SPECIALS[index - 36],
+    })
+    .collect_with::<_, String>(1..=16usize);
+    let local = map(
+        atom.collect_with::<_, Vec<_>>(1..=3usize),
+        |atoms: Vec<String>| atoms.join("."),
+    );
+    let domain = label().collect_with::<_, Vec<_>>(1..=2usize);
+    let top = Generate::collect_with::<_, String>('a'..='z', 2..=6usize);
+    map(
+        (local, domain, top),
+        |(local, domain, top): (String, Vec<String>, String)| {
+            format!("{local}@{}.{top}", domain.join("."))
+        },
+    )
+}
+
+/// Urls that are valid by construction (RFC 3986) assembled from a scheme, a
+/// [`hostname`], an optional port, a path and an optional query made of
+/// unreserved characters.
+///
+/// Shrinks toward `http://a/`.
+pub fn url() -> impl Generate<Item = String> {
+    const SCHEMES: [&str; 4] = ["http", "https", "ftp", "ws"];
+    let scheme = map(0..SCHEMES.len(), |index| SCHEMES[index]);
+    let segment = || {
+        unify::<_, char>((
+            Weight::new(8.0, alphanumeric()),
+            Weight::new(1.0, map(0..3usize, |index| ['-', '_', '~'][index])),
+        ))
+        .collect_with::<_, String>(1..=8usize)
+    };
+    let port = option::Generator(1..=u16::MAX);
+    let path = segment().collect_with::<_, Vec<_>>(0..=4usize);
+    let query = option::Generator((segment(), segment()).collect_with::<_, Vec<_>>(1..=4usize));
+    map(
+        (scheme, hostname(), port, path, query),
+        |(scheme, host, port, path, query): (_, _, _, Vec<String>, Option<Vec<_>>)| {
+            let mut url = format!("{scheme}://{host}");
+            if let Some(port) = port {
+                url.push_str(&format!(":{port}"));
+            }
+            url.push('/');
+            url.push_str(&path.join("/"));
+            if let Some(query) = query {
+                let pairs = query
+                    .iter()
+                    .map(|(key, value): &(String, String)| format!("{key}={value}"))
+                    .collect::<Vec<_>>();
+                url.push('?');
+                url.push_str(&pairs.join("&"));
+            }
+            url
+        },
+    )
+}
+
+/// Words of 1 to 12 letters drawn from `alphabet`, such as a
+/// [`Script`](crate::text::Script).
+///
+/// Shrinks toward a single letter.
+pub fn word<G: Generate<Item = char>>(alphabet: G) -> impl Generate<Item = String> {
+    alphabet.collect_with::<_, String>(1..=12usize)
+}
+
+/// Words of the classic "lorem ipsum" placeholder text (such as `dolor` or
+/// `consectetur`), which read like natural language without the noise of
+/// random letters. They can be used as the `word` of a [`sentence`].
+///
+/// Shrinks toward `lorem`.
+pub fn lorem() -> impl Generate<Item = String> {
+    map(0..LOREM.len(), |index| LOREM[index].to_string())
+}
+
+/// Sentences of 1 to 16 `word`s separated by spaces, starting with an
+/// uppercase letter (in scripts that have one) and ending with a `punctuation`
+/// mark.
+///
+/// Shrinks by removing words, then by shrinking the remaining words.
+pub fn sentence<W: Generate<Item = String>, P: Generate<Item = char>>(
+    word: W,
+    punctuation: P,
+) -> impl Generate<Item = String> {
+    map(
+        (word.collect_with::<_, Vec<_>>(1..=16usize), punctuation),
+        |(words, punctuation): (Vec<String>, char)| {
+            let text = words.join(" ");
+            let mut characters = text.chars();
+            let mut sentence = characters.next().map_or(String::new(), |first| {
+                first.to_uppercase().chain(characters).collect()
+            });
+            sentence.push(punctuation);
+            sentence
+        },
+    )
+}
+
+/// Paragraphs of 1 to 8 `sentence`s separated by spaces.
+///
+/// Shrinks by removing sentences, then by shrinking the remaining sentences.
+pub fn paragraph<S: Generate<Item = String>>(sentence: S) -> impl Generate<Item = String> {
+    map(
+        sentence.collect_with::<_, Vec<_>>(1..=8usize),
+        |sentences: Vec<String>| sentences.join(" "),
+    )
+}
+
+/// Lowercase ascii letters and digits that shrink toward `a`.
+const fn alphanumeric() -> impl Generate<Item = char> {
+    let generator = map(0..36u8, |index| match index {
+        0..=25 => (b'a' + index) as char,
+        _ => (b'0' + index - 26) as char,
+    });
+    #[allow(clippy::let_and_return)]
+    generator
+}
+
+/// A hostname label of 1 to 63 characters that does not start or end with `-`.
+fn label() -> impl Generate<Item = String> {
+    let middle = unify::<_, char>((Weight::new(9.0, alphanumeric()), Weight::new(1.0, '-')))
+        .collect_with::<_, String>(0..=61usize);
+    map(
+        (alphanumeric(), option::Generator((middle, alphanumeric()))),
+        |(first, rest)| {
+            let mut label = String::from(first);
+            if let Some((middle, last)) = rest {
+                label.push_str(&middle);
+                label.push(last);
+            }
+            label
+        },
+    )
+}
+
 #[inline]
 pub const fn with<T, F: Fn() -> T + Clone>(generator: F) -> impl Generate<Item = T> {
     let generator = map((), move |_| generator());
@@ -194,6 +904,34 @@ pub const fn with<T, F: Fn() -> T + Clone>(generator: F) -> impl Generate<Item =
     generator
 }
 
+/// Generates items with `generate`, which has access to the whole [`State`]
+/// (its size, depth and random draws) such that custom generation logic may be
+/// written inline without implementing [`Generate`]. Items do not shrink unless
+/// a shrink function is provided with [`WithState::shrink_with`].
+///
+/// Items should only depend on the `state` for generation to be reproducible.
+///
+/// ```
+/// use checkito::*;
+///
+/// let generator = with_state(|state| {
+///     let length = (state.size() * 10.0) as usize;
+///     Iterator::map(0..length, |_| state.draw_choice(26) as u8 + b'a').collect::<Vec<_>>()
+/// });
+/// generator.check(|letters| letters.len() <= 10);
+/// ```
+#[inline]
+pub const fn with_state<T, F: Fn(&mut State) -> T>(
+    generate: F,
+) -> WithState<F, fn(&T) -> Option<T>> {
+    WithState {
+        generate,
+        shrink: |_| None,
+    }
+}
+
+/// Constructs the generator every time an item is generated (see
+/// [`lazy_once`] to construct it only once).
 #[inline]
 pub const fn lazy<G: Generate, F: Fn() -> G + Clone>(
     generator: F,
@@ -202,3 +940,14 @@ pub const fn lazy<G: Generate, F: Fn() -> G + Clone>(
     #[allow(clippy::let_and_return)]
     generator
 }
+
+/// Same as [`lazy`] but the generator is constructed only once, on first use,
+/// and reused afterwards. This is useful when constructing the generator is
+/// expensive (such as parsing a grammar). See [`Lazy`] for thread safety.
+#[inline]
+pub fn lazy_once<G: Generate, F: Fn() -> G>(generator: F) -> Lazy<G, F> {
+    Lazy {
+        function: generator,
+        generator: Arc::new(Mutex::new(None)),
+    }
+}
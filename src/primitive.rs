@@ -1,11 +1,11 @@
 use crate::{
     any::Any,
+    cardinality,
     generate::{FullGenerate, Generate, State},
     nudge::Nudge,
     shrink::Shrink,
 };
 use core::{
-    convert::TryInto,
     marker::PhantomData,
     ops::{self, Bound},
 };
@@ -23,6 +23,20 @@ pub struct Full<T: ?Sized>(PhantomData<T>);
 #[derive(Debug)]
 pub struct Special<T: ?Sized>(PhantomData<T>);
 
+/// Finite floating point numbers (excludes `NaN` and infinities).
+#[derive(Debug)]
+pub struct Finite<T: ?Sized>(PhantomData<T>);
+
+/// Normal floating point numbers (excludes zeros, subnormals, `NaN` and
+/// infinities).
+#[derive(Debug)]
+pub struct Normal<T: ?Sized>(PhantomData<T>);
+
+/// Subnormal floating point numbers (non-zero numbers smaller in magnitude than
+/// `MIN_POSITIVE`).
+#[derive(Debug)]
+pub struct Subnormal<T: ?Sized>(PhantomData<T>);
+
 #[derive(Clone, Debug)]
 pub struct Shrinker<T> {
     pub(crate) start: T,
@@ -42,6 +56,23 @@ impl<T: ?Sized> Clone for Special<T> {
 }
 impl<T: ?Sized> Copy for Special<T> {}
 
+macro_rules! phantom {
+    ($($name: ident),*) => {$(
+        impl<T: ?Sized> $name<T> {
+            pub(crate) const NEW: Self = Self(PhantomData);
+        }
+
+        impl<T: ?Sized> Clone for $name<T> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+        impl<T: ?Sized> Copy for $name<T> {}
+    )*};
+}
+
+phantom!(Finite, Normal, Subnormal);
+
 impl<T: ?Sized> Full<T> {
     pub(crate) const NEW: Self = Self(PhantomData);
 }
@@ -79,6 +110,10 @@ macro_rules! same {
             fn constant(&self) -> bool {
                 true
             }
+
+            fn cardinality(&self) -> Option<u128> {
+                Some(1)
+            }
         }
 
         impl Shrink for $t {
@@ -110,6 +145,16 @@ macro_rules! range {
                 let (start, end) = range(self);
                 (start..=end).constant()
             }
+
+            fn cardinality(&self) -> Option<u128> {
+                let (start, end) = range(self);
+                (start..=end).cardinality()
+            }
+
+            fn shrink_from(&self, item: Self::Item) -> Option<Self::Shrink> {
+                let (start, end) = range(self);
+                Some(Shrinker((start..=end).shrink_from(index(item))?))
+            }
         }
     };
     (INTEGER, $t:ident, $r:ty) => {
@@ -120,7 +165,20 @@ macro_rules! range {
             fn generate(&self, state: &mut State) -> Self::Shrink {
                 let (start, end) = range(self);
                 let (start, end) = shrinked((start, end), state.size());
-                let item = state.random().$t(start..=end);
+                let item = match state.stratum() {
+                    Some((low, high)) => {
+                        // The stratum is resolved as offsets from `start` that wrap into `$t`
+                        // such that full-width and extreme ranges keep every stratum distinct.
+                        let span = end.abs_diff(start) as u128;
+                        let range = span as f64 + 1.0;
+                        let low = ((range * low) as u128).min(span);
+                        let high = ((range * high - 1.0) as u128).clamp(low, span);
+                        let low = start.wrapping_add(low as $t);
+                        let high = start.wrapping_add(high as $t);
+                        state.random().$t(low..=high)
+                    }
+                    None => state.random().$t(start..=end),
+                };
                 Shrinker {
                     start,
                     end,
@@ -133,6 +191,21 @@ macro_rules! range {
                 let (start, end) = range(self);
                 start == end
             }
+
+            fn cardinality(&self) -> Option<u128> {
+                let (start, end) = range(self);
+                (end.abs_diff(start) as u128).checked_add(1)
+            }
+
+            fn shrink_from(&self, item: Self::Item) -> Option<Self::Shrink> {
+                let (start, end) = range(self);
+                (start..=end).contains(&item).then_some(Shrinker {
+                    start,
+                    end,
+                    item,
+                    direction: Direction::None,
+                })
+            }
         }
     };
     (FLOATING, $t:ident, $r:ty) => {
@@ -145,7 +218,7 @@ macro_rules! range {
                 debug_assert!(start.is_finite() && end.is_finite());
                 let (start, end) = shrinked((start, end), state.size());
                 debug_assert!(start.is_finite() && end.is_finite());
-                let ratio = state.random().$t();
+                let ratio = state.ratio() as $t;
                 debug_assert!(ratio.is_finite() && ratio >= 0 as $t && ratio <= 1 as $t);
                 let difference = end * ratio - start * ratio;
                 let item = (difference + start).clamp(start, end);
@@ -162,6 +235,16 @@ macro_rules! range {
                 let (start, end) = range(self);
                 start == end
             }
+
+            fn shrink_from(&self, item: Self::Item) -> Option<Self::Shrink> {
+                let (start, end) = range(self);
+                (start..=end).contains(&item).then_some(Shrinker {
+                    start,
+                    end,
+                    item,
+                    direction: Direction::None,
+                })
+            }
         }
     };
 }
@@ -303,6 +386,14 @@ pub mod bool {
         fn constant(&self) -> bool {
             false
         }
+
+        fn cardinality(&self) -> Option<u128> {
+            Some(2)
+        }
+
+        fn shrink_from(&self, item: Self::Item) -> Option<Self::Shrink> {
+            Some(Shrinker(true, item))
+        }
     }
 
     impl Shrink for Shrinker {
@@ -330,9 +421,35 @@ pub mod bool {
 pub mod char {
     use super::*;
 
+    /// Shrinks the index of a `char` in a contiguous space that excludes the
+    /// surrogate code points (`U+D800` to `U+DFFF`), such that shrinking never
+    /// produces an invalid `char`.
     #[derive(Clone, Debug)]
     pub struct Shrinker(super::Shrinker<u32>);
 
+    const SURROGATES: ops::RangeInclusive<u32> = 0xD800..=0xDFFF;
+    const GAP: u32 = *SURROGATES.end() - *SURROGATES.start() + 1;
+
+    /// Maps a `char` to its index in the contiguous space of valid `char`s such
+    /// that every index maps back to exactly one `char`.
+    const fn index(item: char) -> u32 {
+        let item = item as u32;
+        if item < *SURROGATES.start() {
+            item
+        } else {
+            item - GAP
+        }
+    }
+
+    fn unindex(index: u32) -> char {
+        let item = if index < *SURROGATES.start() {
+            index
+        } else {
+            index + GAP
+        };
+        char::from_u32(item).unwrap_or(char::REPLACEMENT_CHARACTER)
+    }
+
     impl Generate for Special<char> {
         type Item = char;
         type Shrink = char;
@@ -362,24 +479,38 @@ pub mod char {
         fn constant(&self) -> bool {
             false
         }
+
+        fn cardinality(&self) -> Option<u128> {
+            Some(15)
+        }
     }
 
     fn range<R: ops::RangeBounds<char>>(range: &R) -> (u32, u32) {
         let start = match range.start_bound() {
-            Bound::Included(&bound) => Bound::Included(bound as u32),
-            Bound::Excluded(&bound) => Bound::Excluded(bound as u32),
+            Bound::Included(&bound) => Bound::Included(index(bound)),
+            Bound::Excluded(&bound) => Bound::Excluded(index(bound)),
             Bound::Unbounded => Bound::Included(0),
         };
         let end = match range.end_bound() {
-            Bound::Included(&bound) => Bound::Included(bound as u32),
-            Bound::Excluded(&bound) => Bound::Excluded(bound as u32),
-            Bound::Unbounded => Bound::Included(char::MAX as u32),
+            Bound::Included(&bound) => Bound::Included(index(bound)),
+            Bound::Excluded(&bound) => Bound::Excluded(index(bound)),
+            Bound::Unbounded => Bound::Included(index(char::MAX)),
         };
         number::u32::range(&(start, end))
     }
 
     pub(crate) const fn shrink(item: char) -> Shrinker {
-        Shrinker(number::u32::shrinker(item as u32))
+        Shrinker(number::u32::shrinker(index(item)))
+    }
+
+    /// A shrinker that does not shrink `item` any further.
+    pub(crate) const fn fixed(item: char) -> Shrinker {
+        Shrinker(super::Shrinker {
+            start: index(item),
+            end: index(item),
+            item: index(item),
+            direction: Direction::None,
+        })
     }
 
     impl Generate for Full<char> {
@@ -396,16 +527,21 @@ pub mod char {
         fn constant(&self) -> bool {
             false
         }
+
+        fn cardinality(&self) -> Option<u128> {
+            (0 as char..=char::MAX).cardinality()
+        }
+
+        fn shrink_from(&self, item: Self::Item) -> Option<Self::Shrink> {
+            Some(shrink(item))
+        }
     }
 
     impl Shrink for Shrinker {
         type Item = char;
 
         fn item(&self) -> Self::Item {
-            self.0
-                .item()
-                .try_into()
-                .unwrap_or(char::REPLACEMENT_CHARACTER)
+            unindex(self.0.item())
         }
 
         fn shrink(&mut self) -> Option<Self> {
@@ -445,6 +581,174 @@ pub mod number {
         const NEGATIVE: Self::Negative;
     }
 
+    pub trait Floating: Number {
+        type Finite: Generate<Item = Self>;
+        type Normal: Generate<Item = Self>;
+        type Subnormal: Generate<Item = Self>;
+
+        const FINITE: Self::Finite;
+        const NORMAL: Self::Normal;
+        const SUBNORMAL: Self::Subnormal;
+    }
+
+    /// Floating point numbers within `ulps` units in the last place of `value`.
+    #[derive(Clone, Debug)]
+    pub struct Ulp<T> {
+        pub(crate) value: T,
+        pub(crate) ulps: u64,
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct UlpShrinker<T> {
+        value: T,
+        offset: Shrinker<i64>,
+    }
+
+    /// Integers of a range from which values or sub-ranges have been removed
+    /// (see [`Except`]).
+    ///
+    /// The allowed values are kept as disjoint inclusive ranges such that
+    /// generation never needs to reject an item. An item is shrunk within the
+    /// allowed range from which it was generated.
+    ///
+    /// # Panics
+    /// When generating if every value has been excluded.
+    #[derive(Clone, Debug)]
+    pub struct Excluding<T> {
+        pub(crate) ranges: Vec<(T, T)>,
+    }
+
+    /// Numbers of a range that shrink toward a `target` rather than toward `0`
+    /// (see [`ShrinkToward`]). Items are generated like the items of the
+    /// range.
+    #[derive(Clone, Debug)]
+    pub struct Toward<T> {
+        pub(crate) start: T,
+        pub(crate) end: T,
+        pub(crate) target: T,
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TowardShrinker<T> {
+        target: T,
+        item: T,
+        /// The closest value to the target that may still be accepted. Once the
+        /// target itself was rejected, it is an exclusive bound.
+        low: T,
+        tried: bool,
+    }
+
+    /// Shrinks the numbers of a range toward a meaningful origin of their
+    /// domain, such that minimal items are easier to read.
+    ///
+    /// ```
+    /// use checkito::*;
+    ///
+    /// let fail = (1900..2100u16)
+    ///     .shrink_toward(2000)
+    ///     .check(|year| year < 2050)
+    ///     .unwrap();
+    /// assert_eq!(fail.item, 2050);
+    /// ```
+    pub trait ShrinkToward<T>: Sized {
+        /// Shrinks the items of this range toward `target` instead of `0`. A
+        /// `target` outside of the range is clamped into it.
+        fn shrink_toward(self, target: T) -> Toward<T>;
+    }
+
+    macro_rules! toward {
+        ($t: ident) => {
+            impl<R: ops::RangeBounds<$t>> ShrinkToward<$t> for R {
+                fn shrink_toward(self, target: $t) -> Toward<$t> {
+                    let (start, end) = range(&self);
+                    Toward {
+                        start,
+                        end,
+                        target: target.clamp(start, end),
+                    }
+                }
+            }
+
+            impl Generate for Toward<$t> {
+                type Item = $t;
+                type Shrink = TowardShrinker<$t>;
+
+                fn generate(&self, state: &mut State) -> Self::Shrink {
+                    TowardShrinker {
+                        target: self.target,
+                        item: (self.start..=self.end).generate(state).item,
+                        low: self.target,
+                        tried: false,
+                    }
+                }
+
+                fn constant(&self) -> bool {
+                    self.start == self.end
+                }
+
+                fn cardinality(&self) -> Option<u128> {
+                    (self.start..=self.end).cardinality()
+                }
+            }
+
+            impl Shrink for TowardShrinker<$t> {
+                type Item = $t;
+
+                fn item(&self) -> Self::Item {
+                    self.item
+                }
+
+                /// Tries the target first and then bisects between the closest
+                /// value that may still be accepted and the item.
+                fn shrink(&mut self) -> Option<Self> {
+                    if !self.tried {
+                        self.tried = true;
+                        return if self.item == self.target {
+                            None
+                        } else {
+                            Some(Self {
+                                item: self.target,
+                                ..self.clone()
+                            })
+                        };
+                    }
+                    // Halving each bound first prevents overflows.
+                    let middle = if self.item > self.low {
+                        self.low + (self.item / 2 as $t - self.low / 2 as $t)
+                    } else {
+                        self.low - (self.low / 2 as $t - self.item / 2 as $t)
+                    };
+                    if middle == self.low || middle == self.item {
+                        None
+                    } else {
+                        let shrinker = Self {
+                            item: middle,
+                            ..self.clone()
+                        };
+                        self.low = middle;
+                        Some(shrinker)
+                    }
+                }
+            }
+        };
+    }
+
+    /// Removes values or sub-ranges from an integer range.
+    ///
+    /// ```
+    /// use checkito::*;
+    ///
+    /// (0..100u8)
+    ///     .except(10..20)
+    ///     .check(|item| assert!(!(10..20).contains(&item)));
+    /// ```
+    pub trait Except<T>: Sized {
+        /// Removes the values of `range`. An empty `range` removes nothing.
+        fn except<R: ops::RangeBounds<T>>(self, range: R) -> Excluding<T>;
+        /// Removes each of the `values`.
+        fn excluding(self, values: &[T]) -> Excluding<T>;
+    }
+
     macro_rules! number {
         ($t: ident) => {
             impl Number for $t {
@@ -478,6 +782,10 @@ pub mod number {
                 fn constant(&self) -> bool {
                     false
                 }
+
+                fn cardinality(&self) -> Option<u128> {
+                    Some(3)
+                }
             }
 
             pub(crate) const fn shrinker(item: $t) -> Shrinker<$t> {
@@ -498,6 +806,14 @@ pub mod number {
                 fn constant(&self) -> bool {
                     false
                 }
+
+                fn cardinality(&self) -> Option<u128> {
+                    ($t::MIN..=$t::MAX).cardinality()
+                }
+
+                fn shrink_from(&self, item: Self::Item) -> Option<Self::Shrink> {
+                    Some(shrinker(item))
+                }
             }
 
             impl Shrink for Shrinker<$t> {
@@ -542,10 +858,96 @@ pub mod number {
                 (start.0.clamp($t::MIN, end.0), end.0.clamp(start.0, $t::MAX))
             }
 
+            impl<R: ops::RangeBounds<$t>> Except<$t> for R {
+                fn except<E: ops::RangeBounds<$t>>(self, range: E) -> Excluding<$t> {
+                    Excluding { ranges: vec![self::range(&self)] }.except(range)
+                }
+
+                fn excluding(self, values: &[$t]) -> Excluding<$t> {
+                    Excluding { ranges: vec![self::range(&self)] }.excluding(values)
+                }
+            }
+
+            impl Except<$t> for Excluding<$t> {
+                fn except<R: ops::RangeBounds<$t>>(mut self, range: R) -> Excluding<$t> {
+                    let start = match range.start_bound() {
+                        Bound::Included(&bound) => Some(bound),
+                        Bound::Excluded(&bound) => bound.checked_add(1 as $t),
+                        Bound::Unbounded => Some($t::MIN),
+                    };
+                    let end = match range.end_bound() {
+                        Bound::Included(&bound) => Some(bound),
+                        Bound::Excluded(&bound) => bound.checked_sub(1 as $t),
+                        Bound::Unbounded => Some($t::MAX),
+                    };
+                    let (Some(start), Some(end)) = (start, end) else {
+                        return self;
+                    };
+                    if start > end {
+                        return self;
+                    }
+                    self.ranges = self
+                        .ranges
+                        .into_iter()
+                        .flat_map(|(low, high)| {
+                            // `low < start` ensures that `start - 1` does not overflow and
+                            // `high > end` ensures that `end + 1` does not overflow.
+                            let left = (low < start).then(|| (low, high.min(start - 1 as $t)));
+                            let right = (high > end).then(|| (low.max(end + 1 as $t), high));
+                            left.into_iter().chain(right)
+                        })
+                        .collect();
+                    self
+                }
+
+                fn excluding(self, values: &[$t]) -> Excluding<$t> {
+                    values.iter().fold(self, |excluding, &value| excluding.except(value..=value))
+                }
+            }
+
+            impl Generate for Excluding<$t> {
+                type Item = $t;
+                type Shrink = Shrinker<$t>;
+
+                fn generate(&self, state: &mut State) -> Self::Shrink {
+                    // Ranges are weighted by their size-adjusted length such that small sizes
+                    // favor the values that are closest to `0`.
+                    let size = state.size();
+                    let weight = |&(low, high): &($t, $t)| {
+                        let (low, high) = shrinked((low, high), size);
+                        high.abs_diff(low) as f64 + 1.0
+                    };
+                    let total = self.ranges.iter().map(weight).sum::<f64>();
+                    let mut ratio = state.ratio() * total;
+                    for pair in self.ranges.iter() {
+                        let weight = weight(pair);
+                        if ratio < weight {
+                            return (pair.0..=pair.1).generate(state);
+                        }
+                        ratio -= weight;
+                    }
+                    match self.ranges.last() {
+                        Some(&(low, high)) => (low..=high).generate(state),
+                        None => panic!("every value has been excluded"),
+                    }
+                }
+
+                fn constant(&self) -> bool {
+                    self.cardinality() == Some(1)
+                }
+
+                fn cardinality(&self) -> Option<u128> {
+                    self.ranges.iter().fold(Some(0), |sum, &(low, high)| {
+                        cardinality::sum(sum, (high.abs_diff(low) as u128).checked_add(1))
+                    })
+                }
+            }
+
             full!($t);
             same!($t);
             ranges!(INTEGER, $t);
             number!($t);
+            toward!($t);
         };
         ($($ts:ident),*) => { $(pub(crate) mod $ts { use super::*; integer!($ts); })* };
     }
@@ -573,6 +975,180 @@ pub mod number {
 
             shrinked!($t);
 
+            const BITS: u32 = core::mem::size_of::<$t>() as u32 * 8;
+            const SIGN: u64 = 1 << (BITS - 1);
+            const MANTISSA: u64 = (1 << ($t::MANTISSA_DIGITS - 1)) - 1;
+
+            /// Maps a number to an integer such that adjacent numbers map to
+            /// adjacent integers (both zeros map to `0`).
+            fn key(value: $t) -> i64 {
+                let bits = value.to_bits() as u64;
+                if bits & SIGN == 0 {
+                    bits as i64
+                } else {
+                    -((bits & !SIGN) as i64)
+                }
+            }
+
+            fn unkey(key: i64) -> $t {
+                if key >= 0 {
+                    $t::from_bits(key as u64 as _)
+                } else {
+                    $t::from_bits((SIGN | key.unsigned_abs()) as _)
+                }
+            }
+
+            fn signed(shrinker: Shrinker<$t>, negative: bool) -> Shrinker<$t> {
+                if negative {
+                    Shrinker {
+                        start: -shrinker.end,
+                        end: -shrinker.start,
+                        item: -shrinker.item,
+                        direction: shrinker.direction,
+                    }
+                } else {
+                    shrinker
+                }
+            }
+
+            impl Generate for Finite<$t> {
+                type Item = $t;
+                type Shrink = Shrinker<$t>;
+
+                fn generate(&self, state: &mut State) -> Self::Shrink {
+                    match state.random().u8(..) {
+                        0..=89 => ($t::MIN..=$t::MAX).generate(state),
+                        90..=179 => (-$t::EPSILON.recip()..=$t::EPSILON.recip()).generate(state),
+                        180..=214 => ($t::MIN.recip()..=$t::MAX.recip()).generate(state),
+                        215..=249 => (-$t::EPSILON..=$t::EPSILON).generate(state),
+                        250.. => {
+                            let item: $t = Any((0 as $t, $t::MIN, $t::MAX, $t::EPSILON, $t::MIN_POSITIVE))
+                                .generate(state)
+                                .into();
+                            shrinker(item)
+                        }
+                    }
+                }
+
+                fn constant(&self) -> bool {
+                    false
+                }
+            }
+
+            impl Generate for Normal<$t> {
+                type Item = $t;
+                type Shrink = Shrinker<$t>;
+
+                /// The exponent is generated such that a small `size` produces
+                /// numbers close to `1.0` in magnitude.
+                fn generate(&self, state: &mut State) -> Self::Shrink {
+                    let exponent = ($t::MIN_EXP - 1..=$t::MAX_EXP - 1).generate(state).item();
+                    let biased = (exponent + $t::MAX_EXP - 1) as u64;
+                    let mantissa = state.random().u64(..) & MANTISSA;
+                    let item = $t::from_bits((biased << ($t::MANTISSA_DIGITS - 1) | mantissa) as _);
+                    debug_assert!(item.is_normal());
+                    let shrinker = Shrinker {
+                        start: $t::MIN_POSITIVE,
+                        end: $t::MAX,
+                        item,
+                        direction: Direction::None,
+                    };
+                    signed(shrinker, state.random().bool())
+                }
+
+                fn constant(&self) -> bool {
+                    false
+                }
+            }
+
+            impl Generate for Subnormal<$t> {
+                type Item = $t;
+                type Shrink = Shrinker<$t>;
+
+                fn generate(&self, state: &mut State) -> Self::Shrink {
+                    let mantissa = (1..=MANTISSA).generate(state).item();
+                    let item = $t::from_bits(mantissa as _);
+                    debug_assert!(item.is_subnormal());
+                    let shrinker = Shrinker {
+                        start: $t::from_bits(1),
+                        end: $t::from_bits(MANTISSA as _),
+                        item,
+                        direction: Direction::None,
+                    };
+                    signed(shrinker, state.random().bool())
+                }
+
+                fn constant(&self) -> bool {
+                    false
+                }
+
+                fn cardinality(&self) -> Option<u128> {
+                    Some(MANTISSA as u128 * 2)
+                }
+            }
+
+            impl Ulp<$t> {
+                /// The range of offsets (in keys) around `value`.
+                fn offsets(&self) -> (i64, i64) {
+                    assert!(self.value.is_finite());
+                    let key = key(self.value);
+                    let ulps = i64::try_from(self.ulps).unwrap_or(i64::MAX);
+                    let low = key.saturating_sub(ulps).max(self::key($t::MIN));
+                    let high = key.saturating_add(ulps).min(self::key($t::MAX));
+                    (low - key, high - key)
+                }
+            }
+
+            impl Generate for Ulp<$t> {
+                type Item = $t;
+                type Shrink = UlpShrinker<$t>;
+
+                fn generate(&self, state: &mut State) -> Self::Shrink {
+                    let (low, high) = self.offsets();
+                    UlpShrinker {
+                        value: self.value,
+                        offset: (low..=high).generate(state),
+                    }
+                }
+
+                fn constant(&self) -> bool {
+                    self.ulps == 0
+                }
+
+                fn cardinality(&self) -> Option<u128> {
+                    let (low, high) = self.offsets();
+                    Some(high.abs_diff(low) as u128 + 1)
+                }
+            }
+
+            impl Shrink for UlpShrinker<$t> {
+                type Item = $t;
+
+                fn item(&self) -> Self::Item {
+                    match self.offset.item() {
+                        0 => self.value,
+                        offset => unkey(key(self.value) + offset),
+                    }
+                }
+
+                fn shrink(&mut self) -> Option<Self> {
+                    Some(UlpShrinker {
+                        value: self.value,
+                        offset: self.offset.shrink()?,
+                    })
+                }
+            }
+
+            impl Floating for $t {
+                type Finite = Finite<Self>;
+                type Normal = Normal<Self>;
+                type Subnormal = Subnormal<Self>;
+
+                const FINITE: Self::Finite = Finite::<$t>::NEW;
+                const NORMAL: Self::Normal = Normal::<$t>::NEW;
+                const SUBNORMAL: Self::Subnormal = Subnormal::<$t>::NEW;
+            }
+
             impl Generate for Full<$t> {
                 type Item = $t;
                 type Shrink = Shrinker<$t>;
@@ -590,6 +1166,10 @@ pub mod number {
                 fn constant(&self) -> bool {
                     false
                 }
+
+                fn shrink_from(&self, item: Self::Item) -> Option<Self::Shrink> {
+                    Some(shrinker(item))
+                }
             }
 
             impl Shrink for Shrinker<$t> {
@@ -647,6 +1227,7 @@ pub mod number {
             same!($t);
             ranges!(FLOATING, $t);
             number!($t);
+            toward!($t);
         };
         ($($ts:ident),*) => { $(pub mod $ts { use super::*; floating!($ts); })* };
     }
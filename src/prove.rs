@@ -1,4 +1,6 @@
-use core::convert::Infallible;
+use core::{convert::Infallible, fmt};
+use orn::Or2;
+use std::error;
 
 pub trait Prove {
     type Proof;
@@ -6,6 +8,197 @@ pub trait Prove {
     fn prove(self) -> Result<Self::Proof, Self::Error>;
 }
 
+/// The proof of a conjunction of properties built with the [`crate::all!`]
+/// macro.
+#[derive(Clone, Debug)]
+pub struct All<P, E>(Result<P, E>);
+
+/// A result that is proven by the property in its `Ok` value such that nested
+/// results (or `Result<bool, E>`) compose. The error of a disproved `Ok` value
+/// is distinguished from the `Err` of the result with an [`Or2`].
+///
+/// ```
+/// use checkito::{Prove, prove::Nested};
+/// use orn::Or2;
+///
+/// assert_eq!(Nested(Ok::<_, u8>(true)).prove(), Ok(()));
+/// assert_eq!(Nested(Ok::<_, u8>(false)).prove(), Err(Or2::T1(())));
+/// assert_eq!(Nested(Err::<bool, _>(1u8)).prove(), Err(Or2::T0(1)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Nested<R>(pub R);
+
+/// Identifies the property that failed in a [`crate::all!`] conjunction.
+#[derive(Debug)]
+pub struct Clause {
+    /// The position of the failed property in the conjunction.
+    pub index: usize,
+    /// The source code of the failed property.
+    pub expression: &'static str,
+    /// The error of the failed property. Properties of a conjunction may have
+    /// different error types, so it is type erased.
+    pub error: Box<dyn fmt::Debug>,
+}
+
+/// A property and its name, as checked by [`crate::Check::check_all`].
+pub type NamedProperty<'a, T, P> = (&'static str, &'a dyn Fn(&T) -> P);
+
+/// Identifies the named property that failed in [`crate::Check::check_all`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Property<E> {
+    /// The position of the failed property in the list of properties.
+    pub index: usize,
+    /// The name of the failed property.
+    pub name: &'static str,
+    /// The error of the failed property.
+    pub error: E,
+}
+
+/// The outputs of a reference and a candidate implementation that must be
+/// equal (see [`crate::Check::differential`]).
+#[derive(Clone, Debug)]
+pub struct Differential<O> {
+    reference: O,
+    candidate: O,
+}
+
+/// The outputs of a reference and a candidate implementation that disagree.
+/// Its `Display` implementation is a line diff of their pretty `Debug`
+/// representations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divergence<O> {
+    pub reference: O,
+    pub candidate: O,
+}
+
+impl<P, E> All<P, E> {
+    #[doc(hidden)]
+    pub const fn new(result: Result<P, E>) -> Self {
+        Self(result)
+    }
+}
+
+impl Clause {
+    #[doc(hidden)]
+    pub fn new<E: fmt::Debug + 'static>(index: usize, expression: &'static str, error: E) -> Self {
+        Self {
+            index,
+            expression,
+            error: Box::new(error),
+        }
+    }
+}
+
+/// Proves all the given properties in order and stops at the first one that
+/// fails. The error is a [`Clause`] that identifies the failed property, which
+/// preserves more information than a chain of `bool &&`.
+///
+/// ```
+/// use checkito::{Prove, all};
+///
+/// let value = 7;
+/// let error = all!(value > 0, value < 5, value % 2 == 1).prove().unwrap_err();
+/// assert_eq!(error.index, 1);
+/// assert_eq!(error.expression, "value < 5");
+/// ```
+#[macro_export]
+macro_rules! all {
+    ($($expression:expr),+ $(,)?) => {
+        $crate::prove::All::new($crate::all!(@prove [] (0) $($expression,)+))
+    };
+    (@prove [$($proof:ident)*] ($index:expr)) => {
+        ::core::result::Result::Ok(($($proof,)*))
+    };
+    (@prove [$($proof:ident)*] ($index:expr) $expression:expr, $($rest:tt)*) => {
+        match $crate::Prove::prove($expression) {
+            ::core::result::Result::Ok(proof) => {
+                $crate::all!(@prove [$($proof)* proof] ($index + 1) $($rest)*)
+            }
+            ::core::result::Result::Err(error) => ::core::result::Result::Err(
+                $crate::prove::Clause::new($index, ::core::stringify!($expression), error),
+            ),
+        }
+    };
+}
+
+impl<O> Differential<O> {
+    pub const fn new(reference: O, candidate: O) -> Self {
+        Self {
+            reference,
+            candidate,
+        }
+    }
+}
+
+impl<O: fmt::Debug> fmt::Display for Divergence<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reference = format!("{:#?}", self.reference);
+        let candidate = format!("{:#?}", self.candidate);
+        let references = reference.lines().collect::<Vec<_>>();
+        let candidates = candidate.lines().collect::<Vec<_>>();
+        write!(f, "outputs diverge (- reference, + candidate)")?;
+        for (sign, line) in diff(&references, &candidates) {
+            write!(f, "\n{sign} {line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Aligns the `left` and `right` lines along their longest common subsequence
+/// such that only the lines that differ are marked with `-` or `+`.
+fn diff<'a>(left: &[&'a str], right: &[&'a str]) -> Vec<(char, &'a str)> {
+    // The common prefix and suffix are trimmed such that the table only covers the lines between
+    // them.
+    let prefix = left
+        .iter()
+        .zip(right)
+        .take_while(|(left, right)| left == right)
+        .count();
+    let suffix = left[prefix..]
+        .iter()
+        .rev()
+        .zip(right[prefix..].iter().rev())
+        .take_while(|(left, right)| left == right)
+        .count();
+    let (middle, other) = (
+        &left[prefix..left.len() - suffix],
+        &right[prefix..right.len() - suffix],
+    );
+    // `lengths[i][j]` is the length of the longest common subsequence of `middle[i..]` and
+    // `other[j..]`.
+    let mut lengths = vec![vec![0usize; other.len() + 1]; middle.len() + 1];
+    for i in (0..middle.len()).rev() {
+        for j in (0..other.len()).rev() {
+            lengths[i][j] = if middle[i] == other[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+    let mut lines = left[..prefix]
+        .iter()
+        .map(|&line| (' ', line))
+        .collect::<Vec<_>>();
+    let (mut i, mut j) = (0, 0);
+    while i < middle.len() || j < other.len() {
+        if i < middle.len() && j < other.len() && middle[i] == other[j] {
+            lines.push((' ', middle[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if j == other.len() || (i < middle.len() && lengths[i + 1][j] >= lengths[i][j + 1]) {
+            lines.push(('-', middle[i]));
+            i += 1;
+        } else {
+            lines.push(('+', other[j]));
+            j += 1;
+        }
+    }
+    lines.extend(left[left.len() - suffix..].iter().map(|&line| (' ', line)));
+    lines
+}
+
+impl<O: fmt::Debug> error::Error for Divergence<O> {}
+
 impl Prove for () {
     type Error = Infallible;
     type Proof = ();
@@ -24,6 +217,16 @@ impl Prove for bool {
     }
 }
 
+/// A `None` is a failed property. A `Some` is proven by its inner property.
+impl<P: Prove> Prove for Option<P> {
+    type Error = Option<P::Error>;
+    type Proof = P::Proof;
+
+    fn prove(self) -> Result<Self::Proof, Self::Error> {
+        self.ok_or(None)?.prove().map_err(Some)
+    }
+}
+
 impl<T, E> Prove for Result<T, E> {
     type Error = E;
     type Proof = T;
@@ -32,3 +235,38 @@ impl<T, E> Prove for Result<T, E> {
         self
     }
 }
+
+impl<P: Prove, E> Prove for Nested<Result<P, E>> {
+    type Error = Or2<E, P::Error>;
+    type Proof = P::Proof;
+
+    fn prove(self) -> Result<Self::Proof, Self::Error> {
+        self.0.map_err(Or2::T0)?.prove().map_err(Or2::T1)
+    }
+}
+
+/// Proven when both outputs are equal, in which case the proof is the output.
+impl<O: PartialEq> Prove for Differential<O> {
+    type Error = Divergence<O>;
+    type Proof = O;
+
+    fn prove(self) -> Result<Self::Proof, Self::Error> {
+        if self.reference == self.candidate {
+            Ok(self.candidate)
+        } else {
+            Err(Divergence {
+                reference: self.reference,
+                candidate: self.candidate,
+            })
+        }
+    }
+}
+
+impl<P, E> Prove for All<P, E> {
+    type Error = E;
+    type Proof = P;
+
+    fn prove(self) -> Result<Self::Proof, Self::Error> {
+        self.0
+    }
+}
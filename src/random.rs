@@ -12,6 +12,21 @@ impl Random {
     pub fn seed(&self) -> u64 {
         self.0.get_seed()
     }
+
+    /// Draws a `u64` with the final version (4.2) of the wyrand algorithm.
+    /// Unlike the other methods, it does not depend on the version of
+    /// `fastrand` (which has changed its constants before), such that its
+    /// values can be part of the reproducibility contract of
+    /// [`crate::generate::State::VERSION`]. It advances the same state as the
+    /// other methods.
+    pub(crate) fn wyrand(&mut self) -> u64 {
+        const INCREMENT: u64 = 0x2D35_8DCC_AA6C_78A5;
+        const MULTIPLIER: u64 = 0x8BB8_4B93_962E_ACC9;
+        let seed = self.0.get_seed().wrapping_add(INCREMENT);
+        self.0.seed(seed);
+        let product = u128::from(seed) * u128::from(seed ^ MULTIPLIER);
+        product as u64 ^ (product >> 64) as u64
+    }
 }
 
 pub(crate) fn seed() -> u64 {
@@ -6,12 +6,12 @@ use crate::{
     collect::{self},
     generate::{Generate, State},
     prelude::collect,
-    primitive::char,
+    primitive::{self, char},
     shrink::Shrink,
 };
 use core::{fmt, ops::RangeInclusive};
 use regex_syntax::{
-    Parser,
+    Parser, ParserBuilder,
     hir::{Capture, Class, ClassBytesRange, ClassUnicodeRange, Hir, HirKind, Repetition},
 };
 
@@ -19,7 +19,9 @@ use regex_syntax::{
 pub enum Regex {
     Empty,
     Text(String),
+    Bytes(Box<[u8]>),
     Range(RangeInclusive<char>),
+    Byte(RangeInclusive<u8>),
     Collect(collect::Collect<Box<Regex>, RangeInclusive<usize>, String>),
     Any(any::Any<Box<[Regex]>>),
     All(Box<[Regex]>),
@@ -29,11 +31,21 @@ pub enum Regex {
 pub enum Shrinker {
     Empty,
     Text(String),
+    Bytes(Box<[u8]>),
     Range(char::Shrinker),
+    Byte(primitive::Shrinker<u8>),
     All(all::Shrinker<Box<[Shrinker]>>),
     Collect(collect::Shrinker<Shrinker, String>),
 }
 
+/// A generator of byte strings that match a pattern parsed in byte mode (see
+/// [`crate::regex_bytes`]).
+#[derive(Debug, Clone)]
+pub struct Bytes(pub(crate) Regex);
+
+#[derive(Debug, Clone)]
+pub struct BytesShrinker(Shrinker);
+
 #[derive(Clone)]
 pub struct Error(Box<regex_syntax::Error>);
 
@@ -50,6 +62,15 @@ impl Regex {
     }
 }
 
+impl Bytes {
+    pub(crate) fn new(pattern: &str, repeats: Option<u32>) -> Result<Self, Error> {
+        // Disabling `utf8` allows `(?-u)` classes and literals to match bytes that
+        // are not valid UTF-8 on their own.
+        let hir = ParserBuilder::new().utf8(false).build().parse(pattern)?;
+        Ok(Bytes(Regex::from_hir(hir, repeats.unwrap_or(REPEATS))))
+    }
+}
+
 impl From<regex_syntax::Error> for Error {
     fn from(value: regex_syntax::Error) -> Self {
         Error(Box::new(value))
@@ -64,7 +85,7 @@ impl From<&ClassUnicodeRange> for Regex {
 
 impl From<&ClassBytesRange> for Regex {
     fn from(value: &ClassBytesRange) -> Self {
-        Regex::Range(value.start() as char..=value.end() as char)
+        Regex::Byte(value.start()..=value.end())
     }
 }
 
@@ -97,9 +118,10 @@ impl Regex {
     fn from_hir(hir: Hir, repeats: u32) -> Self {
         match hir.into_kind() {
             HirKind::Empty | HirKind::Look(_) => Self::Empty,
-            HirKind::Literal(literal) => {
-                String::from_utf8(literal.0.to_vec()).map_or(Self::Empty, Self::Text)
-            }
+            HirKind::Literal(literal) => match String::from_utf8(literal.0.to_vec()) {
+                Ok(text) => Self::Text(text),
+                Err(error) => Self::Bytes(error.into_bytes().into_boxed_slice()),
+            },
             HirKind::Capture(Capture { sub, .. }) => Self::from_hir(*sub, repeats),
             HirKind::Repetition(Repetition { min, max, sub, .. }) => {
                 let tree = Self::from_hir(*sub, repeats / 2);
@@ -147,7 +169,9 @@ impl Generate for Regex {
         match self {
             Regex::Empty => Shrinker::Empty,
             Regex::Text(text) => Shrinker::Text(text.clone()),
+            Regex::Bytes(bytes) => Shrinker::Bytes(bytes.clone()),
             Regex::Range(range) => Shrinker::Range(range.generate(state)),
+            Regex::Byte(range) => Shrinker::Byte(range.generate(state)),
             Regex::Collect(collect) => Shrinker::Collect(collect.generate(state)),
             Regex::Any(any) => any.generate(state).0.unwrap_or(Shrinker::Empty),
             Regex::All(all) => Shrinker::All(all.generate(state)),
@@ -156,8 +180,9 @@ impl Generate for Regex {
 
     fn constant(&self) -> bool {
         match self {
-            Regex::Empty | Regex::Text(_) => true,
+            Regex::Empty | Regex::Text(_) | Regex::Bytes(_) => true,
             Regex::Range(range) => range.constant(),
+            Regex::Byte(range) => range.constant(),
             Regex::Collect(collect) => collect.constant(),
             Regex::Any(any) => any.constant(),
             Regex::All(all) => all.constant(),
@@ -165,39 +190,75 @@ impl Generate for Regex {
     }
 }
 
-impl Shrink for Shrinker {
-    type Item = String;
+impl Generate for Bytes {
+    type Item = Vec<u8>;
+    type Shrink = BytesShrinker;
 
-    fn item(&self) -> Self::Item {
-        fn descend(shrinker: &Shrinker, buffer: &mut String) {
-            match shrinker {
-                Shrinker::Empty => {}
-                Shrinker::Text(text) => buffer.push_str(text),
-                Shrinker::Range(shrinker) => buffer.push(shrinker.item()),
-                Shrinker::All(shrinker) => {
-                    for shrinker in shrinker.shrinkers.iter() {
-                        descend(shrinker, buffer);
-                    }
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        BytesShrinker(self.0.generate(state))
+    }
+
+    fn constant(&self) -> bool {
+        self.0.constant()
+    }
+}
+
+impl Shrinker {
+    fn descend(&self, buffer: &mut Vec<u8>) {
+        match self {
+            Shrinker::Empty => {}
+            Shrinker::Text(text) => buffer.extend_from_slice(text.as_bytes()),
+            Shrinker::Bytes(bytes) => buffer.extend_from_slice(bytes),
+            Shrinker::Range(shrinker) => {
+                buffer.extend_from_slice(shrinker.item().encode_utf8(&mut [0; 4]).as_bytes())
+            }
+            Shrinker::Byte(shrinker) => buffer.push(shrinker.item()),
+            Shrinker::All(shrinker) => {
+                for shrinker in shrinker.shrinkers.iter() {
+                    shrinker.descend(buffer);
                 }
-                Shrinker::Collect(shrinker) => {
-                    for shrinker in shrinker.shrinkers.iter() {
-                        descend(shrinker, buffer);
-                    }
+            }
+            Shrinker::Collect(shrinker) => {
+                for shrinker in shrinker.shrinkers.iter() {
+                    shrinker.descend(buffer);
                 }
             }
         }
+    }
+}
 
-        let mut buffer = String::new();
-        descend(self, &mut buffer);
-        buffer
+impl Shrink for Shrinker {
+    type Item = String;
+
+    fn item(&self) -> Self::Item {
+        // A pattern parsed in UTF-8 mode can only produce valid UTF-8.
+        let mut buffer = Vec::new();
+        self.descend(&mut buffer);
+        String::from_utf8(buffer)
+            .unwrap_or_else(|error| String::from_utf8_lossy(error.as_bytes()).into_owned())
     }
 
     fn shrink(&mut self) -> Option<Self> {
         match self {
-            Self::Empty | Self::Text(_) => None,
+            Self::Empty | Self::Text(_) | Self::Bytes(_) => None,
             Self::Range(shrinker) => Some(Self::Range(shrinker.shrink()?)),
+            Self::Byte(shrinker) => Some(Self::Byte(shrinker.shrink()?)),
             Self::All(shrinker) => Some(Self::All(shrinker.shrink()?)),
             Self::Collect(shrinker) => Some(Self::Collect(shrinker.shrink()?)),
         }
     }
 }
+
+impl Shrink for BytesShrinker {
+    type Item = Vec<u8>;
+
+    fn item(&self) -> Self::Item {
+        let mut buffer = Vec::new();
+        self.0.descend(&mut buffer);
+        buffer
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        Some(BytesShrinker(self.0.shrink()?))
+    }
+}
@@ -0,0 +1,228 @@
+//! A registry of named constructors of trait objects.
+//!
+//! Plugin-style architectures are tested against many implementations of a
+//! trait. A [`Registry`] gathers generators of boxed implementations under a
+//! name and a weight such that they can be generated as a single generator of
+//! [`Named`] trait objects, whose name identifies the constructor of a failing
+//! item in reports.
+//!
+//! ```
+//! use checkito::{registry::Registry, *};
+//!
+//! trait Shape {
+//!     fn area(&self) -> f64;
+//! }
+//!
+//! struct Square(f64);
+//! struct Circle(f64);
+//!
+//! impl Shape for Square {
+//!     fn area(&self) -> f64 {
+//!         self.0 * self.0
+//!     }
+//! }
+//!
+//! impl Shape for Circle {
+//!     fn area(&self) -> f64 {
+//!         self.0 * self.0 * core::f64::consts::PI
+//!     }
+//! }
+//!
+//! let registry = Registry::<dyn Shape>::new()
+//!     .register("square", 1.0, (0.0..10.0).map(|side| Box::new(Square(side)) as Box<dyn Shape>))
+//!     .register("circle", 2.0, (0.0..10.0).map(|radius| Box::new(Circle(radius)) as Box<_>));
+//! assert!(registry.check(|shape| shape.area() >= 0.0).is_none());
+//! ```
+
+use crate::{
+    any::{self, Weight},
+    boxed::{self, Boxed},
+    cardinality,
+    generate::{Generate, State},
+    shrink::Shrink,
+};
+use core::{fmt, ops::Deref};
+
+/// A generator of trait objects built by weighted named constructors.
+pub struct Registry<T: ?Sized> {
+    constructors: Vec<Weight<Constructor<T>>>,
+}
+
+/// A generator of trait objects of a [`Registry`] under a name.
+struct Constructor<T: ?Sized> {
+    name: &'static str,
+    generator: Boxed<Box<T>>,
+}
+
+/// A trait object labeled with the name of the constructor that built it. Its
+/// `Debug` representation is the name since trait objects usually do not
+/// implement `Debug`.
+pub struct Named<T: ?Sized> {
+    pub name: &'static str,
+    pub value: Box<T>,
+}
+
+pub struct Shrinker<T: ?Sized> {
+    name: &'static str,
+    shrinker: boxed::Shrinker<Box<T>>,
+}
+
+impl<T: ?Sized> Registry<T> {
+    pub const fn new() -> Self {
+        Self {
+            constructors: Vec::new(),
+        }
+    }
+
+    /// Registers the `generator` of trait objects under `name`. It is chosen
+    /// with a probability proportional to its `weight`.
+    ///
+    /// # Panics
+    /// If the `weight` is not finite or not strictly positive.
+    pub fn register<G: Generate<Item = Box<T>> + 'static>(
+        mut self,
+        name: &'static str,
+        weight: f64,
+        generator: G,
+    ) -> Self
+    where
+        G::Shrink: 'static,
+    {
+        let generator = Boxed::new(Box::new(generator));
+        self.constructors
+            .push(Weight::new(weight, Constructor { name, generator }));
+        self
+    }
+
+    /// The names of the registered constructors in registration order.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.constructors
+            .iter()
+            .map(|constructor| constructor.value().name)
+    }
+}
+
+impl<T: ?Sized> Default for Registry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> Named<T> {
+    pub fn into_inner(self) -> Box<T> {
+        self.value
+    }
+}
+
+impl<T: ?Sized> Deref for Named<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Registry<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Constructor<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Constructor").field(&self.name).finish()
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Named<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Named").field(&self.name).finish()
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Shrinker<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Shrinker").field(&self.name).finish()
+    }
+}
+
+impl<T: ?Sized> Clone for Shrinker<T> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name,
+            shrinker: self.shrinker.clone(),
+        }
+    }
+}
+
+impl<T: ?Sized> Generate for Constructor<T> {
+    type Item = Named<T>;
+    type Shrink = Shrinker<T>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        Shrinker {
+            name: self.name,
+            shrinker: self.generator.generate(state),
+        }
+    }
+
+    fn constant(&self) -> bool {
+        self.generator.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.generator.cardinality()
+    }
+}
+
+impl<T: ?Sized> Generate for Registry<T> {
+    type Item = Named<T>;
+    type Shrink = Shrinker<T>;
+
+    /// # Panics
+    /// If no constructor is registered.
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let index = any::weighted(&self.constructors, state)
+            .expect("a registry must have at least one constructor");
+        self.constructors[index].value().generate(state)
+    }
+
+    fn constant(&self) -> bool {
+        match self.constructors.as_slice() {
+            [constructor] => constructor.value().constant(),
+            _ => false,
+        }
+    }
+
+    /// The sum of the cardinalities of the constructors, which assumes that
+    /// different constructors build different trait objects.
+    fn cardinality(&self) -> Option<u128> {
+        self.constructors.iter().try_fold(0, |sum, constructor| {
+            cardinality::sum(Some(sum), constructor.value().cardinality())
+        })
+    }
+}
+
+impl<T: ?Sized> Shrink for Shrinker<T> {
+    type Item = Named<T>;
+
+    /// A trait object is shrunk within its constructor such that the name of a
+    /// failing item never changes while shrinking.
+    fn item(&self) -> Self::Item {
+        Named {
+            name: self.name,
+            value: self.shrinker.item(),
+        }
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        Some(Self {
+            name: self.name,
+            shrinker: self.shrinker.shrink()?,
+        })
+    }
+
+    fn release(&mut self) -> bool {
+        self.shrinker.release()
+    }
+}
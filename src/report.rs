@@ -0,0 +1,50 @@
+//! Context breadcrumbs that are attached to the failures of a check.
+//!
+//! A panic message alone loses the intermediate steps of a property that runs
+//! in multiple stages. Calling [`context`] while a check runs records a
+//! breadcrumb for the item being checked, and the breadcrumbs of the item that
+//! failed are included in its [`Fail`](crate::check::Fail). Since they are
+//! recorded again for every shrunk candidate, the reported breadcrumbs are the
+//! ones of the minimal item.
+//!
+//! ```
+//! use checkito::*;
+//!
+//! let fail = (0..100u8, 0..100u8)
+//!     .check(|(header, body)| {
+//!         checkito::context("while parsing header");
+//!         assert!(header < 200);
+//!         checkito::context(format!("while parsing body of length {body}"));
+//!         assert!(body < 10);
+//!     })
+//!     .unwrap();
+//! assert_eq!(
+//!     fail.context,
+//!     ["while parsing header", "while parsing body of length 10"]
+//! );
+//! ```
+
+use core::cell::RefCell;
+use std::borrow::Cow;
+
+thread_local! { static CONTEXT: RefCell<Option<Vec<Cow<'static, str>>>> = const { RefCell::new(None) }; }
+
+/// Records a breadcrumb for the item that is currently being checked on this
+/// thread. Outside of a check, this does nothing.
+pub fn context<C: Into<Cow<'static, str>>>(context: C) {
+    CONTEXT.with(|breadcrumbs| {
+        if let Some(breadcrumbs) = breadcrumbs.borrow_mut().as_mut() {
+            breadcrumbs.push(context.into());
+        }
+    });
+}
+
+/// Runs `run` with an empty list of breadcrumbs and returns the breadcrumbs
+/// that it recorded. The breadcrumbs of an enclosing check are restored
+/// afterwards such that nested checks do not interfere.
+pub(crate) fn capture<T>(run: impl FnOnce() -> T) -> (T, Vec<Cow<'static, str>>) {
+    let outer = CONTEXT.with(|breadcrumbs| breadcrumbs.replace(Some(Vec::new())));
+    let value = run();
+    let inner = CONTEXT.with(|breadcrumbs| breadcrumbs.replace(outer));
+    (value, inner.unwrap_or_default())
+}
@@ -17,6 +17,10 @@ impl<T: Clone> Generate for Same<T> {
     fn constant(&self) -> bool {
         true
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        Some(1)
+    }
 }
 
 impl<T: Clone> Shrink for Same<T> {
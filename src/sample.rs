@@ -4,6 +4,56 @@ use crate::{
     shrink::{Shrink, Shrinkers, shrinker},
 };
 use core::{iter, ops::Range};
+use format::Format;
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+};
+
+/// The formats in which [`Sample::write_to_dir`] writes items.
+pub mod format {
+    use core::fmt;
+    use std::io;
+
+    /// Writes an item to a fixture file.
+    pub trait Format<T: ?Sized> {
+        /// The extension of the fixture files (without the leading `.`).
+        fn extension(&self) -> &str;
+        fn write(&self, item: &T, writer: &mut dyn io::Write) -> io::Result<()>;
+    }
+
+    /// Writes the pretty-printed `Debug` representation of items.
+    #[derive(Clone, Copy, Debug)]
+    pub struct Debug;
+
+    /// Writes items as pretty-printed JSON.
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    #[derive(Clone, Copy, Debug)]
+    pub struct Json;
+
+    impl<T: fmt::Debug + ?Sized> Format<T> for Debug {
+        fn extension(&self) -> &str {
+            "txt"
+        }
+
+        fn write(&self, item: &T, writer: &mut dyn io::Write) -> io::Result<()> {
+            writeln!(writer, "{item:#?}")
+        }
+    }
+
+    #[cfg(all(feature = "serde", feature = "serde_json"))]
+    impl<T: serde::Serialize + ?Sized> Format<T> for Json {
+        fn extension(&self) -> &str {
+            "json"
+        }
+
+        fn write(&self, item: &T, mut writer: &mut dyn io::Write) -> io::Result<()> {
+            serde_json::to_writer_pretty(&mut writer, item)?;
+            writeln!(writer)
+        }
+    }
+}
 
 #[derive(Debug)]
 pub struct Sampler<'a, G: ?Sized> {
@@ -18,6 +68,10 @@ pub struct Sampler<'a, G: ?Sized> {
     /// Number of samples that will be generated.
     /// Defaults to `100`.
     pub count: usize,
+    /// Whether or not the samples are spread over a low-discrepancy sequence
+    /// (see [`Sample::grid`]).
+    /// Defaults to `false`.
+    pub grid: bool,
 }
 
 #[derive(Debug)]
@@ -26,15 +80,27 @@ pub struct Samples<'a, G: ?Sized>(Shrinkers<'a, G>);
 pub trait Sample: Generate {
     /// Provides a [`Sampler`] that allows to configure sampling settings and
     /// generate samples.
-    fn sampler(&self) -> Sampler<Self> {
+    fn sampler(&self) -> Sampler<'_, Self> {
         Sampler::new(self, random::seed())
     }
 
     /// Generates `count` random values the are progressively larger in size.
     /// For additional sampling settings, see [`Sample::sampler`].
-    fn samples(&self, count: usize) -> Samples<Self> {
+    fn samples(&self, count: usize) -> Samples<'_, Self> {
+        let mut sampler = self.sampler();
+        sampler.count = count;
+        sampler.samples()
+    }
+
+    /// Generates `count` values like [`Sample::samples`], but the first random
+    /// primitive of each sample is spread over a low-discrepancy sequence rather
+    /// than drawn independently. Any prefix of the samples therefore covers the
+    /// value space evenly without the clumping of independent random draws,
+    /// which is useful to build documentation tables or golden files.
+    fn grid(&self, count: usize) -> Samples<'_, Self> {
         let mut sampler = self.sampler();
         sampler.count = count;
+        sampler.grid = true;
         sampler.samples()
     }
 
@@ -43,6 +109,21 @@ pub trait Sample: Generate {
     fn sample(&self, size: f64) -> Self::Item {
         self.sampler().sample(size)
     }
+
+    /// Writes `count` samples to numbered files in the directory at `path`
+    /// such that they can be used as fixtures by test suites that are not
+    /// written in Rust. See [`Sampler::write_to_dir`] for the layout of the
+    /// directory.
+    fn write_to_dir<P: AsRef<Path>, F: Format<Self::Item>>(
+        &self,
+        path: P,
+        count: usize,
+        format: F,
+    ) -> io::Result<()> {
+        let mut sampler = self.sampler();
+        sampler.count = count;
+        sampler.write_to_dir(path, format)
+    }
 }
 
 const COUNT: usize = 100;
@@ -56,6 +137,7 @@ impl<'a, G: ?Sized> Sampler<'a, G> {
             seed,
             size: 0.0..1.0,
             count: COUNT,
+            grid: false,
         }
     }
 }
@@ -67,6 +149,7 @@ impl<G: ?Sized> Clone for Sampler<'_, G> {
             seed: self.seed,
             size: self.size.clone(),
             count: self.count,
+            grid: self.grid,
         }
     }
 }
@@ -76,13 +159,63 @@ impl<'a, G: Generate + ?Sized> Sampler<'a, G> {
         shrinker(self.generator, size, Some(self.seed)).item()
     }
 
+    /// Writes the samples of this sampler to numbered files in the directory
+    /// at `path` (which is created if needed), along with a `manifest.json`
+    /// file that records the settings of the sampler. Sampling again with the
+    /// same settings reproduces the same items.
+    ///
+    /// ```
+    /// use checkito::{sample::format, *};
+    ///
+    /// let path = std::env::temp_dir().join("checkito-fixtures");
+    /// (0..100u8, bool::generator())
+    ///     .write_to_dir(&path, 3, format::Debug)
+    ///     .unwrap();
+    /// assert!(path.join("0.txt").exists() && path.join("manifest.json").exists());
+    /// ```
+    pub fn write_to_dir<P: AsRef<Path>, F: Format<G::Item>>(
+        &self,
+        path: P,
+        format: F,
+    ) -> io::Result<()> {
+        let path = path.as_ref();
+        fs::create_dir_all(path)?;
+        let width = self.count.saturating_sub(1).to_string().len();
+        let mut files = Vec::with_capacity(self.count);
+        for (index, item) in self.samples().enumerate() {
+            let name = format!("{index:0width$}.{}", format.extension());
+            let mut file = io::BufWriter::new(fs::File::create(path.join(&name))?);
+            format.write(&item, &mut file)?;
+            file.flush()?;
+            files.push(format!("\"{name}\""));
+        }
+        fs::write(
+            path.join("manifest.json"),
+            format!(
+                "{{\n  \"seed\": {},\n  \"size\": [{:?}, {:?}],\n  \"count\": {},\n  \"grid\": {},\n  \
+                 \"files\": [{}]\n}}\n",
+                self.seed,
+                self.size.start,
+                self.size.end,
+                self.count,
+                self.grid,
+                files.join(", ")
+            ),
+        )
+    }
+
     pub fn samples(&self) -> Samples<'a, G> {
-        Samples(Shrinkers::new(
+        let shrinkers = Shrinkers::new(
             self.generator,
             self.count,
             self.size.clone(),
             Some(self.seed),
-        ))
+        );
+        Samples(if self.grid {
+            shrinkers.grid()
+        } else {
+            shrinkers
+        })
     }
 }
 
@@ -0,0 +1,143 @@
+use crate::{
+    generate::{Generate, State},
+    shrink::Shrink,
+};
+use core::hash::{BuildHasher, Hash, Hasher};
+use std::{
+    collections::{HashMap, hash_map::RandomState},
+    sync::{Arc, Mutex, PoisonError, Weak},
+};
+
+/// A generator that wraps its items in an [`Arc`] and interns structurally
+/// equal items (see [`Generate::shared`]).
+///
+/// The interning table is shared by all clones of this [`Shared`] and by the
+/// shrinkers it produces. It only holds weak references to the interned items
+/// such that an item is freed as soon as it is no longer used, and the
+/// references to freed items are swept as the table grows.
+#[derive(Debug)]
+pub struct Shared<G: ?Sized, T> {
+    pub(crate) table: Arc<Mutex<Table<T>>>,
+    pub(crate) generator: G,
+}
+
+#[derive(Debug)]
+pub struct Shrinker<S, T> {
+    table: Arc<Mutex<Table<T>>>,
+    shrinker: S,
+}
+
+#[derive(Debug)]
+pub(crate) struct Table<T> {
+    hasher: RandomState,
+    items: HashMap<u64, Vec<Weak<T>>>,
+    count: usize,
+    sweep: usize,
+}
+
+impl<T: Hash + Eq> Table<T> {
+    const SWEEP: usize = 64;
+
+    pub(crate) fn new() -> Self {
+        Self {
+            hasher: RandomState::new(),
+            items: HashMap::new(),
+            count: 0,
+            sweep: Self::SWEEP,
+        }
+    }
+
+    fn intern(&mut self, item: T) -> Arc<T> {
+        let mut hasher = self.hasher.build_hasher();
+        item.hash(&mut hasher);
+        let bucket = self.items.entry(hasher.finish()).or_default();
+        for weak in bucket.iter() {
+            match weak.upgrade() {
+                Some(shared) if *shared == item => return shared,
+                _ => {}
+            }
+        }
+
+        let shared = Arc::new(item);
+        bucket.push(Arc::downgrade(&shared));
+        self.count += 1;
+        if self.count >= self.sweep {
+            // Sweep the references to freed items whenever the table doubles in
+            // size such that the cost of sweeping is amortized.
+            self.items.retain(|_, bucket| {
+                bucket.retain(|weak| weak.strong_count() > 0);
+                !bucket.is_empty()
+            });
+            self.count = self.items.values().map(Vec::len).sum();
+            self.sweep = (self.count * 2).max(Self::SWEEP);
+        }
+        shared
+    }
+}
+
+impl<G: Clone, T> Clone for Shared<G, T> {
+    fn clone(&self) -> Self {
+        Self {
+            table: self.table.clone(),
+            generator: self.generator.clone(),
+        }
+    }
+}
+
+impl<S: Clone, T> Clone for Shrinker<S, T> {
+    fn clone(&self) -> Self {
+        Self {
+            table: self.table.clone(),
+            shrinker: self.shrinker.clone(),
+        }
+    }
+}
+
+impl<G: Generate + ?Sized> Generate for Shared<G, G::Item>
+where
+    G::Item: Hash + Eq,
+{
+    type Item = Arc<G::Item>;
+    type Shrink = Shrinker<G::Shrink, G::Item>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        Shrinker {
+            table: self.table.clone(),
+            shrinker: self.generator.generate(state),
+        }
+    }
+
+    fn constant(&self) -> bool {
+        self.generator.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.generator.cardinality()
+    }
+}
+
+impl<S: Shrink> Shrink for Shrinker<S, S::Item>
+where
+    S::Item: Hash + Eq,
+{
+    type Item = Arc<S::Item>;
+
+    fn item(&self) -> Self::Item {
+        let item = self.shrinker.item();
+        self.table
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .intern(item)
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        Some(Self {
+            table: self.table.clone(),
+            shrinker: self.shrinker.shrink()?,
+        })
+    }
+
+    fn release(&mut self) -> bool {
+        self.shrinker.release()
+    }
+}
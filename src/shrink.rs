@@ -1,6 +1,7 @@
 use crate::{
     check::{self, Sizes},
     generate::{Generate, State, States},
+    map::Map,
     random,
 };
 use core::iter;
@@ -9,6 +10,17 @@ pub trait Shrink: Clone {
     type Item;
     fn item(&self) -> Self::Item;
     fn shrink(&mut self) -> Option<Self>;
+
+    /// Allows the components that were kept until last (see
+    /// [`crate::keep_until_last`]) to shrink and resets `self` such that
+    /// [`Shrink::shrink`] visits them. Returns `true` if any component was
+    /// released.
+    ///
+    /// Shrinkers that do not forward this call to their inner shrinkers keep
+    /// those components intact.
+    fn release(&mut self) -> bool {
+        false
+    }
 }
 
 #[derive(Debug)]
@@ -20,6 +32,52 @@ pub struct Shrinkers<'a, G: ?Sized> {
 #[derive(Debug, Clone)]
 pub struct Shrinker<T: ?Sized>(pub(crate) T);
 
+/// A shrinker that skips the candidates of a shrinker whose item is rejected
+/// by a filter (see [`filter`]).
+#[derive(Debug, Clone)]
+pub struct Filter<S, F> {
+    shrinker: S,
+    filter: F,
+}
+
+/// A shrinker that continues with another shrinker once a first one is
+/// exhausted (see [`and_then`]).
+#[derive(Debug, Clone)]
+pub struct AndThen<S, T, F> {
+    first: S,
+    second: Option<T>,
+    then: F,
+}
+
+/// Maps the items of a `shrinker` with `map`. Combined with [`filter`] and
+/// [`and_then`], it allows to build the [`Generate::Shrink`] of a manual
+/// [`Generate`] implementation from existing shrinkers.
+pub const fn map<S: Shrink, T, F: Fn(S::Item) -> T + Clone>(shrinker: S, map: F) -> Map<S, F> {
+    Map(map, shrinker)
+}
+
+/// Skips the shrinking candidates of a `shrinker` whose item is rejected by
+/// `filter`. The initial item is kept as is, even if it is rejected.
+pub const fn filter<S: Shrink, F: Fn(&S::Item) -> bool + Clone>(
+    shrinker: S,
+    filter: F,
+) -> Filter<S, F> {
+    Filter { shrinker, filter }
+}
+
+/// Shrinks with a `shrinker` until it is exhausted and then continues with the
+/// shrinker that `then` creates from the smallest item found so far.
+pub const fn and_then<S: Shrink, T: Shrink<Item = S::Item>, F: Fn(&S::Item) -> T + Clone>(
+    shrinker: S,
+    then: F,
+) -> AndThen<S, T, F> {
+    AndThen {
+        first: shrinker,
+        second: None,
+        then,
+    }
+}
+
 impl<G: Generate + ?Sized> Generate for Shrinker<G> {
     type Item = G::Shrink;
     type Shrink = Shrinker<G::Shrink>;
@@ -31,6 +89,10 @@ impl<G: Generate + ?Sized> Generate for Shrinker<G> {
     fn constant(&self) -> bool {
         self.0.constant()
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.0.cardinality()
+    }
 }
 
 impl<S: Shrink> Shrink for Shrinker<S> {
@@ -43,6 +105,65 @@ impl<S: Shrink> Shrink for Shrinker<S> {
     fn shrink(&mut self) -> Option<Self> {
         Some(Self(self.0.shrink()?))
     }
+
+    fn release(&mut self) -> bool {
+        self.0.release()
+    }
+}
+
+impl<S: Shrink, F: Fn(&S::Item) -> bool + Clone> Shrink for Filter<S, F> {
+    type Item = S::Item;
+
+    fn item(&self) -> Self::Item {
+        self.shrinker.item()
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        loop {
+            let shrinker = self.shrinker.shrink()?;
+            if (self.filter)(&shrinker.item()) {
+                break Some(Self {
+                    shrinker,
+                    filter: self.filter.clone(),
+                });
+            }
+        }
+    }
+
+    fn release(&mut self) -> bool {
+        self.shrinker.release()
+    }
+}
+
+impl<S: Shrink, T: Shrink<Item = S::Item>, F: Fn(&S::Item) -> T + Clone> Shrink
+    for AndThen<S, T, F>
+{
+    type Item = S::Item;
+
+    fn item(&self) -> Self::Item {
+        match &self.second {
+            Some(second) => second.item(),
+            None => self.first.item(),
+        }
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        if self.second.is_none() {
+            if let Some(first) = self.first.shrink() {
+                return Some(Self {
+                    first,
+                    second: None,
+                    then: self.then.clone(),
+                });
+            }
+            self.second = Some((self.then)(&self.first.item()));
+        }
+        Some(Self {
+            first: self.first.clone(),
+            second: Some(self.second.as_mut()?.shrink()?),
+            then: self.then.clone(),
+        })
+    }
 }
 
 impl<G: Generate + ?Sized> Clone for Shrinkers<'_, G> {
@@ -67,6 +188,13 @@ impl<'a, G: Generate + ?Sized> Shrinkers<'a, G> {
             states: States::new(count, size, seed),
         }
     }
+
+    pub(crate) fn grid(self) -> Self {
+        Shrinkers {
+            generator: self.generator,
+            states: self.states.grid(),
+        }
+    }
 }
 
 pub(crate) fn shrinker<G: Generate + ?Sized>(
@@ -22,4 +22,8 @@ impl<G: Generate, S: Into<Sizes>, F: Fn(Sizes) -> S> Generate for Size<G, F> {
     fn constant(&self) -> bool {
         self.0.constant()
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.0.cardinality()
+    }
 }
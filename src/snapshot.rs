@@ -0,0 +1,178 @@
+//! Integration with [`insta`] snapshots for minimal failing items.
+//!
+//! When a property wrapped with [`record`] fails, its failing item is
+//! serialized as JSON in a pending `.snap.new` file next to the test, in the
+//! `snapshots` directory where `insta` stores its snapshots. It can then be
+//! reviewed as a diff and accepted with `cargo insta review`. Once accepted, a
+//! [`Replay`] generator yields the snapshotted item as its first item such
+//! that the failure is checked again as a regression case.
+
+use crate::{
+    generate::{Generate, State},
+    pinned::Shrinker,
+    prove::Prove,
+    same::Same,
+};
+pub use insta;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread::panicking,
+};
+
+/// The location of the snapshot of a test, as resolved by `insta` for a named
+/// snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    source: String,
+    path: PathBuf,
+}
+
+/// A generator that yields the snapshotted item of a test (if any) before
+/// generating items with the wrapped generator.
+#[derive(Clone, Debug)]
+pub struct Replay<G: Generate + ?Sized> {
+    case: Option<G::Item>,
+    generator: G,
+}
+
+/// The result of a property wrapped with [`record`] that writes the item to a
+/// pending snapshot if it is disproved.
+#[derive(Debug)]
+pub struct Record<T: Serialize, P> {
+    proof: P,
+    guard: Guard<T>,
+}
+
+#[derive(Debug)]
+struct Guard<T: Serialize> {
+    location: Location,
+    item: Option<T>,
+}
+
+impl Location {
+    /// Resolves the snapshot `name` of a test in the source `file` of a crate
+    /// at `root`, where `module` is the module path of the test. This is
+    /// usually called with `env!("CARGO_MANIFEST_DIR")`, `file!()` and
+    /// `module_path!()`.
+    pub fn new(root: &str, file: &str, module: &str, name: &str) -> Self {
+        let directory = Path::new(file).parent().unwrap_or_else(|| Path::new(""));
+        let path = Path::new(root)
+            .join(directory)
+            .join("snapshots")
+            .join(format!("{}__{name}.snap", module.replace("::", "__")));
+        Self {
+            source: file.into(),
+            path,
+        }
+    }
+
+    /// The path of the accepted snapshot. The pending snapshot has the same
+    /// path with a `.snap.new` extension.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Loads the item of the accepted snapshot, if there is one and it can be
+    /// deserialized.
+    pub fn load<T: DeserializeOwned>(&self) -> Option<T> {
+        let snapshot = insta::Snapshot::from_file(&self.path).ok()?;
+        serde_json::from_str(&snapshot.as_text()?.to_string()).ok()
+    }
+
+    /// Writes `item` to the pending snapshot unless it is equal to the
+    /// accepted one.
+    pub fn save<T: Serialize>(&self, item: &T) {
+        let Ok(json) = serde_json::to_string_pretty(item) else {
+            return;
+        };
+        let pending = self.path.with_extension("snap.new");
+        let accepted = insta::Snapshot::from_file(&self.path)
+            .ok()
+            .and_then(|snapshot| Some(snapshot.as_text()?.to_string()));
+        if accepted.as_deref() == Some(json.as_str()) {
+            let _ = fs::remove_file(pending);
+            return;
+        }
+        if let Some(parent) = pending.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let header = format!("---\nsource: {}\nexpression: item\n---\n", self.source);
+        let _ = fs::write(pending, header + &json + "\n");
+    }
+}
+
+impl<G: Generate> Replay<G>
+where
+    G::Item: DeserializeOwned,
+{
+    /// Loads the snapshotted item at `location` when it is created.
+    pub fn new(location: &Location, generator: G) -> Self {
+        Self {
+            case: location.load(),
+            generator,
+        }
+    }
+}
+
+impl<G: Generate + ?Sized> Generate for Replay<G>
+where
+    G::Item: Clone,
+{
+    type Item = G::Item;
+    type Shrink = Shrinker<G::Item, G::Shrink>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        match (&self.case, state.index) {
+            (Some(case), 0) => Shrinker::Case(Same(case.clone())),
+            _ => Shrinker::Generator(self.generator.generate(state)),
+        }
+    }
+
+    fn constant(&self) -> bool {
+        self.case.is_none() && self.generator.constant()
+    }
+}
+
+/// Wraps a property such that its failing items (either disproved or
+/// panicking) are written to the pending snapshot at `location`. Since
+/// shrinking only fails on smaller and smaller items, the last written item is
+/// the minimal failing item.
+pub fn record<T: Serialize + Clone, P: Prove, F: Fn(T) -> P>(
+    location: Location,
+    check: F,
+) -> impl Fn(T) -> Record<T, P> {
+    move |item| {
+        // The guard writes the item if `check` panics.
+        let guard = Guard {
+            location: location.clone(),
+            item: Some(item.clone()),
+        };
+        let proof = check(item);
+        Record { proof, guard }
+    }
+}
+
+impl<T: Serialize, P: Prove> Prove for Record<T, P> {
+    type Error = P::Error;
+    type Proof = P::Proof;
+
+    fn prove(mut self) -> Result<Self::Proof, Self::Error> {
+        let result = self.proof.prove();
+        if let (Err(_), Some(item)) = (&result, self.guard.item.take()) {
+            self.guard.location.save(&item);
+        }
+        result
+    }
+}
+
+impl<T: Serialize> Drop for Guard<T> {
+    fn drop(&mut self) {
+        if panicking() {
+            if let Some(item) = self.item.take() {
+                self.location.save(&item);
+            }
+        }
+    }
+}
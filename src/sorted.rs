@@ -0,0 +1,60 @@
+use crate::{
+    generate::{Generate, State},
+    shrink::Shrink,
+};
+
+/// Sorts the items generated by a collection generator (see
+/// [`crate::sorted()`] and [`crate::strictly_increasing()`]).
+#[derive(Clone, Debug)]
+pub struct Sorted<G: ?Sized> {
+    pub(crate) strict: bool,
+    pub(crate) generator: G,
+}
+
+#[derive(Clone, Debug)]
+pub struct Shrinker<S> {
+    strict: bool,
+    shrinker: S,
+}
+
+impl<T: Ord, G: Generate<Item = Vec<T>> + ?Sized> Generate for Sorted<G> {
+    type Item = Vec<T>;
+    type Shrink = Shrinker<G::Shrink>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        Shrinker {
+            strict: self.strict,
+            shrinker: self.generator.generate(state),
+        }
+    }
+
+    fn constant(&self) -> bool {
+        self.generator.constant()
+    }
+}
+
+impl<T: Ord, S: Shrink<Item = Vec<T>>> Shrink for Shrinker<S> {
+    type Item = Vec<T>;
+
+    /// Since the order is restored on every item, any shrunk collection is
+    /// ordered as well.
+    fn item(&self) -> Self::Item {
+        let mut items = self.shrinker.item();
+        items.sort();
+        if self.strict {
+            items.dedup();
+        }
+        items
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        Some(Self {
+            strict: self.strict,
+            shrinker: self.shrinker.shrink()?,
+        })
+    }
+
+    fn release(&mut self) -> bool {
+        self.shrinker.release()
+    }
+}
@@ -1,10 +1,17 @@
 use crate::{
+    cardinality,
     convert::Convert,
     generate::{FullGenerate, Generate, State},
+    map::Map,
+    same::Same,
     shrink::Shrink,
 };
-use core::{marker::PhantomData, mem::take};
-use std::{rc::Rc, sync::Arc};
+use core::{
+    cell::{Cell, RefCell},
+    marker::PhantomData,
+    mem::take,
+};
+use std::{borrow::Cow, rc::Rc, sync::Arc};
 
 pub mod option {
     use super::*;
@@ -40,6 +47,10 @@ pub mod option {
         fn constant(&self) -> bool {
             false
         }
+
+        fn cardinality(&self) -> Option<u128> {
+            cardinality::sum(self.0.cardinality(), Some(1))
+        }
     }
 
     impl<G: Generate> Generate for Option<G> {
@@ -56,6 +67,10 @@ pub mod option {
         fn constant(&self) -> bool {
             self.as_ref().map_or(true, Generate::constant)
         }
+
+        fn cardinality(&self) -> Option<u128> {
+            self.as_ref().map_or(Some(1), Generate::cardinality)
+        }
     }
 
     impl<S: Shrink> Shrink for Shrinker<S> {
@@ -72,6 +87,10 @@ pub mod option {
                 Self(false, Some(self.1.as_mut()?.shrink()?))
             })
         }
+
+        fn release(&mut self) -> bool {
+            self.1.as_mut().map_or(false, Shrink::release)
+        }
     }
 }
 
@@ -107,6 +126,10 @@ pub mod result {
         fn constant(&self) -> bool {
             false
         }
+
+        fn cardinality(&self) -> Option<u128> {
+            cardinality::sum(self.0.cardinality(), self.1.cardinality())
+        }
     }
 
     impl<T: Generate, E: Generate> Generate for Result<T, E> {
@@ -126,6 +149,13 @@ pub mod result {
                 Err(generator) => generator.constant(),
             }
         }
+
+        fn cardinality(&self) -> Option<u128> {
+            match self {
+                Ok(generator) => generator.cardinality(),
+                Err(generator) => generator.cardinality(),
+            }
+        }
     }
 
     impl<T: Shrink, E: Shrink> Shrink for Shrinker<T, E> {
@@ -144,6 +174,13 @@ pub mod result {
                 Err(shrinker) => Err(shrinker.shrink()?),
             }))
         }
+
+        fn release(&mut self) -> bool {
+            match &mut self.0 {
+                Ok(shrinker) => shrinker.release(),
+                Err(shrinker) => shrinker.release(),
+            }
+        }
     }
 }
 
@@ -172,6 +209,14 @@ macro_rules! pointer {
                 fn constant(&self) -> bool {
                     G::constant(self)
                 }
+
+                fn cardinality(&self) -> Option<u128> {
+                    G::cardinality(self)
+                }
+
+                fn shrink_from(&self, item: Self::Item) -> Option<Self::Shrink> {
+                    G::shrink_from(self, item)
+                }
             }
         }
     };
@@ -180,3 +225,42 @@ macro_rules! pointer {
 pointer!(boxed, Box);
 pointer!(rc, Rc);
 pointer!(arc, Arc);
+
+macro_rules! cell {
+    ($t: ident) => {
+        impl<G: FullGenerate> FullGenerate for $t<G> {
+            type Generator = Convert<G::Generator, Self::Item>;
+            type Item = $t<G::Item>;
+
+            fn generator() -> Self::Generator {
+                Convert(PhantomData, G::generator())
+            }
+        }
+    };
+}
+
+cell!(Cell);
+cell!(RefCell);
+
+/// Generates owned values only since a borrowed `'static` value can not be
+/// produced from a generated item.
+impl<T: ToOwned + ?Sized + 'static> FullGenerate for Cow<'static, T>
+where
+    T::Owned: FullGenerate<Item = T::Owned>,
+{
+    type Generator = Map<<T::Owned as FullGenerate>::Generator, fn(T::Owned) -> Self::Item>;
+    type Item = Cow<'static, T>;
+
+    fn generator() -> Self::Generator {
+        Map(Cow::Owned, T::Owned::generator())
+    }
+}
+
+impl<T: ?Sized> FullGenerate for PhantomData<T> {
+    type Generator = Same<Self>;
+    type Item = Self;
+
+    fn generator() -> Self::Generator {
+        Same(PhantomData)
+    }
+}
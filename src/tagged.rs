@@ -0,0 +1,215 @@
+use crate::{
+    cardinality,
+    check::Sizes,
+    generate::{Generate, State},
+    shrink::Shrink,
+};
+use std::sync::Arc;
+
+/// The encoding of an integer field of a frame (see [`crate::tagged_union`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Width {
+    /// A single byte. Larger values are truncated.
+    U8,
+    /// Two big-endian bytes. Larger values are truncated.
+    U16,
+    /// Four big-endian bytes. Larger values are truncated.
+    U32,
+    /// An unsigned LEB128 variable length integer.
+    Varint,
+}
+
+/// A generated message of a tagged union along with its encoding.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Frame<T> {
+    pub tag: u64,
+    pub payload: T,
+    /// The encoded tag, followed by the encoded length of the payload (if
+    /// any), followed by the payload.
+    pub bytes: Vec<u8>,
+}
+
+/// Generates [`Frame`]s from a list of variants (see [`crate::tagged_union`]).
+#[derive(Debug)]
+pub struct TaggedUnion<G> {
+    pub(crate) variants: Arc<[(u64, G)]>,
+    pub(crate) tag: Width,
+    pub(crate) length: Option<Width>,
+}
+
+#[derive(Debug)]
+pub struct Shrinker<G: Generate> {
+    variants: Arc<[(u64, G)]>,
+    tag: Width,
+    length: Option<Width>,
+    seed: u64,
+    index: usize,
+    shrinker: G::Shrink,
+    /// The next simpler variant to switch to once the payload is fully shrunk.
+    next: usize,
+}
+
+impl Width {
+    /// Appends the encoding of `value` to `bytes`.
+    pub fn encode(self, value: u64, bytes: &mut Vec<u8>) {
+        match self {
+            Width::U8 => bytes.push(value as u8),
+            Width::U16 => bytes.extend((value as u16).to_be_bytes()),
+            Width::U32 => bytes.extend((value as u32).to_be_bytes()),
+            Width::Varint => {
+                let mut value = value;
+                while value >= 0x80 {
+                    bytes.push(value as u8 | 0x80);
+                    value >>= 7;
+                }
+                bytes.push(value as u8);
+            }
+        }
+    }
+
+    /// Decodes a value at the start of `bytes` and returns it along with the
+    /// number of bytes that it used, or `None` if `bytes` is too short.
+    pub fn decode(self, bytes: &[u8]) -> Option<(u64, usize)> {
+        match self {
+            Width::U8 => Some((*bytes.first()? as u64, 1)),
+            Width::U16 => Some((
+                u16::from_be_bytes(bytes.get(..2)?.try_into().ok()?) as u64,
+                2,
+            )),
+            Width::U32 => Some((
+                u32::from_be_bytes(bytes.get(..4)?.try_into().ok()?) as u64,
+                4,
+            )),
+            Width::Varint => {
+                let mut value = 0u64;
+                for (index, &byte) in bytes.iter().enumerate().take(10) {
+                    value |= ((byte & 0x7F) as u64) << (7 * index);
+                    if byte < 0x80 {
+                        return Some((value, index + 1));
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+impl<G> Clone for TaggedUnion<G> {
+    fn clone(&self) -> Self {
+        Self {
+            variants: self.variants.clone(),
+            tag: self.tag,
+            length: self.length,
+        }
+    }
+}
+
+impl<G: Generate> Shrinker<G> {
+    fn variant(&self, index: usize, shrinker: G::Shrink) -> Self {
+        Self {
+            variants: self.variants.clone(),
+            tag: self.tag,
+            length: self.length,
+            seed: self.seed,
+            index,
+            shrinker,
+            next: 0,
+        }
+    }
+}
+
+impl<G: Generate> Clone for Shrinker<G> {
+    fn clone(&self) -> Self {
+        Self {
+            variants: self.variants.clone(),
+            tag: self.tag,
+            length: self.length,
+            seed: self.seed,
+            index: self.index,
+            shrinker: self.shrinker.clone(),
+            next: self.next,
+        }
+    }
+}
+
+impl<G: Generate> Generate for TaggedUnion<G>
+where
+    G::Item: AsRef<[u8]>,
+{
+    type Item = Frame<G::Item>;
+    type Shrink = Shrinker<G>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        assert!(
+            !self.variants.is_empty(),
+            "tagged_union requires at least one variant"
+        );
+        let index = state.random().usize(..self.variants.len());
+        Shrinker {
+            variants: self.variants.clone(),
+            tag: self.tag,
+            length: self.length,
+            seed: state.seed(),
+            index,
+            shrinker: self.variants[index].1.generate(state),
+            next: 0,
+        }
+    }
+
+    fn constant(&self) -> bool {
+        match &*self.variants {
+            [(_, generator)] => generator.constant(),
+            _ => false,
+        }
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.variants.iter().try_fold(0, |sum, (_, generator)| {
+            cardinality::sum(Some(sum), generator.cardinality())
+        })
+    }
+}
+
+impl<G: Generate> Shrink for Shrinker<G>
+where
+    G::Item: AsRef<[u8]>,
+{
+    type Item = Frame<G::Item>;
+
+    fn item(&self) -> Self::Item {
+        let tag = self.variants[self.index].0;
+        let payload = self.shrinker.item();
+        let mut bytes = Vec::new();
+        self.tag.encode(tag, &mut bytes);
+        if let Some(length) = self.length {
+            length.encode(payload.as_ref().len() as u64, &mut bytes);
+        }
+        bytes.extend_from_slice(payload.as_ref());
+        Frame {
+            tag,
+            payload,
+            bytes,
+        }
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        // Stay within the selected variant until its payload is fully shrunk.
+        if let Some(shrinker) = self.shrinker.shrink() {
+            return Some(self.variant(self.index, shrinker));
+        }
+
+        // Then switch to the smallest payload of a variant listed before it.
+        if self.next < self.index {
+            let index = self.next;
+            self.next += 1;
+            let mut state = State::new(0, 1, Sizes::from(0.0), self.seed);
+            let shrinker = self.variants[index].1.generate(&mut state);
+            return Some(self.variant(index, shrinker));
+        }
+        None
+    }
+
+    fn release(&mut self) -> bool {
+        self.shrinker.release()
+    }
+}
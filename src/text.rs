@@ -0,0 +1,299 @@
+use crate::{
+    collect::{self, Collect, Machine},
+    generate::{FullGenerate, Generate, State},
+    primitive::{self, Full},
+    shrink::Shrink,
+};
+use core::ops::{Range, RangeInclusive};
+
+/// Generates a [`String`] with a shrinker that is specialized for text.
+///
+/// Before falling back to the shrinking of a regular collection, the shrinker
+/// tries to remove whole lines, then whitespace-delimited words. Before
+/// shrinking characters individually, it tries to simplify them to `'a'`.
+#[derive(Clone, Debug)]
+pub struct Text<C>(pub(crate) Collect<Full<char>, C, String>);
+
+#[derive(Clone, Debug)]
+pub struct Shrinker {
+    inner: collect::Shrinker<primitive::char::Shrinker, String>,
+    phase: Phase,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Phase {
+    Lines(usize),
+    Words(usize),
+    Collect,
+    Simplify(usize),
+    Characters,
+}
+
+impl FullGenerate for String {
+    type Generator = Text<RangeInclusive<usize>>;
+    type Item = String;
+
+    fn generator() -> Self::Generator {
+        Text(Collect::new(char::generator()))
+    }
+}
+
+impl<C: Generate<Item = usize>> Generate for Text<C> {
+    type Item = String;
+    type Shrink = Shrinker;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        Shrinker {
+            inner: self.0.generate(state),
+            phase: Phase::Lines(0),
+        }
+    }
+
+    fn constant(&self) -> bool {
+        self.0.constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.0.cardinality()
+    }
+}
+
+/// Splits `items` in segments that each end with a run of separators.
+fn segments(items: &[char], separator: fn(&char) -> bool) -> Vec<Range<usize>> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    for index in 1..items.len() {
+        if separator(&items[index - 1]) && !separator(&items[index]) {
+            segments.push(start..index);
+            start = index;
+        }
+    }
+    if start < items.len() {
+        segments.push(start..items.len());
+    }
+    segments
+}
+
+impl Shrinker {
+    fn items(&self) -> Vec<char> {
+        self.inner.shrinkers.iter().map(Shrink::item).collect()
+    }
+
+    /// Removes the segment at `index` if there is more than one segment.
+    fn remove(&self, index: usize, separator: fn(&char) -> bool) -> Option<Self> {
+        let segments = segments(&self.items(), separator);
+        if segments.len() < 2 {
+            return None;
+        }
+        let segment = segments.get(index)?.clone();
+        let mut shrinkers = self.inner.shrinkers.clone();
+        shrinkers.drain(segment);
+        if shrinkers.len() < self.inner.minimum {
+            return None;
+        }
+        Some(Self {
+            inner: collect::Shrinker::new(shrinkers, Some(self.inner.minimum)),
+            phase: self.phase,
+        })
+    }
+}
+
+impl Shrink for Shrinker {
+    type Item = String;
+
+    fn item(&self) -> Self::Item {
+        self.inner.item()
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        loop {
+            match self.phase {
+                // Try to remove whole lines.
+                Phase::Lines(index) => {
+                    let segments = segments(&self.items(), |item| *item == '\n').len();
+                    if index < segments {
+                        self.phase = Phase::Lines(index + 1);
+                        if let Some(shrinker) = self.remove(index, |item| *item == '\n') {
+                            break Some(Self {
+                                phase: Phase::Lines(index),
+                                ..shrinker
+                            });
+                        }
+                    } else {
+                        self.phase = Phase::Words(0);
+                    }
+                }
+                // Try to remove whitespace-delimited words.
+                Phase::Words(index) => {
+                    let segments = segments(&self.items(), |item| item.is_whitespace()).len();
+                    if index < segments {
+                        self.phase = Phase::Words(index + 1);
+                        if let Some(shrinker) = self.remove(index, |item| item.is_whitespace()) {
+                            break Some(Self {
+                                phase: Phase::Words(index),
+                                ..shrinker
+                            });
+                        }
+                    } else {
+                        self.phase = Phase::Collect;
+                    }
+                }
+                // Truncate and remove characters as a regular collection.
+                Phase::Collect => {
+                    if let Machine::Shrink(_) | Machine::Done = self.inner.machine {
+                        self.phase = Phase::Simplify(0);
+                    } else {
+                        break Some(Self {
+                            inner: self.inner.shrink()?,
+                            phase: Phase::Collect,
+                        });
+                    }
+                }
+                // Try to simplify each character to `'a'`.
+                Phase::Simplify(index) => match self.inner.shrinkers.get(index) {
+                    Some(shrinker) => {
+                        self.phase = Phase::Simplify(index + 1);
+                        if shrinker.item() != 'a' {
+                            let mut inner = self.inner.clone();
+                            inner.shrinkers[index] = primitive::char::fixed('a');
+                            break Some(Self {
+                                inner,
+                                phase: Phase::Simplify(index + 1),
+                            });
+                        }
+                    }
+                    None => self.phase = Phase::Characters,
+                },
+                // Shrink each character individually.
+                Phase::Characters => {
+                    break Some(Self {
+                        inner: self.inner.shrink()?,
+                        phase: Phase::Characters,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// The words of the classic "lorem ipsum" placeholder text, without
+/// duplicates and in order of appearance (see [`crate::lorem()`]).
+pub(crate) const LOREM: [&str; 63] = [
+    "lorem",
+    "ipsum",
+    "dolor",
+    "sit",
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit",
+    "sed",
+    "do",
+    "eiusmod",
+    "tempor",
+    "incididunt",
+    "ut",
+    "labore",
+    "et",
+    "dolore",
+    "magna",
+    "aliqua",
+    "enim",
+    "ad",
+    "minim",
+    "veniam",
+    "quis",
+    "nostrud",
+    "exercitation",
+    "ullamco",
+    "laboris",
+    "nisi",
+    "aliquip",
+    "ex",
+    "ea",
+    "commodo",
+    "consequat",
+    "duis",
+    "aute",
+    "irure",
+    "in",
+    "reprehenderit",
+    "voluptate",
+    "velit",
+    "esse",
+    "cillum",
+    "eu",
+    "fugiat",
+    "nulla",
+    "pariatur",
+    "excepteur",
+    "sint",
+    "occaecat",
+    "cupidatat",
+    "non",
+    "proident",
+    "sunt",
+    "culpa",
+    "qui",
+    "officia",
+    "deserunt",
+    "mollit",
+    "anim",
+    "id",
+    "est",
+    "laborum",
+];
+
+/// Unicode scripts from which the letters of [`crate::prelude::word`]s can be
+/// generated.
+///
+/// Every script generates its letters from a contiguous range and shrinks
+/// toward the first letter of that range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Script {
+    /// Lowercase latin letters from `a` to `z`.
+    Latin,
+    /// Lowercase greek letters from `α` to `ω`.
+    Greek,
+    /// Lowercase cyrillic letters from `а` to `я`.
+    Cyrillic,
+    /// Hiragana syllables from `ぁ` to `ゖ`.
+    Hiragana,
+    /// Hangul syllables from `가` to `힣`.
+    Hangul,
+    /// Unified CJK ideographs from `一` to `龥`, which were all assigned in
+    /// the first version of the block (unlike the later additions at its end
+    /// that fonts may not cover).
+    Han,
+}
+
+impl Script {
+    /// The contiguous range of letters of this script.
+    pub const fn letters(self) -> RangeInclusive<char> {
+        match self {
+            Script::Latin => 'a'..='z',
+            Script::Greek => 'α'..='ω',
+            Script::Cyrillic => 'а'..='я',
+            Script::Hiragana => 'ぁ'..='ゖ',
+            Script::Hangul => '가'..='힣',
+            Script::Han => '一'..='龥',
+        }
+    }
+}
+
+impl Generate for Script {
+    type Item = char;
+    type Shrink = primitive::char::Shrinker;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        self.letters().generate(state)
+    }
+
+    fn constant(&self) -> bool {
+        self.letters().constant()
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.letters().cardinality()
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    COLLECT, collect,
+    generate::{Generate, State},
+    shrink::Shrink,
+};
+use core::mem::replace;
+
+/// Generates sequences of states where each state is generated by one of the
+/// valid successors of the previous state (see [`crate::transitions()`]).
+#[derive(Clone, Debug)]
+pub struct Transitions<S, F> {
+    pub(crate) initial: S,
+    pub(crate) successors: F,
+}
+
+#[derive(Clone, Debug)]
+pub struct Shrinker<S, F, T> {
+    initial: S,
+    successors: F,
+    steps: Vec<Step<T>>,
+    machine: Machine,
+}
+
+#[derive(Clone, Debug)]
+struct Step<T> {
+    /// The state from which the step was generated, such that it can be
+    /// generated again from a different previous state.
+    state: State,
+    shrinker: T,
+}
+
+#[derive(Clone, Debug)]
+enum Machine {
+    Remove(usize),
+    Shrink(usize),
+    Done,
+}
+
+/// Generates a step from one of the successors of `previous`, if any.
+fn step<S, G: Generate<Item = S>, F: Fn(&S) -> Vec<G>>(
+    successors: &F,
+    previous: &S,
+    state: &mut State,
+) -> Option<Step<G::Shrink>> {
+    let old = state.clone();
+    let successors = successors(previous);
+    if successors.is_empty() {
+        None
+    } else {
+        let index = state.random().usize(..successors.len());
+        Some(Step {
+            shrinker: successors[index].generate(state),
+            state: old,
+        })
+    }
+}
+
+impl<S: Clone, G: Generate<Item = S>, F: Fn(&S) -> Vec<G> + Clone> Generate for Transitions<S, F> {
+    type Item = Vec<S>;
+    type Shrink = Shrinker<S, F, G::Shrink>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        let count = collect::length(&(0..=COLLECT), state);
+        let mut steps = Vec::<Step<G::Shrink>>::with_capacity(count);
+        let mut previous = self.initial.clone();
+        for _ in 0..count {
+            // A state without successors is terminal.
+            let Some(step) = step(&self.successors, &previous, state) else {
+                break;
+            };
+            previous = step.shrinker.item();
+            steps.push(step);
+        }
+        Shrinker {
+            initial: self.initial.clone(),
+            successors: self.successors.clone(),
+            steps,
+            machine: Machine::Remove(0),
+        }
+    }
+}
+
+impl<S: Clone, G: Generate<Item = S>, F: Fn(&S) -> Vec<G> + Clone> Shrinker<S, F, G::Shrink> {
+    /// Generates the `rest` of the steps again after the `steps` such that
+    /// every step is a valid successor of the one before it.
+    fn replay(&self, mut steps: Vec<Step<G::Shrink>>, rest: &[Step<G::Shrink>]) -> Self {
+        let mut previous = steps
+            .last()
+            .map_or_else(|| self.initial.clone(), |step| step.shrinker.item());
+        for old in rest {
+            let Some(step) = step(&self.successors, &previous, &mut old.state.clone()) else {
+                break;
+            };
+            previous = step.shrinker.item();
+            steps.push(step);
+        }
+        Self {
+            initial: self.initial.clone(),
+            successors: self.successors.clone(),
+            steps,
+            machine: self.machine.clone(),
+        }
+    }
+}
+
+impl<S: Clone, G: Generate<Item = S>, F: Fn(&S) -> Vec<G> + Clone> Shrink
+    for Shrinker<S, F, G::Shrink>
+{
+    type Item = Vec<S>;
+
+    /// The initial state is the first item of the sequence.
+    fn item(&self) -> Self::Item {
+        let mut items = Vec::with_capacity(self.steps.len() + 1);
+        items.push(self.initial.clone());
+        items.extend(self.steps.iter().map(|step| step.shrinker.item()));
+        items
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        loop {
+            match replace(&mut self.machine, Machine::Done) {
+                // Removing a step generates the following steps again from its predecessor.
+                Machine::Remove(index) if index < self.steps.len() => {
+                    self.machine = Machine::Remove(index + 1);
+                    let mut shrinker =
+                        self.replay(self.steps[..index].to_vec(), &self.steps[index + 1..]);
+                    shrinker.machine = Machine::Remove(index);
+                    break Some(shrinker);
+                }
+                Machine::Remove(_) => self.machine = Machine::Shrink(0),
+                Machine::Shrink(index) if index < self.steps.len() => {
+                    match self.steps[index].shrinker.shrink() {
+                        Some(new) => {
+                            self.machine = Machine::Shrink(index);
+                            let mut steps = self.steps[..index].to_vec();
+                            steps.push(Step {
+                                state: self.steps[index].state.clone(),
+                                shrinker: new,
+                            });
+                            let mut shrinker = self.replay(steps, &self.steps[index + 1..]);
+                            shrinker.machine = Machine::Shrink(index);
+                            break Some(shrinker);
+                        }
+                        None => self.machine = Machine::Shrink(index + 1),
+                    }
+                }
+                Machine::Shrink(_) | Machine::Done => break None,
+            }
+        }
+    }
+}
@@ -28,6 +28,10 @@ where
     fn constant(&self) -> bool {
         self.1.constant()
     }
+
+    fn cardinality(&self) -> Option<u128> {
+        self.1.cardinality()
+    }
 }
 
 macro_rules! tuple {
@@ -43,6 +47,10 @@ macro_rules! tuple {
             fn shrink(&mut self) -> Option<Self> {
                 Some(Unify(PhantomData, self.1.shrink()?))
             }
+
+            fn release(&mut self) -> bool {
+                self.1.release()
+            }
         }
     }
 }
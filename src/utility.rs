@@ -56,3 +56,111 @@ macro_rules! tuples {
     };
 }
 pub(crate) use tuples;
+
+/// Tuples of arities 17 to 32 for the implementations that do not depend on
+/// `orn` types (which stop at `Or16`).
+#[cfg(feature = "big-tuples")]
+macro_rules! big_tuples {
+    ($m:ident) => {
+        $m!(
+            or17, 17, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16
+        );
+        $m!(
+            or18, 18, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17
+        );
+        $m!(
+            or19, 19, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17, p18, T18, 18
+        );
+        $m!(
+            or20, 20, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17, p18, T18, 18, p19, T19, 19
+        );
+        $m!(
+            or21, 21, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17, p18, T18, 18, p19, T19, 19,
+            p20, T20, 20
+        );
+        $m!(
+            or22, 22, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17, p18, T18, 18, p19, T19, 19,
+            p20, T20, 20, p21, T21, 21
+        );
+        $m!(
+            or23, 23, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17, p18, T18, 18, p19, T19, 19,
+            p20, T20, 20, p21, T21, 21, p22, T22, 22
+        );
+        $m!(
+            or24, 24, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17, p18, T18, 18, p19, T19, 19,
+            p20, T20, 20, p21, T21, 21, p22, T22, 22, p23, T23, 23
+        );
+        $m!(
+            or25, 25, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17, p18, T18, 18, p19, T19, 19,
+            p20, T20, 20, p21, T21, 21, p22, T22, 22, p23, T23, 23, p24, T24, 24
+        );
+        $m!(
+            or26, 26, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17, p18, T18, 18, p19, T19, 19,
+            p20, T20, 20, p21, T21, 21, p22, T22, 22, p23, T23, 23, p24, T24, 24, p25, T25, 25
+        );
+        $m!(
+            or27, 27, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17, p18, T18, 18, p19, T19, 19,
+            p20, T20, 20, p21, T21, 21, p22, T22, 22, p23, T23, 23, p24, T24, 24, p25, T25, 25,
+            p26, T26, 26
+        );
+        $m!(
+            or28, 28, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17, p18, T18, 18, p19, T19, 19,
+            p20, T20, 20, p21, T21, 21, p22, T22, 22, p23, T23, 23, p24, T24, 24, p25, T25, 25,
+            p26, T26, 26, p27, T27, 27
+        );
+        $m!(
+            or29, 29, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17, p18, T18, 18, p19, T19, 19,
+            p20, T20, 20, p21, T21, 21, p22, T22, 22, p23, T23, 23, p24, T24, 24, p25, T25, 25,
+            p26, T26, 26, p27, T27, 27, p28, T28, 28
+        );
+        $m!(
+            or30, 30, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17, p18, T18, 18, p19, T19, 19,
+            p20, T20, 20, p21, T21, 21, p22, T22, 22, p23, T23, 23, p24, T24, 24, p25, T25, 25,
+            p26, T26, 26, p27, T27, 27, p28, T28, 28, p29, T29, 29
+        );
+        $m!(
+            or31, 31, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17, p18, T18, 18, p19, T19, 19,
+            p20, T20, 20, p21, T21, 21, p22, T22, 22, p23, T23, 23, p24, T24, 24, p25, T25, 25,
+            p26, T26, 26, p27, T27, 27, p28, T28, 28, p29, T29, 29, p30, T30, 30
+        );
+        $m!(
+            or32, 32, p0, T0, 0, p1, T1, 1, p2, T2, 2, p3, T3, 3, p4, T4, 4, p5, T5, 5, p6, T6, 6,
+            p7, T7, 7, p8, T8, 8, p9, T9, 9, p10, T10, 10, p11, T11, 11, p12, T12, 12, p13, T13,
+            13, p14, T14, 14, p15, T15, 15, p16, T16, 16, p17, T17, 17, p18, T18, 18, p19, T19, 19,
+            p20, T20, 20, p21, T21, 21, p22, T22, 22, p23, T23, 23, p24, T24, 24, p25, T25, 25,
+            p26, T26, 26, p27, T27, 27, p28, T28, 28, p29, T29, 29, p30, T30, 30, p31, T31, 31
+        );
+    };
+}
+#[cfg(feature = "big-tuples")]
+pub(crate) use big_tuples;
@@ -0,0 +1,107 @@
+use crate::{
+    generate::{Generate, State},
+    shrink::Shrink,
+};
+
+/// Generates items with a function that has access to the whole [`State`]
+/// (see [`crate::with_state()`]).
+#[derive(Clone, Debug)]
+pub struct WithState<F, S> {
+    pub(crate) generate: F,
+    pub(crate) shrink: S,
+}
+
+/// Shrinks the items of a [`WithState`] generator by trying the candidates
+/// returned by its shrink function in order.
+#[derive(Clone, Debug)]
+pub struct Shrinker<T, S> {
+    item: T,
+    shrink: S,
+    /// The remaining candidates, in reverse order, once they were computed.
+    candidates: Option<Vec<T>>,
+}
+
+impl<F, S> WithState<F, S> {
+    /// Replaces the shrink function of this generator with `shrink`, which
+    /// returns the simpler candidates of an item, from the most to the least
+    /// preferred. Candidates are shrunk again with `shrink` when they are
+    /// accepted, such that `shrink` must eventually return no candidates.
+    ///
+    /// ```
+    /// use checkito::*;
+    ///
+    /// let generator = with_state(|state| state.draw_u64(..1000))
+    ///     .shrink_with(|&item: &u64| (item > 0).then(|| item / 2));
+    /// let fail = generator.check(|item| item < 10).unwrap();
+    /// assert!((10..20).contains(&fail.item));
+    /// ```
+    pub fn shrink_with<T, I: IntoIterator<Item = T>, R: Fn(&T) -> I + Clone>(
+        self,
+        shrink: R,
+    ) -> WithState<F, R> {
+        WithState {
+            generate: self.generate,
+            shrink,
+        }
+    }
+}
+
+impl<T: Clone, F: Fn(&mut State) -> T, I: IntoIterator<Item = T>, S: Fn(&T) -> I + Clone> Generate
+    for WithState<F, S>
+{
+    type Item = T;
+    type Shrink = Shrinker<T, S>;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        Shrinker {
+            item: (self.generate)(state),
+            shrink: self.shrink.clone(),
+            candidates: None,
+        }
+    }
+}
+
+impl<T: Clone, I: IntoIterator<Item = T>, S: Fn(&T) -> I + Clone> Shrink for Shrinker<T, S> {
+    type Item = T;
+
+    fn item(&self) -> Self::Item {
+        self.item.clone()
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        let candidates = self.candidates.get_or_insert_with(|| {
+            let mut candidates = (self.shrink)(&self.item).into_iter().collect::<Vec<_>>();
+            candidates.reverse();
+            candidates
+        });
+        Some(Self {
+            item: candidates.pop()?,
+            shrink: self.shrink.clone(),
+            candidates: None,
+        })
+    }
+}
+
+// A blanket implementation over every `Fn(&mut State) -> T` closure would conflict with
+// the implementations for references, so only function pointers and closure trait
+// objects generate directly. Their items do not shrink.
+macro_rules! function {
+    ($t:ty) => {
+        impl<'a, T: Clone> Generate for $t {
+            type Item = T;
+            type Shrink = Shrinker<T, fn(&T) -> Option<T>>;
+
+            fn generate(&self, state: &mut State) -> Self::Shrink {
+                Shrinker {
+                    item: self(state),
+                    shrink: |_| None,
+                    candidates: None,
+                }
+            }
+        }
+    };
+}
+
+function!(fn(&mut State) -> T);
+function!(dyn Fn(&mut State) -> T + 'a);
+function!(dyn Fn(&mut State) -> T + Send + Sync + 'a);
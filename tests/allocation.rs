@@ -0,0 +1,48 @@
+#![cfg(feature = "allocation")]
+
+pub mod common;
+use checkito::allocation::Tracker;
+use common::*;
+
+#[global_allocator]
+static ALLOCATOR: Tracker = Tracker::new(1 << 20);
+
+#[test]
+fn exceeding_the_budget_fails_and_shrinks() {
+    let fail = (0..(1usize << 24))
+        .check(|length| vec![0u8; length].len() == length)
+        .unwrap();
+    assert_eq!(
+        fail.cause,
+        Cause::ResourceExhausted {
+            allocated: fail.item,
+            budget: 1 << 20
+        }
+    );
+    assert_eq!(fail.item, (1 << 20) + 1);
+}
+
+#[test]
+fn freed_memory_does_not_count_toward_the_budget() {
+    assert!(
+        (0..64usize)
+            .check(|_| {
+                for _ in 0..16 {
+                    drop(vec![0u8; 1 << 19]);
+                }
+            })
+            .is_none()
+    );
+}
+
+#[test]
+fn nested_checks_are_tracked_separately() {
+    let fail = (0..(1usize << 24))
+        .check(|length| {
+            let outer = vec![0u8; 1 << 19];
+            let inner = (0..10u8).check(|_| !vec![0u8; 1 << 19].is_empty());
+            inner.is_none() && vec![0u8; length].len() + outer.len() >= length
+        })
+        .unwrap();
+    assert_eq!(fail.item, (1 << 19) + 1);
+}
@@ -1,5 +1,5 @@
 pub mod common;
-use checkito::any::Weight;
+use checkito::any::{InvalidWeight, Weight};
 use common::*;
 
 #[test]
@@ -18,3 +18,468 @@ fn weighted_any() {
     assert!(one < ten);
     assert!(ten < hundred);
 }
+
+#[test]
+fn weighted_boxed_any_with_runtime_weights() {
+    let configuration = [("small", 1.0), ("large", 1000.0)];
+    let mut generators = configuration
+        .iter()
+        .map(|&(name, weight)| match name {
+            "small" => Weight::new(weight, (0..10).boxed()),
+            _ => Weight::new(weight, (1000..2000).boxed()),
+        })
+        .collect::<Vec<_>>();
+    let large = any(&generators)
+        .samples(1000)
+        .flatten()
+        .filter(|&value| value >= 1000)
+        .count();
+    assert!(large > 900);
+
+    for generator in generators.iter_mut() {
+        let weight = 1001.0 - generator.weight();
+        generator.reweigh(weight);
+    }
+    // The cardinality does not depend on the weights.
+    assert_eq!(generators.cardinality(), Some(1010));
+    assert_eq!(any(&generators).cardinality(), Some(1010));
+    let small = generators
+        .any()
+        .samples(1000)
+        .flatten()
+        .filter(|&value| value < 10)
+        .count();
+    assert!(small > 900);
+}
+
+#[test]
+fn try_new_rejects_invalid_weights() {
+    for weight in [0.0, -1.0, f64::EPSILON / 2.0, f64::NAN, f64::INFINITY] {
+        let error = Weight::try_new(weight, 0..10).unwrap_err();
+        assert!(error.0 == weight || weight.is_nan());
+    }
+    assert_eq!(Weight::try_new(2.5, 0..10).unwrap().weight(), 2.5);
+    assert_eq!(
+        Weight::try_new(-1.0, 0..10).err(),
+        Some(InvalidWeight(-1.0))
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid weight")]
+fn new_panics_on_invalid_weight() {
+    Weight::new(0.0, 0..10);
+}
+
+#[test]
+fn any_reweighs_from_its_generators() {
+    let mut generator = any(vec![
+        Weight::new(1000.0, 0..10),
+        Weight::new(1.0, 1000..2000),
+    ]);
+    let large = |generator: &any::Any<Vec<Weight<_>>>| {
+        generator
+            .samples(1000)
+            .flatten()
+            .filter(|&value| value >= 1000)
+            .count()
+    };
+    assert!(large(&generator) < 100);
+    generator.reweigh(|range: &core::ops::Range<i32>| range.start as f64 + 1.0);
+    assert!(large(&generator) > 900);
+}
+
+#[test]
+fn try_reweigh_leaves_the_weights_on_invalid_weight() {
+    let mut weight = Weight::new(2.0, 0..10);
+    assert_eq!(
+        weight.try_reweigh(f64::INFINITY),
+        Err(InvalidWeight(f64::INFINITY))
+    );
+    assert_eq!(weight.weight(), 2.0);
+
+    let samples = |generator: &any::Any<Vec<Weight<core::ops::Range<i32>>>>| {
+        let mut sampler = generator.sampler();
+        sampler.seed = 7;
+        sampler.samples().collect::<Vec<_>>()
+    };
+    let mut generator = any(vec![Weight::new(1.0, 0..10), Weight::new(1.0, 10..20)]);
+    let error = generator.try_reweigh(|range| 10.0 - range.start as f64);
+    assert_eq!(error, Err(InvalidWeight(0.0)));
+    let unchanged = any(vec![Weight::new(1.0, 0..10), Weight::new(1.0, 10..20)]);
+    assert_eq!(samples(&generator), samples(&unchanged));
+    assert_eq!(
+        generator.try_reweigh(|range| range.start as f64 + 1.0),
+        Ok(())
+    );
+    let reweighed = any(vec![Weight::new(1.0, 0..10), Weight::new(11.0, 10..20)]);
+    assert_eq!(samples(&generator), samples(&reweighed));
+}
+
+#[test]
+#[should_panic(expected = "invalid weight")]
+fn reweigh_panics_on_invalid_weight() {
+    let mut generator = any([Weight::new(1.0, 0..10)]);
+    generator.reweigh(|_| f64::NAN);
+}
+
+#[test]
+fn stratified_weighted_choice_is_proportional_to_weights() {
+    let generator = (Weight::new(3.0, 0..10u8), Weight::new(1.0, 10..20u8)).unify::<u8>();
+    let mut checker = generator.checker();
+    checker.generate.count = 100;
+    checker.generate.mode = check::Modes::Stratified;
+    let items = checker
+        .checks(|_| true)
+        .map(|result| *result.item())
+        .collect::<Vec<_>>();
+    assert_eq!(items.iter().filter(|&&item| item < 10).count(), 75);
+}
+
+#[test]
+fn concat_is_proportional_to_cardinalities() {
+    let generator = (0..10u8).concat(10..=39u8);
+    assert_eq!(generator.cardinality(), Some(40));
+    let small = generator
+        .samples(10_000)
+        .filter(|&value| value < 10)
+        .count();
+    assert!((2_000..3_000).contains(&small), "{small}");
+    assert!(generator.samples(1000).all(|value| value < 40));
+}
+
+#[test]
+fn concat_shrinks_within_the_chosen_generator() {
+    let fail = concat(0..10u8, 100..200u8)
+        .check(|value| value < 150)
+        .unwrap();
+    assert_eq!(fail.item, 150);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Color {
+    Red = 1,
+    Green = 2,
+    Blue = 4,
+}
+
+impl Color {
+    const fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Color::Red),
+            2 => Some(Color::Green),
+            4 => Some(Color::Blue),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn enum_of_chooses_listed_values_and_shrinks_toward_the_first() {
+    let generator = enum_of(&[Color::Blue, Color::Red, Color::Green]);
+    assert_eq!(generator.cardinality(), Some(3));
+    let colors = generator.samples(100).collect::<Vec<_>>();
+    assert!(colors.contains(&Color::Red) && colors.contains(&Color::Blue));
+    let fail = generator.check(|color| color == Color::Green).unwrap();
+    assert_eq!(fail.item, Color::Blue);
+}
+
+#[test]
+fn enum_range_skips_invalid_discriminants() {
+    let generator = enum_range(0..=u8::MAX, Color::from_u8);
+    assert_eq!(generator.cardinality(), Some(3));
+    assert!(generator.samples(100).any(|color| color == Color::Blue));
+    let fail = generator.check(|color| color == Color::Blue).unwrap();
+    assert_eq!(fail.item, Color::Red);
+}
+
+mod registry {
+    use super::*;
+    use checkito::registry::Registry;
+
+    trait Shape {
+        fn area(&self) -> u32;
+    }
+
+    struct Square(u32);
+    struct Rectangle(u32, u32);
+
+    impl Shape for Square {
+        fn area(&self) -> u32 {
+            self.0 * self.0
+        }
+    }
+
+    impl Shape for Rectangle {
+        fn area(&self) -> u32 {
+            self.0 * self.1
+        }
+    }
+
+    fn shapes() -> Registry<dyn Shape> {
+        Registry::new()
+            .register(
+                "square",
+                1.0,
+                Generate::map(0..10u32, |side| Box::new(Square(side)) as Box<dyn Shape>),
+            )
+            .register(
+                "rectangle",
+                1.0,
+                (0..10u32, 0..5u32)
+                    .map(|(width, height)| Box::new(Rectangle(width, height)) as Box<dyn Shape>),
+            )
+    }
+
+    #[test]
+    fn cardinality_is_the_sum_of_constructors() {
+        assert_eq!(shapes().cardinality(), Some(60));
+        assert_eq!(Registry::<dyn Shape>::new().cardinality(), Some(0));
+    }
+
+    #[test]
+    fn generates_every_constructor() {
+        let names = shapes()
+            .samples(100)
+            .map(|shape| shape.name)
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(names.len(), 2);
+    }
+
+    #[test]
+    fn failures_are_labeled_with_their_constructor() {
+        let fail = shapes().check(|shape| shape.area() < 50).unwrap();
+        assert_eq!(fail.item.name, "square");
+        assert_eq!(fail.item.area(), 64);
+        assert_eq!(format!("{:?}", fail.item), "Named(\"square\")");
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_registry_panics() {
+        Registry::<dyn Shape>::new().sample(1.0);
+    }
+}
+
+mod adaptive {
+    use super::*;
+    use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
+    fn novelty() -> impl Fn(&u16) -> f64 + Clone {
+        let seen = Rc::new(RefCell::new(HashSet::new()));
+        move |&value| seen.borrow_mut().insert(value) as u8 as f64
+    }
+
+    #[test]
+    fn favors_the_novel_branch() {
+        let generator = adaptive([0..=0u16, 0..=u16::MAX], novelty());
+        assert_eq!(generator.samples(1000).flatten().count(), 1000);
+        let arms = generator.arms();
+        assert_eq!(arms[0].pulls + arms[1].pulls, 1000);
+        assert!(arms[0].reward <= 1.0);
+        assert!(arms[1].pulls > arms[0].pulls * 10);
+    }
+
+    #[test]
+    fn tries_every_branch_first() {
+        let generator = any(vec![0..=0u16, 1..=1, 2..=2]).adaptive(|_| 0.0);
+        let mut items = generator.samples(3).flatten().collect::<Vec<_>>();
+        items.sort_unstable();
+        assert_eq!(items, [0, 1, 2]);
+    }
+
+    #[test]
+    fn weights_scale_exploration() {
+        let branches = [Weight::new(10.0, 0..=0u16), Weight::new(1.0, 1..=1)];
+        let generator = adaptive(branches, |_| 0.0);
+        let _ = generator.samples(1000).flatten().count();
+        let arms = generator.arms();
+        assert!(arms[0].pulls > arms[1].pulls * 10);
+    }
+
+    #[test]
+    fn runs_start_over_and_replay_their_seed() {
+        let generator = adaptive(vec![0..=10u16, 11..=20], |&value| f64::from(value % 2));
+        let mut sampler = generator.sampler();
+        sampler.seed = 7;
+        let first = sampler.samples().collect::<Vec<_>>();
+        let second = sampler.samples().collect::<Vec<_>>();
+        assert_eq!(first, second);
+        let arms = generator.arms();
+        assert_eq!(arms[0].pulls + arms[1].pulls, 100);
+    }
+
+    #[test]
+    fn generates_the_same_item_from_the_same_state() {
+        let generator = adaptive(vec![0..=10u16, 11..=20], |&value| f64::from(value % 2));
+        let _ = generator.assert_deterministic().samples(100).count();
+    }
+
+    #[test]
+    fn counts_the_chosen_branches() {
+        let generator = adaptive([0..=10u16, 11..=20], |_| 0.0);
+        let mut checker = generator.checker();
+        checker.generate.hits = true;
+        checker.generate.count = 100;
+        let mut checks = checker.checks(|_| true);
+        let _ = checks.by_ref().count();
+        assert_eq!(checks.hits().len(), 1);
+        assert_eq!(checks.hits()[0].counts.iter().sum::<usize>(), 100);
+    }
+
+    #[test]
+    fn empty_yields_none() {
+        let generator = adaptive(Vec::<core::ops::Range<u16>>::new(), |_| 1.0);
+        assert!(generator.samples(10).all(|item| item.is_none()));
+        assert!(generator.arms().is_empty());
+    }
+}
+
+mod cover {
+    use super::*;
+
+    #[test]
+    fn chooses_every_branch_first() {
+        let generator = any(vec![0..=0u16, 1..=1, 2..=2]).cover_all_branches();
+        let mut items = generator.samples(3).flatten().collect::<Vec<_>>();
+        items.sort();
+        assert_eq!(items, [0, 1, 2]);
+    }
+
+    #[test]
+    fn replays_the_same_items() {
+        let generator = cover_all_branches(vec![0..=0u16, 1..=1, 2..=2]);
+        let mut sampler = generator.sampler();
+        sampler.count = 3;
+        let first = sampler.samples().flatten().collect::<Vec<_>>();
+        let second = sampler.samples().flatten().collect::<Vec<_>>();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn covers_a_rare_error() {
+        let generator = cover_all_branches([
+            Weight::new(1_000_000.0, map(0..10u8, Ok::<u8, u8>).boxed()),
+            Weight::new(1.0, map(0..10u8, Err::<u8, u8>).boxed()),
+        ]);
+        let items = generator.samples(10).flatten().collect::<Vec<_>>();
+        assert!(items[..2].iter().any(|item| item.is_ok()));
+        assert!(items[..2].iter().any(|item| item.is_err()));
+        let counts = generator.counts();
+        assert_eq!(counts[0] + counts[1], 10);
+        assert!(counts[0] >= 8);
+    }
+
+    #[test]
+    fn nested_choices_are_not_correlated() {
+        let branches =
+            || cover_all_branches(Vec::from_iter(Iterator::map(0..8u8, |index| index..=index)));
+        let generator = (branches(), branches());
+        let mut sampler = generator.sampler();
+        sampler.seed = 7;
+        sampler.count = 8;
+        let items = sampler
+            .samples()
+            .map(|(left, right)| (left.unwrap(), right.unwrap()))
+            .collect::<Vec<_>>();
+        let mut lefts = items.iter().map(|&(left, _)| left).collect::<Vec<_>>();
+        let mut rights = items.iter().map(|&(_, right)| right).collect::<Vec<_>>();
+        assert_ne!(lefts, rights);
+        lefts.sort();
+        rights.sort();
+        assert_eq!(lefts, Vec::from_iter(0..8));
+        assert_eq!(rights, Vec::from_iter(0..8));
+    }
+
+    #[test]
+    fn follows_the_weights_afterwards() {
+        let branches = [Weight::new(100.0, 0..=0u16), Weight::new(1.0, 1..=1)];
+        let generator = cover_all_branches(branches);
+        let _ = generator.samples(1000).flatten().count();
+        let counts = generator.counts();
+        assert!(counts[1] >= 1);
+        assert!(counts[0] > counts[1] * 10);
+    }
+
+    #[test]
+    fn clones_share_counts() {
+        let generator = cover_all_branches(vec![0..=10u16, 11..=20]);
+        let _ = generator.clone().samples(100).flatten().count();
+        assert_eq!(generator.counts().iter().sum::<usize>(), 100);
+    }
+
+    #[test]
+    fn empty_yields_none() {
+        let generator = cover_all_branches(Vec::<core::ops::Range<u16>>::new());
+        assert!(generator.samples(10).all(|item| item.is_none()));
+        assert!(generator.counts().is_empty());
+    }
+}
+
+mod hits {
+    use super::*;
+
+    #[test]
+    fn counts_the_chosen_branches() {
+        let generator = (any((0..10u8, 10..20u8, 20..30u8)), any([0..10u16, 10..20]));
+        let mut checker = generator.checker();
+        checker.generate.hits = true;
+        checker.generate.count = 100;
+        let mut checks = checker.checks(|_| true);
+        assert_eq!(checks.by_ref().count(), 100);
+        let hits = checks.hits();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].counts.len(), 3);
+        assert_eq!(hits[0].counts.iter().sum::<usize>(), 100);
+        assert_eq!(hits[1].counts.len(), 2);
+        assert_eq!(hits[1].counts.iter().sum::<usize>(), 100);
+        assert!(hits[0].counts.iter().all(|&count| count > 0));
+    }
+
+    #[test]
+    fn counts_choices_of_the_same_type_separately() {
+        let generator = (
+            any((0..10u8, 10..20u8)),
+            any((0..10u8, 10..20u8)),
+            any((0..10u8, 10..20u8)).keep(),
+        );
+        let mut checker = generator.checker();
+        checker.generate.hits = true;
+        checker.generate.count = 100;
+        let mut checks = checker.checks(|_| true);
+        let _ = checks.by_ref().count();
+        let hits = checks.hits();
+        assert_eq!(hits.len(), 3);
+        assert!(
+            hits.iter()
+                .all(|hits| hits.counts.iter().sum::<usize>() == 100)
+        );
+        assert_eq!(hits[0].name, "Any<(Range<u8>, Range<u8>)> #1");
+        assert_eq!(hits[1].name, "Any<(Range<u8>, Range<u8>)> #2");
+        assert_eq!(hits[2].name, "Any<(Range<u8>, Range<u8>)> #3");
+    }
+
+    #[test]
+    fn reveals_a_starving_branch() {
+        let generator = (
+            Weight::new(1_000_000.0, 0..10u8),
+            Weight::new(1.0, 10..20u8),
+        );
+        let mut checker = generator.checker();
+        checker.generate.hits = true;
+        let mut checks = checker.checks(|_| true);
+        let _ = checks.by_ref().count();
+        let hits = &checks.hits()[0];
+        assert!(hits.name.contains("Weight"));
+        assert_eq!(hits.counts[0], 1000);
+        assert_eq!(hits.counts[1], 0);
+    }
+
+    #[test]
+    fn counts_nothing_by_default() {
+        let generator = any((0..10u8, 10..20u8));
+        let mut checks = generator.checker().checks(|_| true);
+        let _ = checks.by_ref().count();
+        assert!(checks.hits().is_empty());
+    }
+}
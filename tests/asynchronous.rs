@@ -0,0 +1,93 @@
+#![cfg(feature = "asynchronous")]
+
+pub mod common;
+use checkito::check::{Cancel, Result, Stop};
+use common::*;
+use core::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use futures_core::Stream;
+use std::{sync::Arc, task::Wake};
+
+struct Noop;
+
+impl Wake for Noop {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn collect<S: Stream + Unpin>(mut stream: S) -> Vec<S::Item> {
+    let waker = Waker::from(Arc::new(Noop));
+    let mut context = Context::from_waker(&waker);
+    let mut items = Vec::new();
+    loop {
+        match Pin::new(&mut stream).poll_next(&mut context) {
+            Poll::Ready(Some(item)) => items.push(item),
+            Poll::Ready(None) => break items,
+            Poll::Pending => {}
+        }
+    }
+}
+
+#[test]
+fn stream_yields_all_passes() {
+    let mut checker = (0..100usize).checker();
+    checker.generate.count = 10;
+    let results = collect(checker.checks(|value| value < 100));
+    assert_eq!(results.len(), 10);
+    assert!(
+        results
+            .iter()
+            .all(|result| matches!(result, Result::Pass(_)))
+    );
+}
+
+#[test]
+fn stream_ends_with_fail() {
+    let results = collect((0..100usize).checks(|value| value < 10));
+    match results.last() {
+        Some(Result::Fail(fail)) => assert_eq!(fail.item, 10),
+        _ => panic!("expected a final failure"),
+    }
+}
+
+#[test]
+fn cancelled_stream_yields_nothing() {
+    let cancel = Cancel::new();
+    cancel.cancel();
+    let results = collect(
+        (0..100usize)
+            .checker()
+            .checks(|value| value < 10)
+            .cancel_on(cancel),
+    );
+    assert!(results.is_empty());
+}
+
+#[test]
+fn cancelled_stream_keeps_the_best_failure() {
+    let cancel = Cancel::new();
+    let mut checker = (0..1_000_000usize).checker();
+    checker.generate.size = 1.0.into();
+    let mut checks = checker.checks(|value| value < 10).cancel_on(cancel.clone());
+    let waker = Waker::from(Arc::new(Noop));
+    let mut context = Context::from_waker(&waker);
+    // Cancel the run from outside of the stream once a first item was shrunk.
+    let shrunk = loop {
+        match Pin::new(&mut checks).poll_next(&mut context) {
+            Poll::Ready(Some(Result::Shrunk(fail))) => break fail.item,
+            Poll::Ready(Some(_)) | Poll::Pending => {}
+            Poll::Ready(None) => panic!("expected a shrunk item"),
+        }
+    };
+    cancel.cancel();
+    let results = collect(checks);
+    assert_eq!(results.len(), 1);
+    match &results[0] {
+        Result::Fail(fail) => {
+            assert!((10..=shrunk).contains(&fail.item));
+            assert_eq!(fail.search.stop, Some(Stop::Cancelled));
+        }
+        _ => panic!("expected a final failure"),
+    }
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "bench")]
+
+pub mod common;
+use common::*;
+
+#[test]
+fn bench_measures_every_phase() {
+    let generator = u16::generator();
+    let mut checker = generator.checker();
+    checker.generate.count = 100;
+    let bench = checker.bench(|value| value < 1000);
+    // Generation stops at the first failure, like in the checking process.
+    assert!(bench.generate.count > 0 && bench.generate.count <= 100);
+    assert!(bench.check.count >= bench.generate.count);
+    assert!(bench.check.count <= bench.generate.count + bench.shrink.count);
+    assert!(bench.shrink.count > 0);
+    assert!(bench.generate.throughput() > 0.0);
+}
+
+#[test]
+fn bench_without_failure_does_not_shrink() {
+    let generator = u16::generator();
+    let mut checker = generator.checker();
+    checker.generate.count = 50;
+    let bench = checker.bench(|_| true);
+    assert_eq!(bench.generate.count, 50);
+    assert_eq!(bench.check.count, 50);
+    assert_eq!(bench.shrink.count, 0);
+    assert!(bench.to_string().starts_with("generate: 50 in"));
+}
+
+#[test]
+fn bench_measures_every_run() {
+    let generator = u16::generator();
+    let mut checker = generator.checker();
+    checker.generate.count = 50;
+    checker.generate.runs = 3;
+    let bench = checker.bench(|_| true);
+    assert_eq!(bench.generate.count, 150);
+    assert_eq!(bench.check.count, 150);
+}
+
+#[test]
+fn bench_silences_panics() {
+    let generator = u16::generator();
+    let mut checker = generator.checker();
+    checker.generate.count = 50;
+    let bench = checker.bench(|value| assert!(value < 1000));
+    assert!(bench.shrink.count > 0);
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "big-tuples")]
+
+pub mod common;
+use common::*;
+
+#[test]
+fn generates_and_shrinks_tuples_beyond_arity_16() {
+    let generator = <(
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+    )>::generator();
+    assert_eq!(generator.cardinality(), None);
+    let fail = generator.check(|item| item.19 < 10).unwrap();
+    assert_eq!(fail.item.19, 10);
+    assert_eq!(fail.item.0, 0);
+}
+
+#[test]
+fn generates_tuples_of_arity_32() {
+    let generator = (
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        0..1u8,
+        2..3u8,
+    );
+    assert!(generator.constant());
+    assert_eq!(generator.sample(1.0).31, 2);
+}
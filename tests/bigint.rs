@@ -0,0 +1,83 @@
+#![cfg(feature = "bigint")]
+
+pub mod common;
+use checkito::bigint::{
+    BITS,
+    num_bigint::{BigInt, BigUint},
+};
+use common::*;
+
+#[test]
+fn is_in_range_inclusive() {
+    let start: BigInt = BigInt::from(i128::MIN) * 1000;
+    let end: BigInt = BigInt::from(u128::MAX) * 1000;
+    assert!(
+        (start.clone()..=end.clone())
+            .check(|value| value >= start && value <= end)
+            .is_none()
+    );
+}
+
+#[test]
+fn is_in_exclusive_range() {
+    let fail = (BigUint::from(5u8)..BigUint::from(10u8))
+        .check(|value| value < BigUint::from(9u8))
+        .unwrap();
+    assert_eq!(fail.item, BigUint::from(9u8));
+    assert!(
+        (BigUint::from(5u8)..BigUint::from(10u8))
+            .check(|value| value < BigUint::from(10u8))
+            .is_none()
+    );
+}
+
+#[test]
+fn reaches_beyond_128_bits() {
+    let fail = BigInt::generator()
+        .check(|value| value.bits() <= 128)
+        .unwrap();
+    assert!(fail.item.bits() > 128);
+    assert!(fail.item.bits() <= BITS);
+}
+
+#[test]
+fn shrinks_to_the_smallest_failing_magnitude() {
+    let threshold = BigInt::from(u128::MAX) * 7;
+    let fail = BigInt::generator()
+        .check(|value| value < threshold)
+        .unwrap();
+    assert_eq!(fail.item, threshold);
+}
+
+#[test]
+fn shrinks_negative_integers_toward_zero() {
+    let threshold: BigInt = -(BigInt::from(u128::MAX) << 64u8);
+    let fail = (..BigInt::from(0))
+        .check(|value| value > threshold)
+        .unwrap();
+    assert_eq!(fail.item, threshold);
+}
+
+#[test]
+fn cardinality_is_capped_at_u128() {
+    let end = BigUint::from(u128::MAX);
+    assert_eq!(
+        (BigUint::from(1u8)..=end.clone()).cardinality(),
+        Some(u128::MAX)
+    );
+    assert_eq!((BigUint::from(0u8)..=end).cardinality(), None);
+    assert_eq!(BigInt::generator().cardinality(), None);
+    assert_eq!((BigInt::from(-3)..BigInt::from(3)).cardinality(), Some(6));
+}
+
+#[test]
+fn constant_range_is_constant() {
+    let value: BigInt = BigInt::from(u128::MAX) * -3;
+    assert!((value.clone()..=value.clone()).constant());
+    assert_eq!(
+        (value.clone()..=value.clone())
+            .samples(10)
+            .collect::<Vec<_>>(),
+        vec![value; 10]
+    );
+}
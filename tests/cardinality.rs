@@ -0,0 +1,113 @@
+pub mod common;
+use checkito::{
+    boxed::Boxed,
+    cardinality::{binomial, power, product, series, sum},
+};
+use common::*;
+
+#[test]
+fn primitives_have_exact_cardinality() {
+    assert_eq!(bool::generator().cardinality(), Some(2));
+    assert_eq!(u8::generator().cardinality(), Some(256));
+    assert_eq!(i8::generator().cardinality(), Some(256));
+    assert_eq!((-10..10i32).cardinality(), Some(20));
+    assert_eq!(u64::generator().cardinality(), Some(1 << 64));
+    assert_eq!(u128::generator().cardinality(), None);
+    assert_eq!(i128::generator().cardinality(), None);
+    assert_eq!(f64::generator().cardinality(), None);
+    assert_eq!(same(1).cardinality(), Some(1));
+}
+
+#[test]
+fn combinators_combine_cardinality() {
+    assert_eq!((bool::generator(), 0..10u8).cardinality(), Some(20));
+    assert_eq!((bool::generator(), 0..10u8).any().cardinality(), Some(12));
+    assert_eq!(bool::generator().array::<3>().cardinality(), Some(8));
+    assert_eq!(
+        bool::generator()
+            .collect_with::<_, Vec<_>>(3usize)
+            .cardinality(),
+        Some(8)
+    );
+    assert_eq!(bool::generator().collect::<Vec<_>>().cardinality(), None);
+    assert_eq!(
+        bool::generator()
+            .collect_with::<_, Vec<_>>(1..=3usize)
+            .cardinality(),
+        Some(14)
+    );
+    assert_eq!(
+        (0..10u8).collect_with::<_, Vec<_>>(0..3usize).cardinality(),
+        Some(111)
+    );
+    assert_eq!(
+        bool::generator()
+            .collect_with::<_, Vec<_>>(map(0..3usize, |length| length * 2))
+            .cardinality(),
+        None
+    );
+    assert_eq!(Option::<bool>::generator().cardinality(), Some(3));
+    assert_eq!(
+        u8::generator().map(|value| value / 2).cardinality(),
+        Some(256)
+    );
+    assert_eq!((bool::generator(), f64::generator()).cardinality(), None);
+}
+
+#[test]
+fn composite_cardinality_does_not_overflow() {
+    assert_eq!((u64::generator(), u64::generator()).cardinality(), None);
+    assert_eq!(u64::generator().array::<3>().cardinality(), None);
+    assert_eq!(product(Some(u128::MAX), Some(2)), None);
+    assert_eq!(product(None, Some(0)), Some(0));
+    assert_eq!(sum(Some(u128::MAX), Some(1)), None);
+    assert_eq!(power(Some(2), 128), None);
+    assert_eq!(power(None, 0), Some(1));
+    assert_eq!(series(Some(2), 0, 3), Some(15));
+    assert_eq!(series(Some(1), 2, 5), Some(4));
+    assert_eq!(series(Some(2), 0, usize::MAX), None);
+    assert_eq!(binomial(5, 2), Some(10));
+    assert_eq!(binomial(5, 0), Some(1));
+    assert_eq!(binomial(5, 6), Some(0));
+    assert_eq!(binomial(64, 32), Some(1_832_624_140_942_590_534));
+    assert_eq!(binomial(1000, 500), None);
+}
+
+#[test]
+fn matrix_with_fixed_dimensions_is_known() {
+    assert_eq!(
+        matrix(2usize, 3usize, bool::generator()).cardinality(),
+        Some(64)
+    );
+    assert_eq!(
+        matrix(0..2usize, 3usize, bool::generator()).cardinality(),
+        None
+    );
+}
+
+#[test]
+fn boxed_generators_preserve_cardinality() {
+    assert_eq!(bool::generator().boxed().cardinality(), Some(2));
+    assert_eq!((0..10u8, bool::generator()).boxed().cardinality(), Some(20));
+    assert_eq!(
+        [(0..3u8).boxed(), (10..12u8).boxed()]
+            .any()
+            .boxed()
+            .cardinality(),
+        Some(5)
+    );
+    assert_eq!(f64::generator().boxed().cardinality(), None);
+    assert!(same(1).boxed().constant());
+}
+
+#[test]
+fn boxed_from_parts_declares_cardinality() {
+    let generator = Boxed::from_parts(|state| (0..4u8).generate(state), Some(4));
+    assert_eq!(generator.cardinality(), Some(4));
+    assert!(!generator.constant());
+    assert!(generator.samples(100).all(|item| item < 4));
+
+    let constant = Boxed::from_parts(|_| 7u8, Some(1));
+    assert!(constant.constant());
+    assert_eq!(Boxed::<u8>::from_parts(|_| 7u8, None).cardinality(), None);
+}
@@ -50,6 +50,31 @@ fn full_does_not_panic() {
     assert!(char::generator().check(|_| true).is_none());
 }
 
+#[test]
+fn full_cardinality_excludes_surrogates() {
+    assert_eq!(char::generator().cardinality(), Some(1_112_064));
+}
+
+#[test]
+fn range_skips_surrogate_gap() {
+    let generator = '\u{D7FF}'..='\u{E000}';
+    assert_eq!(generator.cardinality(), Some(2));
+    assert!(
+        generator
+            .samples(100)
+            .all(|value| value == '\u{D7FF}' || value == '\u{E000}')
+    );
+    assert!(('\u{D7FF}'..'\u{E000}').constant());
+}
+
+#[test]
+fn range_across_surrogates_shrinks_across_gap() {
+    let fail = ('\u{D000}'..='\u{F000}')
+        .check(|value| value < '\u{E005}')
+        .unwrap();
+    assert_eq!(fail.item, '\u{E005}');
+}
+
 macro_rules! collection {
     ($m:ident, $t:ty, $i:ident) => {
         mod $m {
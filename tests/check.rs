@@ -2,8 +2,14 @@
 
 pub mod common;
 use common::*;
-use core::fmt;
+use core::{
+    cell::{Cell, RefCell},
+    fmt,
+    marker::PhantomData,
+};
 use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, VecDeque},
     str::FromStr,
     sync::atomic::{AtomicUsize, Ordering},
 };
@@ -64,6 +70,24 @@ fn compiles_with_discard_and_rest_arguments(
 ) {
 }
 
+#[check(..)]
+fn compiles_with_inferred_collections(
+    _first: VecDeque<u8>,
+    _second: BTreeSet<char>,
+    _third: BTreeMap<u8, bool>,
+    _fourth: BinaryHeap<i32>,
+) {
+}
+
+#[check(..)]
+fn compiles_with_inferred_wrappers(
+    _first: Cow<'static, str>,
+    _second: Cell<u8>,
+    _third: RefCell<Vec<u8>>,
+    _fourth: PhantomData<fn() -> u8>,
+) {
+}
+
 #[check("a string")]
 fn compiles_with_constant_str(_: &str) {}
 
@@ -94,6 +118,210 @@ fn compiles_with_debug_true() {}
 #[check(debug = false)]
 fn compiles_with_debug_false() {}
 
+#[check(0..10u8, reporter = check::report::Json, verbose = true)]
+fn compiles_with_json_reporter(_: u8) {}
+
+#[check(0..10u8, shrink.explain = true)]
+#[should_panic]
+fn panics_with_shrink_explain(value: u8) {
+    assert!(value < 5);
+}
+
+#[check(0..10u8, reporter = check::report::Minimal)]
+fn compiles_with_minimal_reporter(_: u8) {}
+
+struct Counting(&'static AtomicUsize);
+
+impl<T, P, E> check::report::Reporter<T, P, E> for Counting {
+    fn pass(&self, _: fmt::Arguments, _: check::Pass<T, P>) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn fail(&self, _: fmt::Arguments, _: check::Fail<T, E>) {
+        unreachable!()
+    }
+
+    fn summary(&self, summary: check::report::Summary) {
+        assert_eq!(self.0.load(Ordering::Relaxed), 10);
+        assert_eq!(summary.failures, 0);
+        assert!(summary.name.ends_with("::reports_to_custom_reporter"));
+    }
+}
+
+static PASSES: AtomicUsize = AtomicUsize::new(0);
+
+#[check(0..100u8, reporter = Counting(&PASSES), verbose = true, generate.count = 10)]
+fn reports_to_custom_reporter(_: u8) {}
+
+struct Unreachable;
+
+impl<T, P, E> check::report::Reporter<T, P, E> for Unreachable {
+    fn pass(&self, _: fmt::Arguments, _: check::Pass<T, P>) {
+        unreachable!()
+    }
+
+    fn fail(&self, _: fmt::Arguments, _: check::Fail<T, E>) {
+        unreachable!()
+    }
+}
+
+#[check(0..100u8, reporter = Unreachable, verbose = true, quiet = true, generate.runs = 3)]
+fn quiet_does_not_report_passing_runs(_: u8) {}
+
+struct Saturated(&'static AtomicUsize);
+
+impl<T, P, E> check::report::Reporter<T, P, E> for Saturated {
+    fn pass(&self, _: fmt::Arguments, _: check::Pass<T, P>) {}
+
+    fn fail(&self, _: fmt::Arguments, _: check::Fail<T, E>) {
+        unreachable!()
+    }
+
+    fn saturated(&self, _: fmt::Arguments, saturation: &check::Saturation) {
+        assert_eq!(saturation.cardinality, 6);
+        assert_eq!(saturation.count, 100);
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn summary(&self, _: check::report::Summary) {
+        assert_eq!(self.0.load(Ordering::Relaxed), 1);
+    }
+}
+
+static SATURATIONS: AtomicUsize = AtomicUsize::new(0);
+
+#[check(0..6u8, reporter = Saturated(&SATURATIONS), generate.count = 100)]
+fn reports_saturation_once(_: u8) {}
+
+struct Named;
+
+impl<T: fmt::Debug, P, E> check::report::Reporter<T, P, E> for Named {
+    fn pass(&self, _: fmt::Arguments, _: check::Pass<T, P>) {}
+
+    fn fail(&self, _: fmt::Arguments, fail: check::Fail<T, E>) {
+        assert_eq!(
+            format!("{:?}", fail.item),
+            r#"{ count: 10, (left, _): (0, 'a') }"#
+        );
+        assert_eq!(
+            format!("{:#?}", fail.item),
+            "{\n    count: 10,\n    (left, _): (\n        0,\n        'a',\n    ),\n}"
+        );
+    }
+}
+
+#[check(0..100u8, same((0u8, 'a')), reporter = Named)]
+#[should_panic(expected = "explicit panic")]
+fn reports_items_with_parameter_names(count: u8, (left, _): (u8, char)) {
+    assert!(count + left < 10);
+}
+
+#[check(0..100u8, verbose = true, quiet = true)]
+#[should_panic]
+fn quiet_reports_failing_runs(value: u8) {
+    assert!(value < 50);
+}
+
+struct Tail(&'static AtomicUsize);
+
+impl<T, P, E> check::report::Reporter<T, P, E> for Tail {
+    fn pass(&self, _: fmt::Arguments, _: check::Pass<T, P>) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn shrink(&self, _: fmt::Arguments, _: check::Pass<T, P>) {}
+
+    fn fail(&self, _: fmt::Arguments, _: check::Fail<T, E>) {}
+
+    fn summary(&self, _: check::report::Summary) {
+        assert_eq!(self.0.load(Ordering::Relaxed), 16);
+    }
+}
+
+static TAIL: AtomicUsize = AtomicUsize::new(0);
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[check(0..100u8, reporter = Tail(&TAIL), verbose = true, quiet = true)]
+#[should_panic(expected = "explicit panic")]
+fn quiet_reports_a_bounded_tail_of_failing_runs(_: u8) {
+    assert!(CALLS.fetch_add(1, Ordering::Relaxed) < 100);
+}
+
+#[derive(Debug)]
+struct Outer(Inner);
+#[derive(Debug)]
+struct Inner;
+
+impl fmt::Display for Outer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("outer")
+    }
+}
+
+impl fmt::Display for Inner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("inner")
+    }
+}
+
+impl std::error::Error for Outer {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+impl std::error::Error for Inner {}
+
+#[check(0..10u8)]
+fn compiles_with_custom_error(value: u8) -> Result<(), Outer> {
+    if value < 10 {
+        Ok(())
+    } else {
+        Err(Outer(Inner))
+    }
+}
+
+fn double(value: u16) -> u32 {
+    u32::from(value) * 2
+}
+
+#[check(_, differential = double)]
+fn compiles_with_differential(value: u16) -> u32 {
+    u32::from(value) << 1
+}
+
+#[check(_, differential = double)]
+#[should_panic]
+fn panics_with_differential_divergence(value: u16) -> u32 {
+    u32::from(value) + 2
+}
+
+#[test]
+// The references select the description through method resolution.
+#[allow(clippy::needless_borrow)]
+fn describes_errors_with_display_and_sources() {
+    use check::help::describe::{self, *};
+
+    let check = |value: u8| if value < 5 { Ok(()) } else { Err(Outer(Inner)) };
+    let format = (&&&&&describe::tag(&check)).describe();
+    let fail = (0..10u8).check(describe::check(check, format)).unwrap();
+    assert_eq!(fail.message(), "outer: inner");
+
+    let check = |value: u8| {
+        let result: Result<Result<(), Outer>, Inner> =
+            Ok(if value < 5 { Ok(()) } else { Err(Outer(Inner)) });
+        checkito::prove::Nested(result)
+    };
+    let format = (&&&&&describe::tag(&check)).describe();
+    let fail = (0..10u8).check(describe::check(check, format)).unwrap();
+    assert_eq!(fail.message(), "outer: inner");
+
+    let check = |value: u8| value < 5;
+    let format = (&&&&&describe::tag(&check)).describe();
+    let fail = (0..10u8).check(describe::check(check, format)).unwrap();
+    assert_eq!(fail.message(), "()");
+}
+
 #[check(color = true)]
 fn compiles_with_color_true() {}
 
@@ -117,6 +345,14 @@ fn compiles_with_generate_size_range() {}
 #[check(generate.size = ..)]
 fn compiles_with_generate_size_full_range() {}
 
+const SIZE: f64 = 0.5;
+
+#[check(generate.size = SIZE..=1.0)]
+fn compiles_with_generate_size_non_literal_range() {}
+
+#[check(generate.count = check::COUNT / 10, shrink.count = 0)]
+fn compiles_with_non_literal_count_and_no_shrinking() {}
+
 #[check(generate.items = false)]
 fn compiles_with_generate_items() {}
 
@@ -126,6 +362,70 @@ fn compiles_with_generate_count() {
     assert!(COUNT.fetch_add(1, Ordering::Relaxed) < 100);
 }
 
+#[check(0..10_000u32, generate.exhaustive = "stratified")]
+fn compiles_with_generate_exhaustive(value: u32) {
+    assert!(value < 10_000);
+}
+
+#[check(_, generate.ramp = "descending")]
+fn compiles_with_generate_ramp(_: u8) {}
+
+#[check(any((0..10u8, 10..20u8)), generate.hits = true)]
+fn compiles_with_generate_hits(value: orn::Or2<u8, u8>) {
+    assert!(value.into::<u8>() < 20);
+}
+
+#[check(generate.count = 10)]
+fn draws_items_inline() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    assert!(CALLS.fetch_add(1, Ordering::Relaxed) < 10);
+    let items = gen!(u8::generator().collect::<Vec<_>>());
+    let index = gen!(0..=items.len());
+    assert!(index <= items.len());
+}
+
+#[check(0..10u8)]
+fn draws_items_inline_along_with_parameters(value: u8) {
+    assert!(value < 10 && gen!(value..=10) >= value);
+}
+
+#[check]
+#[should_panic]
+fn draws_items_inline_and_fails() {
+    assert!(gen!(0..1000u32) < 100);
+}
+
+#[check(0..10u8, #[keep] 10..20u8)]
+fn compiles_with_keep_annotation(left: u8, right: u8) {
+    assert!(left < right);
+}
+
+#[check(#[keep] _, .., keep = [2])]
+fn compiles_with_keep_annotation_on_inferred_generators(_: u8, _: u16, _: bool) {}
+
+#[check(_, 0..10u8, keep = [0, 1])]
+fn compiles_with_keep_positions(_: u8, right: u8) {
+    assert!(right < 10);
+}
+
+#[check(_, _, 0..10u8, size = [1.0, _, 0.0..0.5])]
+fn compiles_with_size_positions(_: u8, _: u16, right: u8) {
+    assert!(right < 10);
+}
+
+#[check(0..=1000u32, 0..=1000u32, generate.size = 0.0, size = [_, 1.0])]
+#[should_panic]
+fn size_positions_override_the_generate_size(small: u32, large: u32) {
+    assert_eq!(small, 0);
+    assert!(large < 10);
+}
+
+#[check(_, progress = true)]
+fn compiles_with_progress(_: u8) {}
+
+#[check(generate.count = 10, generate.runs = 3)]
+fn compiles_with_generate_runs() {}
+
 #[check(shrink.count = 1 + 123_098)]
 fn compiles_with_shrink_count() {}
 
@@ -135,6 +435,62 @@ fn compiles_with_shrink_items() {}
 #[check(shrink.errors = true)]
 fn compiles_with_shrink_errors() {}
 
+#[check(_, _, literals = true)]
+#[should_panic]
+fn compiles_with_literals_and_finds_them(value: u32, text: String) {
+    assert_ne!(value, 987_654);
+    assert_ne!(text, "magic");
+}
+
+#[check(_, literals = true)]
+#[should_panic]
+fn compiles_with_literals_and_finds_negative_ones(value: i32) {
+    assert_ne!(value, -987_654);
+}
+
+type Number = u64;
+
+#[check(_, literals = true)]
+#[should_panic]
+fn compiles_with_literals_and_finds_them_through_paths(value: std::primitive::u32) {
+    assert_ne!(value, 3_141_592);
+}
+
+#[check(_, literals = true)]
+#[should_panic]
+fn compiles_with_literals_and_finds_them_through_aliases(value: Number) {
+    assert_ne!(value, 987_654_321);
+}
+
+#[check(0..10u32, literals = true)]
+fn compiles_with_literals_and_explicit_generator(value: u32) {
+    assert!(value < 10);
+    assert_ne!(value, 1_000_000);
+}
+
+fn setup() -> Vec<u8> {
+    static SETUPS: AtomicUsize = AtomicUsize::new(0);
+    assert_eq!(SETUPS.fetch_add(1, Ordering::Relaxed), 0);
+    Vec::new()
+}
+
+#[check(_, fixture = setup, generate.count = 100)]
+fn compiles_with_fixture(fixture: &mut Vec<u8>, value: u8) {
+    fixture.push(value);
+    assert!(fixture.len() <= 100);
+}
+
+#[check(.., fixture = (|| 0usize, |count| assert_eq!(count, 10)), generate.count = 10)]
+fn compiles_with_fixture_and_teardown(fixture: &mut usize, _: bool, _: char) {
+    *fixture += 1;
+}
+
+#[check(shrink.strategy = checkito::check::Strategy::Bounded(100))]
+fn compiles_with_shrink_strategy() {}
+
+#[check(shrink.confirm = 3)]
+fn compiles_with_shrink_confirm() {}
+
 #[check(true)]
 const fn compiles_with_const(value: bool) -> bool {
     value
@@ -207,3 +563,248 @@ mod regex {
         assert!(value.chars().all(char::is_numeric));
     }
 }
+
+#[check(generate.count = 1, color = false)]
+mod inherited {
+    use super::*;
+
+    #[check(_)]
+    fn inherits_module_settings(_: u8) {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        assert_eq!(CALLS.fetch_add(1, Ordering::Relaxed), 0);
+    }
+
+    #[check(_, generate.count = 5)]
+    #[should_panic]
+    fn overrides_module_settings(_: u8) {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        assert_eq!(CALLS.fetch_add(1, Ordering::Relaxed), 0);
+    }
+
+    mod nested {
+        use super::*;
+
+        #[check(_)]
+        fn inherits_in_nested_modules(_: u8) {
+            static CALLS: AtomicUsize = AtomicUsize::new(0);
+            assert_eq!(CALLS.fetch_add(1, Ordering::Relaxed), 0);
+        }
+    }
+}
+
+mod continues {
+    use super::*;
+
+    #[check(0..10u8, continue = true)]
+    #[check(10..20u8, continue = true)]
+    #[check(20..30u8)]
+    #[should_panic(expected = "2 of 3 checks failed")]
+    fn runs_every_check_and_reports_the_failures(value: u8) {
+        assert!(value >= 20);
+    }
+
+    #[test]
+    fn records_the_seed_of_a_failed_check() {
+        let failure = check::help::attempt(2, || {
+            check::help::minimal(0..10u8, |_| {}, |value| value > 10, false, false, false, "")
+        })
+        .unwrap();
+        assert_eq!(failure.index, 2);
+        assert!(failure.seed.is_some());
+        assert!(check::help::attempt(1, || {}).is_none());
+    }
+}
+
+mod context {
+    use super::*;
+
+    #[test]
+    fn fail_includes_the_context_of_the_minimal_item() {
+        let fail = (0..1000u16)
+            .check(|value| {
+                checkito::context("while checking");
+                if value >= 100 {
+                    checkito::context(format!("while checking {value}"));
+                }
+                value < 100
+            })
+            .unwrap();
+        assert_eq!(fail.item, 100);
+        assert_eq!(fail.context, ["while checking", "while checking 100"]);
+    }
+
+    #[test]
+    fn panics_keep_the_context_recorded_before_them() {
+        let fail = (0..10u8, 0..10u8)
+            .check(|(left, right)| {
+                checkito::context("left");
+                assert!(left < 5);
+                checkito::context("right");
+                assert!(right < 5);
+            })
+            .unwrap();
+        assert_eq!(
+            fail.context,
+            if fail.item.0 < 5 {
+                vec!["left", "right"]
+            } else {
+                vec!["left"]
+            }
+        );
+    }
+
+    #[test]
+    fn nested_checks_do_not_share_context() {
+        let fail = (0..10u8)
+            .check(|value| {
+                checkito::context("outer");
+                assert!((0..10u8).check(|_| checkito::context("inner")).is_none());
+                value < 5
+            })
+            .unwrap();
+        assert_eq!(fail.context, ["outer"]);
+    }
+
+    #[test]
+    fn context_outside_of_a_check_is_ignored() {
+        checkito::context("ignored");
+        let fail = (0..10u8).check(|value| value < 5).unwrap();
+        assert!(fail.context.is_empty());
+    }
+}
+
+mod erased {
+    use super::*;
+
+    #[check(0..10u8, _, erased = true)]
+    fn compiles_with_erased(left: u8, right: bool) -> bool {
+        left < 10 || right
+    }
+
+    #[check(0..10u8, erased = true, generate.count = 5)]
+    fn applies_settings_when_erased(_: u8) {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        assert!(CALLS.fetch_add(1, Ordering::Relaxed) < 5);
+    }
+
+    #[check(0..10u8, erased = true)]
+    #[should_panic]
+    fn fails_when_erased(value: u8) {
+        assert!(value < 5);
+    }
+
+    #[test]
+    fn erased_checker_shrinks_like_the_original() {
+        let checker = (0..1000u16).checker().erased();
+        let fail = checker
+            .checks(|value| value < 100)
+            .find_map(|result| result.fail(false))
+            .unwrap();
+        assert_eq!(fail.item, 100);
+    }
+}
+
+mod skip {
+    use super::*;
+
+    #[check(0..100u8)]
+    fn compiles_with_skip(value: u8) {
+        if value % 4 == 0 {
+            checkito::skip!("{value} is a multiple of 4");
+        }
+        assert!(value % 4 != 0);
+    }
+
+    #[check(0..100u8, generate.skips = 0.0)]
+    #[should_panic]
+    fn fails_when_no_skip_is_allowed(value: u8) {
+        if value % 2 == 0 {
+            checkito::skip!();
+        }
+    }
+
+    #[test]
+    fn skipped_items_are_not_passes() {
+        let mut checker = (0..100u8).checker();
+        checker.generate.count = 100;
+        let mut checks = checker.checks(|value| {
+            if value >= 90 {
+                checkito::skip!("{value} is too large");
+            }
+            value < 90
+        });
+        let mut passes = 0;
+        for result in &mut checks {
+            match result {
+                check::Result::Pass(pass) => {
+                    assert!(pass.item < 90);
+                    passes += 1;
+                }
+                check::Result::Skip(skip) => {
+                    assert!(skip.item >= 90);
+                    assert_eq!(skip.reason, format!("{} is too large", skip.item));
+                }
+                _ => panic!("the check must not fail"),
+            }
+        }
+        assert_eq!(passes + checks.skipped(), 100);
+    }
+
+    #[test]
+    fn too_many_skips_fail_as_vacuous() {
+        let fail = (0..100u8)
+            .check(|value| {
+                if value > 10 {
+                    checkito::skip!();
+                }
+                true
+            })
+            .unwrap();
+        assert!(matches!(fail.cause, check::Cause::Vacuous { .. }));
+        assert_eq!(fail.shrinks, 0);
+    }
+}
+
+mod patterns {
+    use super::*;
+
+    #[derive(Clone, Debug)]
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    fn point() -> impl Generate<Item = Point> {
+        map((0..10u8, 0..10u8), |(x, y)| Point { x, y })
+    }
+
+    #[check((0..10u8, 10..20u8), point())]
+    fn compiles_with_destructured_parameters((low, high): (u8, u8), Point { x, y }: Point) {
+        assert!(low < high);
+        assert!(x < 10 && y < 10);
+    }
+
+    #[check(.., literals = true)]
+    fn compiles_with_inferred_destructured_parameters(
+        [first, _]: [u8; 2],
+        (mut value, _): (u16, bool),
+    ) {
+        value = value.saturating_add(u16::from(first));
+        assert!(value >= u16::from(first));
+    }
+
+    #[check(_, differential = reference)]
+    fn compiles_with_destructured_differential((left, right): (u8, u8)) -> u16 {
+        u16::from(left) + u16::from(right)
+    }
+
+    fn reference((left, right): (u8, u8)) -> u16 {
+        u16::from(right) + u16::from(left)
+    }
+
+    #[check(point())]
+    #[should_panic]
+    fn fails_with_destructured_parameters(Point { x, .. }: Point) {
+        assert!(x < 5);
+    }
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "config")]
+
+pub mod common;
+use checkito::{
+    any::Weight,
+    collect::Collect,
+    config::{Bounds, Configure, Error, FromConfig},
+    same::Same,
+};
+use common::*;
+use std::ops::RangeInclusive;
+
+type Lengths = Vec<Weight<RangeInclusive<usize>>>;
+type Names = Collect<Vec<Weight<Same<char>>>, RangeInclusive<usize>, Vec<Option<char>>>;
+
+#[test]
+fn configured_generators_respect_their_configuration() {
+    let json = r#"{ "item": [[3.0, "a"], [1.0, "b"]], "length": [2, 5] }"#;
+    let generator = serde_json::from_str::<Configure<Names>>(json).unwrap();
+    for name in generator.samples(100) {
+        assert!((2..=5).contains(&name.len()));
+        assert!(
+            name.iter()
+                .all(|&symbol| symbol == Some('a') || symbol == Some('b'))
+        );
+    }
+}
+
+#[test]
+fn invalid_bounds_are_reported_with_their_path() {
+    let error = Configure::<Lengths>::new(vec![
+        (1.0, Bounds { start: 0, end: 1 }),
+        (1.0, Bounds { start: 5, end: 1 }),
+    ])
+    .unwrap_err();
+    assert_eq!(error.path, "[1]");
+    assert_eq!(error.to_string(), "[1]: start 5 is greater than end 1");
+
+    let json = r#"{ "item": [[3.0, "a"]], "length": [6, 5] }"#;
+    let error = serde_json::from_str::<Configure<Names>>(json).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .starts_with("length: start 6 is greater than end 5")
+    );
+}
+
+#[test]
+fn invalid_weights_are_reported() {
+    let error = Configure::<Lengths>::new(vec![(0.0, Bounds { start: 0, end: 1 })]).unwrap_err();
+    assert_eq!(error.path, "[0]");
+    assert_eq!(
+        Configure::<Lengths>::new(Vec::new()).unwrap_err(),
+        Error::new("at least one weighted choice is required")
+    );
+    assert!(
+        RangeInclusive::<f64>::from_config(Bounds {
+            start: f64::NAN,
+            end: 1.0
+        })
+        .is_err()
+    );
+}
@@ -0,0 +1,47 @@
+#![cfg(all(feature = "corpus", feature = "check"))]
+
+pub mod common;
+use checkito::corpus::{self, Corpus};
+use common::*;
+
+#[test]
+fn registered_items_are_loaded_by_type() {
+    corpus::register("tests::left", &(1u8, 'a'));
+    corpus::register("tests::right", &(2u8, 'b'));
+    corpus::register("tests::left", &(3u8, 'c'));
+    assert_eq!(corpus::load::<(u8, char)>(), [(3, 'c'), (2, 'b')]);
+}
+
+#[test]
+fn disproved_items_are_recorded() {
+    let fail = (0..1000u32)
+        .check(corpus::record("tests::disproved", |value: u32| value < 100))
+        .unwrap();
+    assert_eq!(fail.item, 100);
+    assert_eq!(corpus::load::<u32>(), [100]);
+}
+
+#[test]
+fn panicking_items_are_recorded() {
+    let fail = (0..1000i16)
+        .check(corpus::record("tests::panicking", |value: i16| {
+            assert!(value < 50)
+        }))
+        .unwrap();
+    assert_eq!(fail.item, 50);
+    assert_eq!(corpus::load::<i16>(), [50]);
+}
+
+#[test]
+fn corpus_yields_entries_first() {
+    corpus::register("tests::sibling", &1234u64);
+    let generator = Corpus::new(0..10u64);
+    let fail = generator.check(|value| value < 10).unwrap();
+    assert_eq!(fail.item, 1234);
+    assert_eq!(fail.generates, 0);
+}
+
+#[check(_, _, corpus = true)]
+fn compiles_with_corpus(value: i128, text: String) {
+    assert!(value == value && text == text);
+}
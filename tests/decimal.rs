@@ -0,0 +1,71 @@
+#![cfg(feature = "decimal")]
+
+pub mod common;
+use checkito::decimal::rust_decimal::Decimal;
+use common::*;
+
+#[test]
+fn is_in_range_with_the_scale_of_its_bounds() {
+    let start = Decimal::new(-10_000, 2);
+    let end = Decimal::new(10_000, 1);
+    assert!(
+        (start..=end)
+            .check(|value| value >= start && value <= end && value.scale() == 2)
+            .is_none()
+    );
+}
+
+#[test]
+fn excludes_the_end_at_the_scale_of_the_range() {
+    let end = Decimal::new(100, 2);
+    let fail = (Decimal::ZERO..end)
+        .check(|value| value < Decimal::new(90, 2))
+        .unwrap();
+    assert_eq!(fail.item, Decimal::new(90, 2));
+    assert!((Decimal::ZERO..end).check(|value| value < end).is_none());
+}
+
+#[test]
+fn shrinks_toward_zero_and_keeps_its_scale() {
+    let threshold = Decimal::new(123_456, 3);
+    let fail = (Decimal::new(-1_000_000, 3)..=Decimal::new(1_000_000, 3))
+        .check(|value| value.abs() < threshold)
+        .unwrap();
+    assert_eq!(fail.item.abs(), threshold);
+    assert_eq!(fail.item.scale(), 3);
+}
+
+#[test]
+fn full_reaches_beyond_i64() {
+    let fail = Decimal::generator()
+        .check(|value| value.mantissa().unsigned_abs() <= u64::MAX as u128)
+        .unwrap();
+    assert!(fail.item.mantissa().unsigned_abs() > u64::MAX as u128);
+}
+
+#[test]
+fn unbounded_ranges_are_valid() {
+    assert!(
+        (..Decimal::ZERO)
+            .check(|value| value < Decimal::ZERO)
+            .is_none()
+    );
+    assert!(
+        (Decimal::ZERO..)
+            .check(|value| value >= Decimal::ZERO)
+            .is_none()
+    );
+}
+
+#[test]
+fn cardinality_counts_mantissas() {
+    assert_eq!((Decimal::ZERO..Decimal::ONE).cardinality(), Some(1));
+    assert_eq!(
+        (Decimal::new(0, 2)..=Decimal::new(100, 2)).cardinality(),
+        Some(101)
+    );
+    assert_eq!(
+        (Decimal::MIN..=Decimal::MAX).cardinality(),
+        Some((1u128 << 97) - 1)
+    );
+}
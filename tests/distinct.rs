@@ -0,0 +1,126 @@
+pub mod common;
+use common::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+#[test]
+fn set_of_has_requested_length() {
+    for set in set_of::<_, _, HashSet<_>>(u16::generator(), 10usize).samples(100) {
+        assert_eq!(set.len(), 10);
+    }
+}
+
+#[test]
+fn map_of_has_requested_length() {
+    let generator = map_of::<_, _, _, BTreeMap<_, _>>(0..100u8, same(()), 10usize);
+    for map in generator.samples(100) {
+        assert_eq!(map.len(), 10);
+    }
+}
+
+#[test]
+fn set_of_is_limited_by_item_space() {
+    for set in set_of::<_, _, BTreeSet<_>>(bool::generator(), 10usize).samples(100) {
+        assert!(set.len() <= 2);
+    }
+}
+
+#[test]
+fn full_sets_and_maps_are_limited_by_key_space() {
+    for set in BTreeSet::<bool>::generator().samples(100) {
+        assert!(set.len() <= 2);
+    }
+    for map in BTreeMap::<bool, u8>::generator().samples(100) {
+        assert!(map.len() <= 2);
+    }
+}
+
+#[test]
+fn full_sets_shrink_to_a_single_item() {
+    let fail = BTreeSet::<u8>::generator()
+        .check(|set| set.iter().all(|&item| item < 100))
+        .unwrap();
+    assert_eq!(fail.item.into_iter().collect::<Vec<_>>(), [100]);
+}
+
+#[test]
+fn map_of_shrinks_entries_keys_and_values() {
+    let generator = map_of::<_, _, _, HashMap<_, _>>(u8::generator(), u8::generator(), 0..100usize);
+    let fail = generator
+        .check(|map| map.values().all(|&value| value < 100))
+        .unwrap();
+    assert_eq!(fail.item.len(), 1);
+    assert_eq!(fail.item.values().copied().collect::<Vec<_>>(), [100]);
+    assert_eq!(fail.item.keys().copied().collect::<Vec<_>>(), [0]);
+}
+
+#[test]
+fn set_of_shrinks_to_the_smallest_count() {
+    let fail = set_of::<_, _, HashSet<_>>(u8::generator(), 3..10usize)
+        .check(|set| set.len() < 3)
+        .unwrap();
+    assert_eq!(fail.item.len(), 3);
+}
+
+#[test]
+fn set_of_into_vec_only_requires_equality() {
+    for items in set_of::<_, _, Vec<_>>(0.0..1.0f64, 10usize).samples(100) {
+        for (index, item) in items.iter().enumerate() {
+            assert!(!items[..index].contains(item));
+        }
+    }
+}
+
+#[test]
+fn distinct_cardinality() {
+    assert_eq!(
+        set_of::<_, _, BTreeSet<_>>(0..10u8, 0..10usize).cardinality(),
+        Some(1 << 10)
+    );
+    assert_eq!(
+        map_of::<_, _, _, BTreeMap<_, _>>(0..3u8, bool::generator(), 0..3usize).cardinality(),
+        Some(27)
+    );
+    assert_eq!(
+        set_of::<_, _, BTreeSet<_>>(u8::generator(), 0..10usize).cardinality(),
+        None
+    );
+}
+
+#[test]
+fn choose_selects_distinct_items_in_order() {
+    let items = Iterator::collect::<Vec<_>>(0..20u8);
+    for chosen in choose(5, &items).samples(100) {
+        assert_eq!(chosen.len(), 5);
+        assert!(chosen.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+    for chosen in subset(&items).samples(100) {
+        assert!(chosen.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
+
+#[test]
+fn choose_shrinks_toward_earlier_items() {
+    let items = Iterator::collect::<Vec<_>>(0..100u8);
+    let fail = choose(3, &items)
+        .check(|chosen| chosen.iter().all(|&item| item < 50))
+        .unwrap();
+    assert_eq!(fail.item, [0, 1, 50]);
+}
+
+#[test]
+fn subset_shrinks_toward_fewer_items() {
+    let items = Iterator::collect::<Vec<_>>(0..100u8);
+    let fail = subset(&items)
+        .check(|chosen| chosen.iter().all(|&item| item < 50))
+        .unwrap();
+    assert_eq!(fail.item, [50]);
+}
+
+#[test]
+fn choose_cardinality() {
+    let items = [1, 2, 3, 4, 5];
+    assert_eq!(choose(2, &items).cardinality(), Some(10));
+    assert_eq!(choose(5, &items).cardinality(), Some(1));
+    assert_eq!(subset(&items).cardinality(), Some(32));
+    assert_eq!(subset(&[0u8; 200]).cardinality(), None);
+}
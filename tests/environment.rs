@@ -0,0 +1,39 @@
+//! Environment variables are process-wide and reading them while another
+//! thread sets them is racy, so the test that sets them lives in its own binary
+//! and must stay the only test of it.
+
+pub mod common;
+use common::*;
+use std::{
+    env,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+#[test]
+fn environment_overrides_are_scoped_to_a_test_name() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    env::set_var("CHECKITO_GENERATE_COUNT__scoped__override", "7");
+    let check = |_| {
+        COUNT.fetch_add(1, Ordering::Relaxed);
+    };
+    checkito::check::help::minimal(
+        u8::generator(),
+        |_| {},
+        check,
+        false,
+        false,
+        false,
+        "tests::scoped::override",
+    );
+    assert_eq!(COUNT.swap(0, Ordering::Relaxed), 7);
+    checkito::check::help::minimal(
+        u8::generator(),
+        |_| {},
+        check,
+        false,
+        false,
+        false,
+        "tests::scoped::other",
+    );
+    assert_eq!(COUNT.swap(0, Ordering::Relaxed), 1000);
+}
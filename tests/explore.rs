@@ -0,0 +1,79 @@
+#![cfg(feature = "explore")]
+
+pub mod common;
+use checkito::explore::{Error, Explorer};
+use common::*;
+
+#[test]
+fn parses_nested_expressions() {
+    let generator = Explorer::new()
+        .parse("([0..=9; 2..=2], (bool,), -1.0..1.0)")
+        .unwrap();
+    for item in generator.samples(100) {
+        assert!(item.starts_with("([") && item.contains("], (") && item.ends_with(')'));
+        assert!(item.contains("true,)") || item.contains("false,)"));
+    }
+}
+
+#[test]
+fn integer_ranges_stay_within_bounds() {
+    let generator = Explorer::new().parse("-5..=5").unwrap();
+    assert!(generator.samples(100).all(|item| {
+        let value = item.parse::<i8>().unwrap();
+        (-5..=5).contains(&value)
+    }));
+}
+
+#[test]
+fn options_wrap_their_item() {
+    let generator = Explorer::new().parse("u8?").unwrap();
+    assert!(
+        generator
+            .samples(100)
+            .all(|item| item == "None" || item.starts_with("Some("))
+    );
+}
+
+#[test]
+fn registered_generators_are_named() {
+    let explorer = Explorer::empty().register("even", map(0..100u32, |value| value * 2));
+    assert_eq!(explorer.names().collect::<Vec<_>>(), ["even"]);
+    let generator = explorer.parse("[even; 1..3]").unwrap();
+    assert!(
+        generator
+            .samples(100)
+            .flat_map(|item| {
+                item.trim_matches(['[', ']'].as_ref())
+                    .split(", ")
+                    .map(|value| value.parse::<u32>().unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .all(|value| value % 2 == 0)
+    );
+}
+
+#[test]
+fn errors_point_at_the_offending_offset() {
+    let explorer = Explorer::new();
+    assert_eq!(
+        explorer.parse("(u8, foo)").unwrap_err(),
+        Error {
+            offset: 5,
+            message: "unknown generator `foo`".into()
+        }
+    );
+    assert_eq!(explorer.parse("(u8,").unwrap_err().offset, 4);
+    assert_eq!(explorer.parse("0..1.0").unwrap_err().offset, 0);
+    assert_eq!(explorer.parse("[u8; 3..1]").unwrap_err().offset, 5);
+    assert_eq!(explorer.parse("u8 u8").unwrap_err().offset, 3);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn patterns_generate_matching_strings() {
+    let generator = Explorer::new().parse(r#""[a-c]{2}""#).unwrap();
+    assert!(generator.samples(100).all(|item| {
+        let item = item.trim_matches('"');
+        item.len() == 2 && item.chars().all(|letter| ('a'..='c').contains(&letter))
+    }));
+}
@@ -46,3 +46,81 @@ fn shrinked_filter_preserves_inequality() {
     let (left, right) = fail.item.0.clone().unwrap();
     assert_ne!(left, right);
 }
+
+#[test]
+fn or_substitutes_the_fallback_for_filtered_out_items() {
+    let generator = Generate::filter_with(0..100u8, 0, |&value| value % 10 == 0).or(same(1u8));
+    let items = generator.samples(1000).collect::<Vec<_>>();
+    assert!(items.iter().all(|&item| item == 1 || item % 10 == 0));
+    assert!(items.contains(&1));
+}
+
+#[test]
+fn or_shrinks_without_producing_filtered_out_items() {
+    let fail = (
+        Generate::filter(0..1000u16, |value| value % 7 == 0).or(0..7u16),
+        0..10u8,
+    )
+        .check(|(value, _)| value < 100)
+        .unwrap();
+    assert!(fail.item.0 >= 100 && fail.item.0 % 7 == 0);
+}
+
+mod try_convert {
+    use super::*;
+    use core::num::NonZeroUsize;
+
+    #[test]
+    fn converts_every_item_that_converts() {
+        assert!(
+            (0..0x11_0000u32)
+                .try_convert::<char>()
+                .check(|item| item.is_some())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn retries_items_that_fail_to_convert() {
+        assert!(
+            (0..10usize)
+                .try_convert::<NonZeroUsize>()
+                .check(|item| item.map_or(false, |item| item.get() < 10))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn without_retries_surfaces_failures_as_none() {
+        assert!(
+            same(0usize)
+                .try_convert_with::<NonZeroUsize>(0)
+                .samples(10)
+                .all(|item| item.is_none())
+        );
+    }
+
+    #[test]
+    fn gives_up_on_items_that_never_convert() {
+        assert!(
+            same(-1i32)
+                .try_convert::<u8>()
+                .samples(10)
+                .all(|item| item.is_none())
+        );
+    }
+
+    #[test]
+    fn shrinks_the_items_that_convert() {
+        let fail = (1..1000u16)
+            .try_convert::<u8>()
+            .check(|item| item.map_or(true, |item| item < 100))
+            .unwrap();
+        assert_eq!(fail.item, Some(100));
+    }
+
+    #[test]
+    fn preserves_cardinality() {
+        assert_eq!((0..10u32).try_convert::<char>().cardinality(), Some(10));
+    }
+}
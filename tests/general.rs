@@ -1,6 +1,11 @@
 pub mod common;
 use common::*;
 use generate::State;
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
 
 pub fn generate_is_object_safe(
     generator: &dyn Generate<Item = u8, Shrink = u8>,
@@ -17,3 +22,649 @@ pub fn generate_is_object_safe(
     let _ = sampler.sample(1.0);
     let _ = sampler.samples();
 }
+
+#[test]
+fn stratified_covers_every_stratum() {
+    let generator = (0..1000u32).size(|_| 1.0);
+    let mut checker = generator.checker();
+    checker.generate.count = 10;
+    checker.generate.mode = check::Modes::Stratified;
+    let mut strata = checker
+        .checks(|_| true)
+        .map(|result| *result.item() / 100)
+        .collect::<Vec<_>>();
+    strata.sort_unstable();
+    assert_eq!(strata, Iterator::collect::<Vec<_>>(0..10));
+}
+
+#[test]
+fn descending_ramp_starts_with_fully_sized_items() {
+    let generator = u8::generator();
+    let mut checker = generator.checker();
+    checker.generate.count = 100;
+    checker.generate.ramp = check::Ramp::Descending;
+    let sizes = checker
+        .checks(|_| true)
+        .map(|result| result.state().size())
+        .collect::<Vec<_>>();
+    assert_eq!(sizes[0], 1.0);
+    assert!(sizes.windows(2).all(|pair| pair[0] >= pair[1]));
+    assert!(sizes[99] < 1.0);
+}
+
+#[test]
+fn constant_ramp_generates_items_with_the_end_size() {
+    let generator = u8::generator();
+    let mut checker = generator.checker();
+    checker.generate.count = 100;
+    checker.generate.size = (0.0..=0.5).into();
+    checker.generate.ramp = "constant".parse().unwrap();
+    assert!(
+        checker
+            .checks(|_| true)
+            .all(|result| result.state().size() == 0.5)
+    );
+}
+
+#[test]
+fn ramp_rejects_unknown_names() {
+    assert!("sideways".parse::<check::Ramp>().is_err());
+}
+
+#[test]
+fn sanity_reports_no_mutation_for_a_vacuous_property() {
+    assert!((0..10u8).checker().sanity(|_| true).is_empty());
+}
+
+#[test]
+fn sanity_reports_no_mutation_for_a_correct_property_of_a_full_generator() {
+    assert!(
+        u8::generator()
+            .checker()
+            .sanity(|value| u16::from(value) < 256)
+            .is_empty()
+    );
+}
+
+#[test]
+fn sanity_reports_the_mutations_that_fail() {
+    let mut checker = (1..=u8::MAX).checker();
+    checker.generate.seed = 0;
+    let mutations = checker.sanity(|value| value > 0);
+    assert!(mutations.contains(&check::Mutation::Zero));
+}
+
+#[test]
+fn sanity_reports_the_mutations_of_a_correct_property() {
+    let mutations = (10..100u8)
+        .checker()
+        .sanity(|value| (10..100).contains(&value));
+    assert!(mutations.contains(&check::Mutation::Zero));
+    assert!(mutations.contains(&check::Mutation::Maximal));
+}
+
+#[test]
+fn sanity_counts_panics_as_failures() {
+    let mutations = (1..=u8::MAX).checker().sanity(|value| assert!(value > 0));
+    assert!(mutations.contains(&check::Mutation::Zero));
+}
+
+#[test]
+fn saturation_compares_the_cardinality_to_the_count() {
+    let generator = 0..6u8;
+    let mut checker = generator.checker();
+    checker.generate.count = 100;
+    assert_eq!(
+        checker.saturation(),
+        Some(check::Saturation {
+            cardinality: 6,
+            count: 100
+        })
+    );
+    checker.generate.count = 6;
+    assert_eq!(checker.saturation(), None);
+    checker.generate.runs = 10;
+    assert_eq!(checker.saturation(), None);
+    assert_eq!(same(1u8).checker().saturation(), None);
+    assert_eq!(u64::generator().checker().saturation(), None);
+}
+
+#[test]
+fn grid_prefixes_cover_the_value_space_evenly() {
+    let generator = (0..1024u32).size(|_| 1.0);
+    let items = generator.grid(64).collect::<Vec<_>>();
+    for length in [2, 4, 8, 16, 32, 64] {
+        let mut cells = items[..length]
+            .iter()
+            .map(|item| *item as usize * length / 1024)
+            .collect::<Vec<_>>();
+        cells.sort_unstable();
+        assert_eq!(cells, Iterator::collect::<Vec<_>>(0..length));
+    }
+}
+
+#[test]
+fn write_to_dir_writes_reproducible_fixtures() {
+    use checkito::sample::format;
+    use std::{env, fs};
+
+    let path = env::temp_dir().join(format!("checkito-fixtures-{}", std::process::id()));
+    let generator = (0..1000u16, letter());
+    let mut sampler = generator.sampler();
+    sampler.count = 12;
+    sampler.write_to_dir(&path, format::Debug).unwrap();
+    let manifest = fs::read_to_string(path.join("manifest.json")).unwrap();
+    assert!(manifest.contains(&format!("\"seed\": {},", sampler.seed)));
+    assert!(manifest.contains("\"count\": 12,"));
+    assert!(manifest.contains("\"00.txt\", \"01.txt\""));
+    for (index, item) in sampler.samples().enumerate() {
+        let file = fs::read_to_string(path.join(format!("{index:02}.txt"))).unwrap();
+        assert_eq!(file, format!("{item:#?}\n"));
+    }
+    fs::remove_dir_all(&path).unwrap();
+}
+
+#[test]
+fn record_fields_depend_on_previous_fields() {
+    let generator = record! {
+        low in 0..100u8,
+        high in low..=100,
+        values in (low..=high).collect_with::<_, Vec<_>>(0..10usize),
+    };
+    for (low, high, values) in generator.samples(100) {
+        assert!(low <= high);
+        assert!(values.iter().all(|&value| low <= value && value <= high));
+    }
+}
+
+#[test]
+fn progress_is_reported_until_the_end() {
+    use std::cell::Cell;
+
+    thread_local! { static CALLS: Cell<(usize, usize, usize, usize)> = const { Cell::new((0, 0, 0, 0)) }; }
+    fn progress(done: usize, total: usize, passes: usize, fails: usize, _: Duration) {
+        assert!(done <= total);
+        assert!(passes + fails <= done);
+        CALLS.with(|calls| calls.set((calls.get().0 + 1, done, passes, fails)));
+    }
+
+    let generator = u8::generator();
+    let mut checker = generator.checker();
+    checker.generate.count = 1000;
+    checker.generate.progress = Some(progress);
+    assert_eq!(checker.checks(|_| true).count(), 1000);
+    assert_eq!(CALLS.with(Cell::get), (100, 1000, 1000, 0));
+
+    CALLS.with(|calls| calls.set((0, 0, 0, 0)));
+    checker.generate.count = 100;
+    let mut calls = 0;
+    let fail = checker
+        .checks(|_| {
+            calls += 1;
+            if calls % 4 == 0 {
+                skip!();
+            }
+            calls < 50
+        })
+        .last();
+    assert!(matches!(fail, Some(check::Result::Fail(_))));
+    // 12 of the first 50 items are skipped and the 50th fails.
+    assert_eq!(CALLS.with(Cell::get), (50, 50, 37, 1));
+
+    // Generation resumes after each failure, which are not counted as passes.
+    CALLS.with(|calls| calls.set((0, 0, 0, 0)));
+    checker.generate.seed = 7;
+    let failures = checker.failures(usize::MAX, |value| value % 10 != 0);
+    assert_eq!(failures.count(), 1);
+    let (_, done, passes, fails) = CALLS.with(Cell::get);
+    assert_eq!(done, 100);
+    assert_eq!(passes + fails, 100);
+    assert!(fails > 1);
+}
+
+#[test]
+fn collected_items_are_allocated_once_with_an_exact_size() {
+    let generator = collect::<_, _, Vec<u16>>(0..=u16::MAX, 1000..=2000usize, Some(0));
+    for mut shrinker in shrinker(generator).samples(10) {
+        let item = shrinker.item();
+        assert!(item.len() >= 1000);
+        assert_eq!(item.capacity(), item.len());
+        let item = shrinker.shrink().unwrap().item();
+        assert_eq!(item.capacity(), item.len());
+    }
+}
+
+#[test]
+fn dampen_lengths_preserves_item_sizes() {
+    let generator = dampen_lengths(
+        (u32::generator(), u32::generator().collect::<Vec<_>>()),
+        1.0,
+        0,
+        0,
+    );
+    let samples = generator.samples(100).collect::<Vec<_>>();
+    assert!(samples.iter().all(|(_, values)| values.is_empty()));
+    assert!(samples.iter().any(|&(value, _)| value > 1000));
+
+    let generator = dampen(
+        (u32::generator(), u32::generator().collect::<Vec<_>>()),
+        1.0,
+        0,
+        0,
+    );
+    let samples = generator.samples(100).collect::<Vec<_>>();
+    assert!(samples.iter().all(|(_, values)| values.is_empty()));
+}
+
+#[test]
+fn dyn_generators_can_be_stored_by_name() {
+    use checkito::boxed::{Boxed, DynGenerate};
+    use std::collections::HashMap;
+
+    let mut generators = HashMap::<&str, Box<dyn DynGenerate<u8>>>::new();
+    generators.insert("small", Box::new(0..10u8));
+    generators.insert(
+        "large",
+        Box::new(u8::generator().map(|value| value.max(200))),
+    );
+    for value in generators["small"].samples(100) {
+        assert!(value < 10);
+    }
+    for value in generators["large"].samples(100) {
+        assert!(value >= 200);
+    }
+    let boxed = Boxed::from(generators.remove("small").unwrap());
+    assert!(boxed.downcast::<core::ops::Range<u8>>().is_ok());
+}
+
+#[test]
+fn runs_repeat_with_different_seeds_and_report_every_failure() {
+    let generator = u16::generator();
+    let mut checker = generator.checker();
+    checker.generate.count = 100;
+    checker.generate.runs = 3;
+    checker.generate.items = false;
+    checker.shrink.items = false;
+    checker.shrink.errors = false;
+    let fails = checker
+        .checks(|value| value < 1000)
+        .filter_map(|result| result.fail(false))
+        .collect::<Vec<_>>();
+    assert_eq!(fails.len(), 3);
+    assert!(
+        fails
+            .windows(2)
+            .all(|pair| pair[0].seed() != pair[1].seed())
+    );
+    assert!(fails.iter().all(|fail| fail.item == 1000));
+
+    checker.generate.items = true;
+    assert_eq!(checker.checks(|_| true).count(), 300);
+}
+
+#[test]
+fn pinned_cases_are_yielded_first() {
+    let items = (100..200u16)
+        .pinned([1, 2, 3])
+        .samples(10)
+        .collect::<Vec<_>>();
+    assert_eq!(items[..3], [1, 2, 3]);
+    assert!(items[3..].iter().all(|item| (100..200).contains(item)));
+}
+
+#[test]
+fn pinned_cases_are_reported_without_shrinking() {
+    let generator = (0..10u16).pinned([1234]);
+    let fail = generator.check(|item| item < 1000).unwrap();
+    assert_eq!(fail.item, 1234);
+    assert_eq!(fail.generates, 0);
+    assert_eq!(fail.shrinks, 0);
+}
+
+#[test]
+fn pinned_cases_are_shrunk_by_their_generator() {
+    let generator = (0..100u8).pinned([90]);
+    let fail = generator.check(|item| item < 50).unwrap();
+    assert_eq!(fail.item, 50);
+    assert_eq!(fail.generates, 0);
+}
+
+#[test]
+fn pinned_cases_are_yielded_once_per_item() {
+    let generator = (100..200u16).pinned([1]).collect_with::<_, Vec<_>>(10usize);
+    let items = generator.sample(0.0);
+    assert_eq!(items[0], 1);
+    assert!(items[1..].iter().all(|item| (100..200).contains(item)));
+    let pinned = (100..200u16).pinned([1]);
+    assert_eq!((pinned.clone(), pinned).sample(0.0), (1, 1));
+}
+
+#[test]
+fn lazy_once_constructs_the_generator_once() {
+    let constructions = AtomicUsize::new(0);
+    let generator = lazy_once(|| {
+        constructions.fetch_add(1, Ordering::Relaxed);
+        0..100u8
+    });
+    thread::scope(|scope| {
+        for _ in 0..8 {
+            let generator = generator.clone();
+            scope.spawn(move || assert!(generator.samples(100).all(|value| value < 100)));
+        }
+    });
+    assert!(generator.check(|value| value < 100).is_none());
+    assert_eq!(constructions.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn shared_interns_equal_items_and_shrinks_the_underlying_item() {
+    let generator = (0..4u8, 0..4u8).shared();
+    let items = generator.samples(100).collect::<Vec<_>>();
+    for left in items.iter() {
+        for right in items.iter() {
+            assert_eq!(left == right, std::sync::Arc::ptr_eq(left, right));
+        }
+    }
+
+    let fail = (0..100u8).shared().check(|item| *item < 50).unwrap();
+    assert_eq!(*fail.item, 50);
+}
+
+#[test]
+fn shared_does_not_retain_unused_items() {
+    let generator = (0..u64::MAX).shared();
+    let items = generator.samples(1000).collect::<Vec<_>>();
+    let weaks = items
+        .iter()
+        .map(std::sync::Arc::downgrade)
+        .collect::<Vec<_>>();
+    drop(items);
+    assert!(weaks.iter().all(|weak| weak.upgrade().is_none()));
+}
+
+#[test]
+fn inspect_sees_generated_items_and_preserves_shrinking() {
+    let items = std::cell::RefCell::new(Vec::new());
+    let generator = Generate::inspect(0..100u8, |item| items.borrow_mut().push(*item));
+    let fail = generator.check(|item| item < 50).unwrap();
+    assert_eq!(fail.item, 50);
+    assert_eq!(items.borrow().len(), fail.generates + 1);
+    assert!(items.borrow().last().map_or(false, |&item| item >= 50));
+}
+
+#[test]
+fn assert_deterministic_accepts_pure_generators() {
+    let generator = (Vec::<u8>::generator(), 0..100u16).assert_deterministic();
+    let fail = generator.check(|(items, _)| items.len() < 5).unwrap();
+    assert_eq!(fail.item.0.len(), 5);
+}
+
+#[test]
+#[should_panic(expected = "is not deterministic")]
+fn assert_deterministic_panics_on_impure_generators() {
+    let counter = AtomicUsize::new(0);
+    let generator = Generate::map(0..10usize, |item| {
+        item + counter.fetch_add(1, Ordering::Relaxed)
+    });
+    for _ in generator.assert_deterministic().samples(10) {}
+}
+
+#[test]
+fn trace_with_sees_the_state() {
+    let sizes = std::cell::RefCell::new(Vec::new());
+    let generator = (0..100u8).trace_with(|_, state| sizes.borrow_mut().push(state.size()));
+    assert_eq!(generator.samples(10).collect::<Vec<_>>().len(), 10);
+    let sizes = sizes.into_inner();
+    assert_eq!(sizes.len(), 10);
+    assert!(sizes.iter().all(|size| (0.0..=1.0).contains(size)));
+}
+
+#[test]
+fn count_with_shrinks_lengths_within_the_count_domain() {
+    let generator =
+        u8::generator().count_with::<_, Vec<_>>(Generate::map(0..50usize, |count| count * 2));
+    let fail = generator.check(|items| items.len() < 10).unwrap();
+    assert_eq!(fail.item, vec![0; 10]);
+
+    let generator = u8::generator().count_with::<_, Vec<_>>(10..20usize);
+    for _ in 0..10 {
+        let fail = generator.check(|_| false).unwrap();
+        assert_eq!(fail.item, vec![0; 10]);
+    }
+}
+
+#[test]
+fn checker_builders_configure_inline() {
+    let generator = u8::generator();
+    let checker = generator
+        .checker()
+        .with_seed(7)
+        .with_count(10)
+        .with_sizes(0.5..1.0)
+        .with_shrink_count(3)
+        .with_strategy(check::Strategy::Exhaustive);
+    assert_eq!(checker.generate.seed, 7);
+    assert_eq!(checker.generate.count, 10);
+    assert_eq!(checker.generate.size.start(), 0.5);
+    assert_eq!(checker.shrink.count, 3);
+    assert_eq!(checker.shrink.strategy, check::Strategy::Exhaustive);
+    let Some(check::Result::Fail(fail)) = checker.checks(|_| false).last() else {
+        panic!("expected a failure");
+    };
+    assert!(fail.shrinks <= 3);
+    assert_eq!(fail.seed(), 7);
+}
+
+#[test]
+fn durations_are_recorded_per_item_and_totaled_on_failure() {
+    let generator = 0..10u8;
+    let checker = generator.checker().with_count(100);
+    let results = checker
+        .checks(|value| {
+            thread::sleep(Duration::from_millis(1));
+            value < 5
+        })
+        .collect::<Vec<_>>();
+    let (last, items) = results.split_last().expect("expected at least one result");
+    let check::Result::Fail(fail) = last else {
+        panic!("expected a failure");
+    };
+    let durations = items
+        .iter()
+        .map(|result| match result {
+            check::Result::Pass(pass) | check::Result::Shrink(pass) => pass.duration,
+            check::Result::Shrunk(fail) | check::Result::Fail(fail) => fail.duration,
+            check::Result::Flaky(flaky) => flaky.fail.duration,
+            check::Result::Skip(_) => panic!("expected no skip"),
+        })
+        .collect::<Vec<_>>();
+    assert!(
+        durations
+            .iter()
+            .all(|&duration| duration >= Duration::from_millis(1))
+    );
+    assert!(fail.duration >= durations.iter().sum());
+}
+
+#[test]
+fn reproduce_replays_the_failing_run() {
+    let generator = (0..1_000_000u32, 0..1_000_000u32).pinned([(3, 1000)]);
+    let property = |(left, right): (u32, u32)| left % 7 != 3 || right < 1000 || left == 3;
+    for (ramp, mode) in [
+        (check::Ramp::Ascending, check::Modes::Random),
+        (check::Ramp::Descending, check::Modes::Stratified),
+        (check::Ramp::Constant, check::Modes::Stratified),
+    ] {
+        let mut checker = generator.checker().with_count(100);
+        checker.generate.runs = 3;
+        checker.generate.ramp = ramp;
+        checker.generate.mode = mode;
+        checker.generate.items = false;
+        let fails = checker
+            .checks(property)
+            .filter_map(|result| result.fail(false))
+            .collect::<Vec<_>>();
+        assert!(!fails.is_empty());
+        for fail in fails {
+            let command = fail.reproduce("module::test");
+            assert!(command.ends_with("cargo test module::test -- --exact"));
+            let variable = |key: &str| {
+                command
+                    .split_whitespace()
+                    .find_map(|part| part.strip_prefix(key)?.strip_prefix('='))
+                    .unwrap()
+                    .to_string()
+            };
+            checker.generate.seed = variable("CHECKITO_GENERATE_SEED__module__test")
+                .parse()
+                .unwrap();
+            checker.generate.runs = variable("CHECKITO_GENERATE_RUNS__module__test")
+                .parse()
+                .unwrap();
+            let Some(check::Result::Fail(replayed)) = checker.checks(property).last() else {
+                panic!("expected the failure to be replayed");
+            };
+            assert_eq!(replayed.item, fail.item);
+            assert_eq!(replayed.generates, fail.generates);
+        }
+    }
+}
+
+#[test]
+fn draws_are_reproducible_and_shrink() {
+    let fail = draw::Draws
+        .check(|draw| draw::scope(draw, || gen!(0..1000u32) < 100))
+        .unwrap();
+    let value = draw::scope(fail.item.clone(), || gen!(0..1000u32));
+    assert!(value >= 100);
+    assert_eq!(draw::scope(fail.item, || gen!(0..1000u32)), value);
+}
+
+#[test]
+#[should_panic]
+fn draw_outside_of_a_scope_panics() {
+    gen!(0..10u8);
+}
+
+#[test]
+fn failures_yields_distinct_minimal_counterexamples() {
+    let generator = (bool::generator().keep(), 0..100u8);
+    let checker = generator.checker();
+    let check = |(flag, value): (bool, u8)| value < if flag { 50 } else { 10 };
+    let mut items = checker
+        .failures(5, check)
+        .map(|fail| fail.item)
+        .collect::<Vec<_>>();
+    items.sort_unstable();
+    assert_eq!(items, [(false, 10), (true, 50)]);
+    assert_eq!(checker.failures(1, check).count(), 1);
+}
+
+#[test]
+fn triage_buckets_failures_by_normalized_message() {
+    let generator = (Generate::keep(0..3u8), Generate::keep(0..1000u16));
+    let buckets = generator
+        .checker()
+        .failures(20, |(kind, value)| match kind {
+            0 => panic!("index {value} out of bounds"),
+            1 => panic!("division by zero"),
+            _ => {}
+        })
+        .triage();
+    let mut keys = buckets
+        .iter()
+        .map(|bucket| bucket.key.as_str())
+        .collect::<Vec<_>>();
+    keys.sort_unstable();
+    assert_eq!(keys, ["division by zero", "index # out of bounds"]);
+    assert_eq!(buckets.iter().map(|bucket| bucket.count).sum::<usize>(), 20);
+    assert!(buckets[0].count >= buckets[1].count);
+    for bucket in buckets {
+        assert_eq!(
+            bucket.fail.item.0,
+            u8::from(bucket.key.starts_with("division"))
+        );
+    }
+}
+
+#[test]
+fn keep_until_last_shrinks_kept_components_in_a_final_pass() {
+    let generator = (Generate::keep_until_last(0..10u8), 100..1000u16);
+    let fail = generator
+        .check(|(kind, value)| kind == 0 || value < 500)
+        .unwrap();
+    // The other components are minimal before the kept one shrinks.
+    assert_eq!(fail.item, (1, 500));
+}
+
+#[test]
+fn keep_until_last_releases_nested_components() {
+    let generator = (
+        vec![Generate::keep_until_last(10..30u8); 3],
+        Generate::keep_until_last(bool::generator()).map(u8::from),
+    );
+    let fail = generator
+        .check(|(values, _)| values.iter().sum::<u8>() < 40)
+        .unwrap();
+    assert_eq!(fail.item.0.iter().sum::<u8>(), 40);
+    assert_eq!(fail.item.1, 0);
+}
+
+#[test]
+fn keep_until_last_releases_through_adapters() {
+    let generator = (
+        Generate::keep_until_last(50..100u8).filter(|_| true),
+        Generate::keep_until_last(50..100u8).convert::<u16>(),
+        sorted(Generate::keep_until_last(50..100u8), 2usize),
+    );
+    let fail = generator
+        .check(|(left, right, values)| {
+            left < Some(70) || right < 70 || values.iter().any(|&value| value < 70)
+        })
+        .unwrap();
+    assert_eq!(fail.item, (Some(70), 70, vec![70, 70]));
+}
+
+#[test]
+fn keep_until_last_keeps_components_without_a_failure() {
+    let fail = Generate::keep_until_last(0..100u8)
+        .check(|value| value < 50)
+        .unwrap();
+    assert_eq!(fail.item, 50);
+}
+
+#[test]
+fn cancelled_checks_are_not_passes() {
+    let cancel = check::Cancel::new();
+    cancel.cancel();
+    let checker = (0..100u8).checker();
+    let mut checks = checker.checks(|value| value < 10).cancel_on(cancel);
+    assert!(checks.next().is_none());
+    assert!(checks.cancelled());
+}
+
+#[test]
+fn uncancelled_checks_are_not_cancelled() {
+    let checker = (0..100u8).checker();
+    let mut checks = checker
+        .checks(|value| value < 200)
+        .cancel_on(check::Cancel::new());
+    assert_eq!(checks.by_ref().count(), check::COUNT);
+    assert!(!checks.cancelled());
+}
+
+#[test]
+#[should_panic]
+fn cancelled_check_fails() {
+    let cancel = check::Cancel::new();
+    cancel.cancel();
+    check::help::default(
+        0..100u8,
+        |checker| checker.generate.cancel = Some(cancel),
+        |value| value < 200,
+        false,
+        false,
+        true,
+        "cancelled_check_fails",
+    );
+}
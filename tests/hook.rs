@@ -0,0 +1,56 @@
+//! The panic hook is process-wide, so these tests live in their own binary.
+
+pub mod common;
+use common::*;
+use std::{
+    panic::{self, catch_unwind},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+static PANICS: AtomicUsize = AtomicUsize::new(0);
+
+fn sanity(check: impl Fn(u8)) -> Vec<checkito::check::Mutation> {
+    let generator = 0..100u8;
+    let mut checker = generator.checker();
+    checker.generate.count = 32;
+    checker.sanity(check)
+}
+
+fn nested(value: u8) {
+    let mutations = sanity(|inner| assert!(inner < 5));
+    assert!(!mutations.is_empty());
+    assert!(value < 100);
+}
+
+#[test]
+fn concurrent_and_nested_checks_preserve_the_hook() {
+    panic::set_hook(Box::new(|_| {
+        PANICS.fetch_add(1, Ordering::Relaxed);
+    }));
+    let workers = Iterator::map(0..8, |_| {
+        thread::spawn(|| {
+            for _ in 0..10 {
+                let mutations = sanity(nested);
+                assert!(!mutations.is_empty());
+            }
+        })
+    })
+    .collect::<Vec<_>>();
+    // A panic outside of a check reaches the hook while checks run on other threads.
+    thread::spawn(|| panic!("outside")).join().unwrap_err();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    // Checks that panic on the worker threads of a pool are silenced as well.
+    #[cfg(feature = "parallel")]
+    {
+        let result = (0..1000u16)
+            .checker()
+            .parallel(Default::default(), |value| assert!(value < 500));
+        assert_eq!(result.unwrap().fail(true).unwrap().item, 500);
+    }
+    assert!(catch_unwind(|| panic!("after")).is_err());
+    let _ = panic::take_hook();
+    assert_eq!(PANICS.load(Ordering::Relaxed), 2);
+}
@@ -109,7 +109,7 @@ mod range {
                                 (0 as $t..=value, value..=value)
                             }
                         })
-                        .flat_map(|(low, high)| (low, high, shrinker((low..=high))))
+                        .flat_map(|(low, high)| (low, high, shrinker(low..=high)))
                         .check(|(low, high, mut outer)| {
                             while let Some(inner) = outer.shrink() {
                                 outer = inner;
@@ -213,3 +213,214 @@ mod check {
         assert!(value <= zero);
     }
 }
+
+mod floating {
+    use super::*;
+
+    macro_rules! tests {
+        ($t:ident) => {
+            mod $t {
+                use super::*;
+
+                #[test]
+                fn finite_is_finite() {
+                    assert!(finite::<$t>().samples(1000).all(|value| value.is_finite()));
+                }
+
+                #[test]
+                fn normal_is_normal() {
+                    assert!(normal::<$t>().samples(1000).all(|value| value.is_normal()));
+                }
+
+                #[test]
+                fn normal_shrinks_to_normal() {
+                    let fail = normal::<$t>().check(|value| value.abs() < 1.0).unwrap();
+                    assert!(fail.item.is_normal());
+                    assert!(fail.item.abs() >= 1.0);
+                }
+
+                #[test]
+                fn subnormal_is_subnormal() {
+                    assert!(subnormal::<$t>().samples(1000).all(|value| value.is_subnormal()));
+                }
+
+                #[test]
+                fn subnormal_shrinks_to_subnormal() {
+                    let fail = subnormal::<$t>().check(|_| false).unwrap();
+                    assert!(fail.item.is_subnormal());
+                }
+
+                #[test]
+                fn ulp_near_is_near() {
+                    let value = 1.0 as $t;
+                    let low = value - value * $t::EPSILON * 2.0;
+                    let high = value + value * $t::EPSILON * 4.0;
+                    assert!(ulp_near(value, 4).samples(1000).all(|item| item >= low && item <= high));
+                }
+
+                #[test]
+                fn ulp_near_is_bounded() {
+                    assert!(ulp_near($t::MAX, 8).samples(100).all(|item| item.is_finite()));
+                    assert!(ulp_near($t::MIN, 8).samples(100).all(|item| item.is_finite()));
+                }
+
+                #[test]
+                fn ulp_near_crosses_zero() {
+                    let items = ulp_near(0.0 as $t, 2).samples(1000).collect::<Vec<_>>();
+                    assert!(items.iter().all(|item| item.abs() <= $t::from_bits(2)));
+                    assert!(items.iter().any(|item| item.is_sign_negative()));
+                }
+
+                #[test]
+                fn ulp_near_shrinks_to_value() {
+                    let fail = ulp_near(1.0 as $t, 1000).check(|_| false).unwrap();
+                    assert_eq!(fail.item, 1.0 as $t);
+                }
+            }
+        };
+        ($($t:ident),+) => { $(tests!($t);)* };
+    }
+
+    tests!(f32, f64);
+}
+
+mod except {
+    use super::*;
+
+    macro_rules! tests {
+        ($t:ident) => {
+            mod $t {
+                use super::*;
+
+                #[test]
+                fn except_removes_range() {
+                    assert!(
+                        (0..100 as $t)
+                            .except(10..20)
+                            .samples(1000)
+                            .all(|item| item < 100 && !(10..20).contains(&item))
+                    );
+                }
+
+                #[test]
+                fn excluding_removes_values() {
+                    assert!(
+                        excluding(0..=10 as $t, &[0, 5, 10])
+                            .samples(1000)
+                            .all(|item| item != 0 && item != 5 && item != 10)
+                    );
+                }
+
+                #[test]
+                fn except_has_exact_cardinality() {
+                    assert_eq!((0..100 as $t).except(10..20).cardinality(), Some(90));
+                    assert_eq!(excluding(0..=10 as $t, &[0, 5, 5]).cardinality(), Some(9));
+                    assert_eq!((0..100 as $t).except(50..50).cardinality(), Some(100));
+                    assert_eq!((..).except(..=$t::MAX).cardinality(), Some(0));
+                }
+
+                #[test]
+                fn except_is_constant_with_one_value() {
+                    assert!((0..=1 as $t).except(1..).constant());
+                    assert!(!(0..=2 as $t).except(1..2).constant());
+                }
+
+                #[test]
+                fn except_shrinks_within_allowed() {
+                    let fail = (0..100 as $t).except(..20).check(|_| false).unwrap();
+                    assert_eq!(fail.item, 20);
+                    let fail = excluding(0..100 as $t, &[0, 1]).check(|_| false).unwrap();
+                    assert_eq!(fail.item, 2);
+                }
+
+                #[test]
+                fn except_full_range() {
+                    assert!(
+                        (..)
+                            .except($t::MIN..=0)
+                            .samples(1000)
+                            .all(|item: $t| item > 0)
+                    );
+                }
+            }
+        };
+        ($($t:ident),+) => { $(tests!($t);)* };
+    }
+
+    tests!(
+        u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+    );
+
+    #[test]
+    #[should_panic]
+    fn everything_excluded_panics() {
+        (0..=1u8).except(..).samples(1).for_each(drop);
+    }
+
+    #[test]
+    fn except_crosses_zero() {
+        let fail = (-100..100i32).except(-1..=1).check(|_| false).unwrap();
+        assert!(fail.item == -2 || fail.item == 2);
+    }
+}
+
+mod toward {
+    use super::*;
+
+    macro_rules! tests {
+        ($t:ident) => {
+            mod $t {
+                use super::*;
+
+                #[test]
+                fn shrinks_to_the_target() {
+                    let fail = (10 as $t..100 as $t).shrink_toward(50 as $t).check(|_| false).unwrap();
+                    assert_eq!(fail.item, 50 as $t);
+                }
+
+                #[test]
+                fn shrinks_to_the_closest_failing_item_above_the_target() {
+                    let fail = shrink_toward(0 as $t..100 as $t, 20 as $t)
+                        .check(|item| item < 60 as $t)
+                        .unwrap();
+                    assert_eq!(fail.item, 60 as $t);
+                }
+
+                #[test]
+                fn shrinks_to_the_closest_failing_item_below_the_target() {
+                    let fail = shrink_toward(0 as $t..100 as $t, 80 as $t)
+                        .check(|item| item > 30 as $t)
+                        .unwrap();
+                    assert_eq!(fail.item, 30 as $t);
+                }
+
+                #[test]
+                fn clamps_the_target_into_the_range() {
+                    let fail = (10 as $t..=20 as $t).shrink_toward(100 as $t).check(|_| false).unwrap();
+                    assert_eq!(fail.item, 20 as $t);
+                }
+
+                #[test]
+                fn generates_within_the_range() {
+                    let generator = (10 as $t..=20 as $t).shrink_toward(15 as $t);
+                    assert!(generator.samples(1000).all(|item| (10 as $t..=20 as $t).contains(&item)));
+                    assert!((5 as $t..=5 as $t).shrink_toward(0 as $t).constant());
+                }
+            }
+        };
+        ($($t:ident),+) => { $(tests!($t);)* };
+    }
+
+    tests!(
+        u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+    );
+
+    #[test]
+    fn shrinks_across_zero_toward_a_negative_target() {
+        let fail = (i64::MIN..=i64::MAX)
+            .shrink_toward(-1000)
+            .check(|item| item < 5000)
+            .unwrap();
+        assert_eq!(fail.item, 5000);
+    }
+}
@@ -0,0 +1,41 @@
+pub mod common;
+use common::*;
+use std::panic::panic_any;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Error(usize);
+
+#[test]
+fn preserves_string_message() {
+    let fail = (0..10usize).check(|value| assert!(value > 100)).unwrap();
+    assert!(matches!(fail.cause, Cause::Panic(Some(_), _)));
+    assert!(fail.payload::<String>().is_some() || fail.payload::<&str>().is_some());
+}
+
+#[test]
+fn preserves_structured_payload() {
+    let fail = (0..10usize)
+        .check(|value| {
+            if value < 100 {
+                panic_any(Error(value));
+            }
+        })
+        .unwrap();
+    assert!(matches!(fail.cause, Cause::Panic(None, _)));
+    assert_eq!(fail.message(), "panicked");
+    assert_eq!(fail.payload::<Error>(), Some(Error(0)));
+    assert_eq!(fail.payload::<String>(), None);
+}
+
+#[test]
+fn cloned_cause_is_equal() {
+    let fail = (0..10usize)
+        .check(|value| {
+            if value < 100 {
+                panic_any(Error(1));
+            }
+        })
+        .unwrap();
+    assert_eq!(fail.cause, fail.cause.clone());
+    assert!(fail.cause.payload().unwrap().is::<Error>());
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "parallel")]
+
+pub mod common;
+use checkito::check::parallel::Config;
+use common::*;
+use std::{
+    collections::HashSet,
+    sync::{
+        Mutex, PoisonError,
+        atomic::{AtomicUsize, Ordering},
+        mpsc::channel,
+    },
+    thread,
+};
+
+#[test]
+fn parallel_shrinks_the_failing_item() {
+    let fail = u16::generator()
+        .checker()
+        .parallel(Config::default().with_threads(3), |value| value < 1000)
+        .unwrap()
+        .fail(true)
+        .unwrap();
+    assert_eq!(fail.item, 1000);
+}
+
+#[test]
+fn parallel_without_failure_produces_nothing() {
+    let result = u16::generator()
+        .checker()
+        .parallel(Config::default(), |_| true);
+    assert!(result.is_none());
+}
+
+#[test]
+fn nested_parallel_checks_do_not_deadlock() {
+    let config = Config::default().with_threads(1);
+    let fail = (0..100u8)
+        .checker()
+        .parallel(config, move |value| {
+            let inner = (0..100u8).checker().parallel(config, |inner| inner < 50);
+            inner.is_some() && value < 10
+        })
+        .unwrap()
+        .fail(true)
+        .unwrap();
+    assert_eq!(fail.item, 10);
+}
+
+#[test]
+fn parallel_reuses_its_worker_threads() {
+    static THREADS: Mutex<Option<HashSet<thread::ThreadId>>> = Mutex::new(None);
+    fn record(_: u8) -> bool {
+        THREADS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_or_insert_with(HashSet::new)
+            .insert(thread::current().id());
+        true
+    }
+
+    let config = Config::default().with_threads(2).with_stack(1 << 20);
+    let (started, start) = channel();
+    let (release, released) = channel();
+    let released = Mutex::new(released);
+    // A running check keeps the pool alive and occupies one of its workers.
+    let blocker = thread::spawn(move || {
+        u8::generator()
+            .checker()
+            .with_count(1)
+            .parallel(config, move |_| {
+                started.send(()).unwrap();
+                released.lock().unwrap().recv().unwrap();
+                true
+            })
+    });
+    start.recv().unwrap();
+    for _ in 0..10 {
+        assert!(u8::generator().checker().parallel(config, record).is_none());
+    }
+    release.send(()).unwrap();
+    assert!(blocker.join().unwrap().is_none());
+    let threads = THREADS.lock().unwrap_or_else(PoisonError::into_inner);
+    // The checks share the pool of the running check, so only its free worker runs them.
+    assert_eq!(threads.as_ref().map_or(0, HashSet::len), 1);
+}
+
+#[test]
+fn parallel_releases_its_worker_threads() {
+    static THREADS: Mutex<Option<HashSet<thread::ThreadId>>> = Mutex::new(None);
+    static EXITED: AtomicUsize = AtomicUsize::new(0);
+    struct Exit;
+    impl Drop for Exit {
+        fn drop(&mut self) {
+            EXITED.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+    thread_local! { static EXIT: Exit = const { Exit }; }
+    fn record(_: u8) -> bool {
+        EXIT.with(|_| {});
+        THREADS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_or_insert_with(HashSet::new)
+            .insert(thread::current().id());
+        true
+    }
+
+    let config = Config::default().with_threads(2).with_stack(2 << 20);
+    assert!(u8::generator().checker().parallel(config, record).is_none());
+    let threads = THREADS.lock().unwrap_or_else(PoisonError::into_inner);
+    // The workers of the pool are joined when the last check that uses it ends.
+    assert_eq!(
+        EXITED.load(Ordering::Relaxed),
+        threads.as_ref().map_or(0, HashSet::len)
+    );
+}
+
+#[test]
+#[should_panic(expected = "pinning worker threads to cores is not supported")]
+fn parallel_rejects_pinned_threads() {
+    let config = Config::default().with_pin(true);
+    u8::generator().checker().parallel(config, |_| true);
+}
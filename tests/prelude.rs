@@ -16,6 +16,7 @@ macro_rules! generators {
                 is_generator::<$or>(any(($($values,)*)));
                 is_generator::<$type>(unify(any(($($values,)*))));
                 is_generator::<$type>(map($value, |value| value));
+                is_generator::<$type>(map_invertible($value, |value| value, |value| Some(*value)));
                 is_generator::<$type>(flat_map($value, same));
                 is_generator::<$type>(flatten(same(same($value))));
                 is_generator::<Option<$type>>(filter($value, |_| true, 1));
@@ -23,10 +24,19 @@ macro_rules! generators {
                 is_generator::<$type>(boxed(Box::new($value)));
                 is_generator::<[$type; 1]>(array::<_, 1>($value));
                 is_generator::<Vec<$type>>(collect($value, 1usize, None));
+                is_generator::<Vec<$type>>(count_with($value, 1usize));
+                is_generator::<Vec<$type>>(set_of($value, 1usize));
+                is_generator::<Vec<($type, $type)>>(map_of($value, $value, 1usize));
                 is_generator::<$type>(size($value, |_| 1.0));
                 is_generator::<$type>(dampen($value, 1.0, 1, 1));
+                is_generator::<$type>(dampen_lengths($value, 1.0, 1, 1));
                 is_generator::<$type>(keep($value));
+                is_generator::<$type>(inspect($value, |_| {}));
+                is_generator::<$type>(trace($value, |_, _| {}));
+                is_generator::<$type>(lazy_once(|| $value));
+                is_generator::<$type>(pinned($value, [$value]));
                 is_generator::<Option<$type>>(convert($value));
+                is_generator::<Option<Option<$type>>>(try_convert($value, 1));
                 is_generator::<same::Same<$type>>(shrinker(same($value)));
                 is_generator::<keep::Keep<$type>>(shrinker(keep($value)));
                 is_generator::<convert::Convert<$type, $type>>(shrinker(convert($value)));
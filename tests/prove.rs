@@ -0,0 +1,162 @@
+pub mod common;
+use checkito::prove::{Differential, Nested};
+use common::*;
+use orn::Or2;
+
+#[test]
+fn option_none_is_disproved() {
+    assert_eq!(None::<()>.prove(), Err(None));
+    assert_eq!(Some(()).prove(), Ok(()));
+    assert_eq!(Some(false).prove(), Err(Some(())));
+}
+
+#[test]
+fn result_is_proven_by_itself() {
+    assert_eq!(Ok::<_, u8>(false).prove(), Ok(false));
+    assert_eq!(Ok::<_, u8>("proof").prove(), Ok("proof"));
+    assert_eq!(Err::<bool, _>(1u8).prove(), Err(1));
+}
+
+#[test]
+fn nested_result_of_bool_is_proven_by_its_value() {
+    assert_eq!(Nested(Ok::<_, u8>(true)).prove(), Ok(()));
+    assert_eq!(Nested(Ok::<_, u8>(false)).prove(), Err(Or2::T1(())));
+    assert_eq!(Nested(Err::<bool, _>(1u8)).prove(), Err(Or2::T0(1)));
+}
+
+#[test]
+fn nested_results_compose() {
+    let result = Nested(Ok::<_, u8>(Nested(Ok::<_, u16>(Some(false)))));
+    assert_eq!(result.prove(), Err(Or2::T1(Or2::T1(Some(())))));
+}
+
+#[test]
+fn all_proves_every_clause() {
+    assert!(all!(true, (), Some(true)).prove().is_ok());
+}
+
+#[test]
+fn all_identifies_the_failed_clause() {
+    let fail = (0..100u8)
+        .check(|value| all!(value < 200, value < 10, value == value))
+        .unwrap();
+    assert_eq!(fail.item, 10);
+    let Cause::Disprove(clause) = fail.cause else {
+        panic!("expected a disproof");
+    };
+    assert_eq!(clause.index, 1);
+    assert_eq!(clause.expression, "value < 10");
+}
+
+#[test]
+fn all_stops_at_the_first_failed_clause() {
+    let mut proven = Vec::new();
+    let error = all!(
+        {
+            proven.push(0);
+            true
+        },
+        false,
+        {
+            proven.push(2);
+            true
+        }
+    )
+    .prove()
+    .unwrap_err();
+    assert_eq!(error.index, 1);
+    assert_eq!(proven, [0]);
+}
+
+#[test]
+fn differential_shrinks_to_the_minimal_diverging_input() {
+    let fail = (0..1000u32)
+        .differential(|value| value / 2, |value| value.min(100) / 2)
+        .unwrap();
+    assert_eq!(fail.item, 102);
+    let Cause::Disprove(divergence) = fail.cause else {
+        panic!("expected a disproof");
+    };
+    assert_eq!(divergence.reference, 51);
+    assert_eq!(divergence.candidate, 50);
+}
+
+#[test]
+fn differential_passes_when_outputs_agree() {
+    assert!(
+        (0..1000u32)
+            .differential(|value| value * 2, |value| value << 1)
+            .is_none()
+    );
+}
+
+#[test]
+fn divergence_displays_a_line_diff() {
+    let divergence = Differential::new((1, 2), (1, 3)).prove().unwrap_err();
+    assert_eq!(
+        divergence.to_string(),
+        "outputs diverge (- reference, + candidate)\n  (\n      1,\n-     2,\n+     3,\n  )"
+    );
+}
+
+#[test]
+fn divergence_aligns_shifted_lines() {
+    let divergence = Differential::new(vec![1, 2, 3], vec![0, 1, 2, 3])
+        .prove()
+        .unwrap_err();
+    assert_eq!(
+        divergence.to_string(),
+        "outputs diverge (- reference, + candidate)\n  [\n+     0,\n      1,\n      2,\n      3,\n  ]"
+    );
+    let divergence = Differential::new(vec![1, 2, 3, 4], vec![1, 3, 4, 5])
+        .prove()
+        .unwrap_err();
+    assert_eq!(
+        divergence.to_string(),
+        "outputs diverge (- reference, + candidate)\n  [\n      1,\n-     2,\n      3,\n      4,\n+     5,\n  ]"
+    );
+}
+
+#[test]
+fn check_all_shrinks_against_the_failed_property_only() {
+    for _ in 0..10 {
+        let fail = (0..1000u32)
+            .check_all([
+                ("even", &|value: &u32| *value < 100 || value % 2 == 0),
+                ("small", &|value: &u32| *value < 100),
+            ])
+            .unwrap();
+        let Cause::Disprove(property) = fail.cause else {
+            panic!("expected a disproof");
+        };
+        match (property.index, property.name) {
+            (0, "even") => assert!(fail.item > 100 && fail.item % 2 == 1),
+            (1, "small") => assert_eq!(fail.item, 100),
+            other => panic!("unexpected property {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn check_all_passes_when_every_property_holds() {
+    let checked = std::cell::Cell::new(0);
+    let count = |_: &u8| checked.set(checked.get() + 1);
+    assert!(
+        (0..10u8)
+            .check_all([("first", &count), ("second", &count)])
+            .is_none()
+    );
+    assert_eq!(checked.get() % 2, 0);
+}
+
+#[test]
+fn check_all_shrinks_panics_against_the_panicking_property() {
+    let fail = (0..1000u32)
+        .check_all([
+            ("never", &|_: &u32| {}),
+            ("panics", &|value: &u32| assert!(*value < 50)),
+        ])
+        .unwrap();
+    assert_eq!(fail.item, 50);
+    assert!(matches!(fail.cause, Cause::Panic(..)));
+}
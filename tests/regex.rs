@@ -33,3 +33,44 @@ fn range_shrinks() {
     assert!(fail.item.chars().all(|symbol| symbol.is_ascii_lowercase()));
     assert!(fail.item == "wy" || fail.item == "yw");
 }
+
+#[test]
+fn generate_bytes_matches_regex() {
+    const PATTERN: &str = "(?-u)([\\x80-\\xFF]|ab)*\\xFF{2}(?u:[α-ω])?";
+    let matcher = ::regex::bytes::Regex::new(&format!("^(?:{PATTERN})$")).unwrap();
+    assert!(
+        regex_bytes(PATTERN, None)
+            .unwrap()
+            .check(|item| matcher.is_match(&item))
+            .is_none()
+    );
+}
+
+#[test]
+fn generate_bytes_produces_invalid_utf8() {
+    assert!(
+        regex_bytes("(?-u:[\\x80-\\xBF])+", None)
+            .unwrap()
+            .samples(100)
+            .all(|item| !item.is_empty() && String::from_utf8(item).is_err())
+    );
+}
+
+#[test]
+fn generate_bytes_keeps_unicode_as_utf8() {
+    assert!(
+        regex_bytes("[α-ω]{3}", None)
+            .unwrap()
+            .samples(100)
+            .all(|item| String::from_utf8(item).map_or(false, |item| item.chars().count() == 3))
+    );
+}
+
+#[test]
+fn byte_range_shrinks() {
+    let fail = regex_bytes("(?-u:[\\x00-\\xFF])+", None)
+        .unwrap()
+        .check(|item| !item.contains(&0xFF))
+        .unwrap();
+    assert_eq!(fail.item, vec![0xFF]);
+}
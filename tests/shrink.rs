@@ -1,5 +1,7 @@
 pub mod common;
+use checkito::check::{Stop, Strategy};
 use common::*;
+use core::ops::Range;
 
 #[test]
 fn finds_minimum() {
@@ -27,3 +29,413 @@ fn vec_removes_irrelevant_then_shrinks() {
     assert_eq!(shrunk.len(), 10);
     assert_eq!(shrunk.iter().filter(|&&item| item == 10).count(), 1);
 }
+
+#[test]
+fn collections_shrink_to_the_smallest_count() {
+    for _ in 0..100 {
+        let fail = (0..10u8)
+            .collect_with::<_, Vec<_>>(1..=2usize)
+            .check(|_| false)
+            .unwrap();
+        assert_eq!(fail.item, [0]);
+    }
+}
+
+#[test]
+fn bounded_strategy_limits_evaluations() {
+    for count in 0..10 {
+        let mut checker = (1000..1_000_000usize).checker();
+        checker.shrink.strategy = Strategy::Bounded(count);
+        let fail = checker
+            .checks(|value| value < 1000)
+            .last()
+            .unwrap()
+            .fail(false)
+            .unwrap();
+        assert!(fail.shrinks <= count);
+        assert!(fail.item >= 1000);
+    }
+}
+
+#[test]
+fn exhaustive_strategy_explores_beyond_greedy() {
+    let generator = (..100usize).collect::<Vec<_>>();
+    let property = |items: Vec<usize>| items.len() < 5 || items.iter().sum::<usize>() < 50;
+    let mut checker = generator.checker();
+    checker.generate.items = false;
+    let greedy = checker
+        .checks(property)
+        .last()
+        .unwrap()
+        .fail(false)
+        .unwrap();
+    checker.shrink.strategy = Strategy::Exhaustive;
+    checker.shrink.count = 10_000;
+    let exhaustive = checker
+        .checks(property)
+        .last()
+        .unwrap()
+        .fail(false)
+        .unwrap();
+    assert!(!property(exhaustive.item.clone()));
+    assert!(exhaustive.shrinks >= greedy.shrinks);
+}
+
+#[test]
+fn invertible_map_skips_items_outside_of_inverse() {
+    let generator = (0..1_000_000usize).map_invertible(
+        |value| value * 2,
+        |&value| {
+            if value % 4 == 0 {
+                Some(value / 2)
+            } else {
+                None
+            }
+        },
+    );
+    let mut checker = generator.checker();
+    checker.generate.items = false;
+    for result in checker.checks(|value| value < 1000) {
+        if let check::Result::Shrink(pass) = result {
+            assert_eq!(pass.item % 4, 0);
+        }
+    }
+}
+
+#[test]
+fn invertible_map_shrinks_mapped_items() {
+    let plain = u16::generator()
+        .map(u16::reverse_bits)
+        .check(|item| item < 1000)
+        .unwrap();
+    let invertible = u16::generator()
+        .map_invertible(u16::reverse_bits, |&item| Some(item.reverse_bits()))
+        .check(|item| item < 1000)
+        .unwrap();
+    assert_eq!(invertible.item, 1000);
+    assert!(invertible.item < plain.item);
+}
+
+#[test]
+fn confirm_reports_nondeterministic_failures_as_flaky() {
+    let mut checker = (1000..1_000_000usize).checker();
+    checker.shrink.confirm = 4;
+    let mut calls = 0;
+    let flaky = checker
+        .checks(|_| {
+            calls += 1;
+            calls % 2 == 0
+        })
+        .last()
+        .unwrap()
+        .flaky()
+        .unwrap();
+    assert_eq!(flaky.passes, 2);
+    assert_eq!(flaky.fails, 2);
+    assert_eq!(flaky.fail.shrinks, 0);
+    assert!(flaky.fail.item >= 1000);
+}
+
+#[test]
+fn confirm_shrinks_consistent_failures() {
+    let mut checker = (1000..1_000_000usize).checker();
+    checker.shrink.confirm = 4;
+    let fail = checker
+        .checks(|value| value < 1000)
+        .last()
+        .unwrap()
+        .fail(false)
+        .unwrap();
+    assert_eq!(fail.item, 1000);
+}
+
+#[test]
+fn shrink_order_shrinks_listed_components_first() {
+    let generator = (100..1000usize, 100..1000usize, 100..1000usize).shrink_order([2, 0]);
+    let mut checker = generator.checker().with_sizes(1.0).with_seed(7);
+    checker.generate.items = false;
+    let items = checker
+        .checks(|_| false)
+        .filter_map(|result| result.fail(true))
+        .map(|fail| fail.item)
+        .collect::<Vec<_>>();
+    let (first, last) = (items[0], items[items.len() - 1]);
+    assert!(first.0 != 100 && first.1 != 100 && first.2 != 100);
+    assert_eq!(last, (100, 100, 100));
+    let changed = |index: usize| {
+        items
+            .iter()
+            .position(|item| [item.0, item.1, item.2][index] != [first.0, first.1, first.2][index])
+    };
+    let done = |index: usize| {
+        items
+            .iter()
+            .position(|item| [item.0, item.1, item.2][index] == 100)
+    };
+    assert!(changed(0) > done(2));
+    assert!(changed(1) > done(0));
+}
+
+#[test]
+fn search_explains_why_shrinking_stopped() {
+    let fail = (0..1000u16).check(|value| value < 100).unwrap();
+    assert_eq!(fail.search.stop, Some(Stop::Fixpoint));
+    assert_eq!(fail.search.accepted + fail.search.rejected, fail.shrinks);
+    assert_eq!(fail.search.backtracks, 0);
+    assert_eq!(fail.search.phases.len(), 1);
+
+    let mut checker = (100..u16::MAX).checker().with_sizes(1.0);
+    checker.shrink.count = 1;
+    let fail = checker
+        .checks(|value| value < 100)
+        .last()
+        .unwrap()
+        .fail(false)
+        .unwrap();
+    assert_eq!(fail.search.stop, Some(Stop::Budget));
+
+    let mut checker = (100..u16::MAX).checker().with_sizes(1.0);
+    checker.shrink.strategy = Strategy::Exhaustive;
+    let fail = checker
+        .checks(|value| value < 100)
+        .last()
+        .unwrap()
+        .fail(false)
+        .unwrap();
+    assert_eq!(fail.search.stop, Some(Stop::Exhausted));
+    assert!(fail.search.backtracks > 0);
+    assert!(
+        fail.search
+            .to_string()
+            .ends_with("stopped with no more candidates")
+    );
+}
+
+#[test]
+fn search_records_the_candidates_of_each_phase() {
+    // Every generated item fails and the seed and size are pinned, such that both phases make
+    // progress.
+    let generator = (200..1000u16, (200..1000u16).keep_until_last());
+    let fail = generator
+        .checker()
+        .with_seed(7)
+        .with_sizes(1.0)
+        .checks(|(left, right)| left < 200 || right < 200)
+        .last()
+        .unwrap()
+        .fail(false)
+        .unwrap();
+    assert_eq!(fail.generates, 0);
+    assert_eq!(fail.item, (200, 200));
+    let phases = &fail.search.phases;
+    assert_eq!(phases.len(), 2);
+    assert!(phases.iter().all(|phase| phase.accepted > 0));
+    assert_eq!(
+        phases.iter().map(|phase| phase.accepted).sum::<usize>(),
+        fail.search.accepted
+    );
+    assert_eq!(
+        phases.iter().map(|phase| phase.rejected).sum::<usize>(),
+        fail.search.rejected
+    );
+    assert!(fail.search.to_string().contains("; phase 2: "));
+}
+
+#[test]
+fn matrix_rows_have_equal_lengths() {
+    assert!(
+        matrix(0..8usize, 0..8usize, u8::generator())
+            .check(|rows| rows.windows(2).all(|pair| pair[0].len() == pair[1].len()))
+            .is_none()
+    );
+}
+
+#[test]
+fn matrix_shrinks_dimensions_then_items() {
+    let fail = matrix(1..10usize, 1..10usize, ..100usize)
+        .check(|rows| rows.iter().flatten().all(|&item| item < 50))
+        .unwrap();
+    assert_eq!(fail.item, [[50]]);
+}
+
+#[test]
+fn matrix_respects_minimum_dimensions() {
+    let fail = (..100usize)
+        .matrix(2..10usize, 3..10usize)
+        .check(|rows| rows.iter().flatten().all(|&item| item < 50))
+        .unwrap();
+    assert_eq!(fail.item.len(), 2);
+    assert!(fail.item.iter().all(|row| row.len() == 3));
+}
+
+#[test]
+fn indices_are_in_bounds() {
+    assert!(
+        (..100u8)
+            .collect::<Vec<_>>()
+            .with_indices(0..10usize)
+            .check(|(items, indices)| indices.iter().all(|&index| index < items.len()))
+            .is_none()
+    );
+}
+
+#[test]
+fn indices_stay_in_bounds_when_shrinking() {
+    let fail = with_indices((..100usize).collect::<Vec<_>>(), 1..10usize)
+        .check(|(items, indices)| indices.iter().all(|&index| items[index] < 50))
+        .unwrap();
+    assert_eq!(fail.item, (vec![50], vec![0]));
+}
+
+#[test]
+fn indices_follow_their_items_when_shrinking() {
+    let fail = with_indices((..100usize).collect::<Vec<_>>(), 2..10usize)
+        .check(|(items, indices)| indices.iter().filter(|&&index| items[index] >= 50).count() < 2)
+        .unwrap();
+    // Removing items never moves the indices onto other items.
+    let (items, indices) = fail.item;
+    assert_eq!(indices.len(), 2);
+    assert!(indices.iter().all(|&index| items[index] == 50));
+}
+
+#[test]
+fn indices_respect_the_minimum_count_when_shrinking() {
+    let fail = with_indices((..100usize).collect::<Vec<_>>(), 3..10usize)
+        .check(|(items, _)| items.len() < 5)
+        .unwrap();
+    assert_eq!(fail.item.0.len(), 5);
+    assert_eq!(fail.item.1.len(), 3);
+}
+
+#[test]
+fn sorted_is_ordered() {
+    assert!(
+        sorted(u8::generator(), 0..100usize)
+            .check(|items| items.windows(2).all(|pair| pair[0] <= pair[1]))
+            .is_none()
+    );
+}
+
+#[test]
+fn strictly_increasing_is_ordered() {
+    assert!(
+        strictly_increasing(0..1000u16, 0..100usize)
+            .check(|items| items.windows(2).all(|pair| pair[0] < pair[1]))
+            .is_none()
+    );
+}
+
+#[test]
+fn sorted_shrinks_while_ordered() {
+    let fail = sorted(..100usize, 0..100usize)
+        .check(|items| items.iter().sum::<usize>() < 100)
+        .unwrap();
+    assert!(fail.item.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert_eq!(fail.item.iter().sum::<usize>(), 100);
+}
+
+fn counter() -> impl Generate<Item = Vec<u8>> {
+    // A counter that can be incremented up to 10 or reset.
+    transitions(0u8, |&count: &u8| {
+        let mut successors = vec![same(0)];
+        if count < 10 {
+            successors.push(same(count + 1));
+        }
+        successors
+    })
+}
+
+#[test]
+fn transitions_are_valid() {
+    assert!(
+        counter()
+            .check(|states| {
+                states[0] == 0
+                    && states
+                        .windows(2)
+                        .all(|pair| pair[1] == 0 || pair[1] == pair[0] + 1)
+            })
+            .is_none()
+    );
+}
+
+#[test]
+fn transitions_shrink_to_the_shortest_valid_chain() {
+    let fail = counter()
+        .check(|states| states.iter().all(|&state| state < 5))
+        .unwrap();
+    assert_eq!(fail.item, [0, 1, 2, 3, 4, 5]);
+}
+
+/// A user defined shrinker that proposes smaller and smaller items.
+#[derive(Clone)]
+struct Decrement {
+    item: u32,
+    next: Option<u32>,
+}
+
+impl Decrement {
+    const fn new(item: u32) -> Self {
+        Self {
+            item,
+            next: item.checked_sub(1),
+        }
+    }
+}
+
+impl Shrink for Decrement {
+    type Item = u32;
+
+    fn item(&self) -> Self::Item {
+        self.item
+    }
+
+    fn shrink(&mut self) -> Option<Self> {
+        let item = self.next?;
+        self.next = item.checked_sub(1);
+        Some(Decrement::new(item))
+    }
+}
+
+/// Even numbers that shrink as even numbers and then by decrementing.
+struct Evens;
+
+impl Generate for Evens {
+    type Item = u32;
+    type Shrink = shrink::AndThen<
+        checkito::map::Map<<Range<u32> as Generate>::Shrink, fn(u32) -> u32>,
+        Decrement,
+        fn(&u32) -> Decrement,
+    >;
+
+    fn generate(&self, state: &mut generate::State) -> Self::Shrink {
+        let shrinker = shrink::map((0..500u32).generate(state), (|item| item * 2) as fn(_) -> _);
+        shrink::and_then(shrinker, |&item| Decrement::new(item))
+    }
+
+    fn constant(&self) -> bool {
+        false
+    }
+
+    fn cardinality(&self) -> Option<u128> {
+        Some(500)
+    }
+}
+
+#[test]
+fn shrink_combinators_compose_in_manual_generators() {
+    assert!(Evens.samples(100).all(|item| item % 2 == 0));
+    let fail = Evens.check(|item| item < 101).unwrap();
+    assert_eq!(fail.item, 101);
+}
+
+#[test]
+fn shrink_filter_skips_rejected_candidates() {
+    let mut shrinker = shrink::filter(Decrement::new(50), |item| item % 7 == 0);
+    let mut items = Vec::new();
+    while let Some(next) = shrinker.shrink() {
+        items.push(next.item());
+        shrinker = next;
+    }
+    assert_eq!(items, [49, 42, 35, 28, 21, 14, 7, 0]);
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "insta")]
+
+pub mod common;
+use checkito::snapshot::{self, Location, Replay, insta};
+use common::*;
+use std::{env, fs};
+
+fn location(name: &str) -> Location {
+    let root = env::temp_dir().join(format!("checkito-{}", std::process::id()));
+    let location = Location::new(
+        root.to_str().unwrap(),
+        "tests/snapshot.rs",
+        "snapshot",
+        name,
+    );
+    let _ = fs::remove_file(location.path());
+    let _ = fs::remove_file(location.path().with_extension("snap.new"));
+    location
+}
+
+fn accept(location: &Location) {
+    fs::rename(location.path().with_extension("snap.new"), location.path()).unwrap();
+}
+
+#[test]
+fn disproved_items_are_written_to_a_pending_snapshot() {
+    let location = location("disproved");
+    let fail = (0..1000u32)
+        .check(snapshot::record(location.clone(), |value: u32| value < 100))
+        .unwrap();
+    assert_eq!(fail.item, 100);
+    assert!(!location.path().exists());
+    let pending = insta::Snapshot::from_file(&location.path().with_extension("snap.new")).unwrap();
+    assert_eq!(pending.as_text().unwrap().to_string(), "100");
+}
+
+#[test]
+fn panicking_items_are_written_to_a_pending_snapshot() {
+    let location = location("panicking");
+    let fail = (0..1000i16)
+        .check(snapshot::record(location.clone(), |value: i16| {
+            assert!(value < 50)
+        }))
+        .unwrap();
+    assert_eq!(fail.item, 50);
+    accept(&location);
+    assert_eq!(location.load::<i16>(), Some(50));
+}
+
+#[test]
+fn accepted_snapshot_is_replayed_first() {
+    let location = location("replayed");
+    (0..1000u64)
+        .check(snapshot::record(location.clone(), |value: u64| value < 500))
+        .unwrap();
+    accept(&location);
+    let fail = Replay::new(&location, 0..10u64)
+        .check(|value| value < 10)
+        .unwrap();
+    assert_eq!(fail.item, 500);
+    assert_eq!(fail.generates, 0);
+}
+
+#[test]
+fn accepted_snapshot_removes_equal_pending_snapshot() {
+    let location = location("unchanged");
+    let check = snapshot::record(location.clone(), |value: (u8, bool)| value.0 < 10);
+    let fail = (0..100u8, true).check(&check).unwrap();
+    accept(&location);
+    (0..100u8, true).check(&check).unwrap();
+    assert_eq!(location.load::<(u8, bool)>(), Some(fail.item));
+    assert!(!location.path().with_extension("snap.new").exists());
+}
@@ -0,0 +1,212 @@
+pub mod common;
+use common::*;
+use core::cell::RefCell;
+use generate::{State, States};
+
+// These values are part of the reproducibility contract of `State::VERSION`.
+// If they change, so must the version.
+
+fn state(seed: u64) -> State {
+    States::new(1, 1.0, Some(seed)).next().unwrap()
+}
+
+#[test]
+fn version_is_stable() {
+    assert_eq!(State::VERSION, 1);
+}
+
+#[test]
+fn draw_u64_is_reproducible() {
+    let mut state = state(0);
+    assert_eq!(state.draw_u64(..), 11116517241604665558);
+    assert_eq!(state.draw_u64(0..10), 0);
+    assert_eq!(state.draw_u64(100..=200), 109);
+    assert_eq!(state.draw_u64(u64::MAX..), u64::MAX);
+}
+
+#[test]
+fn draw_choice_is_reproducible() {
+    let mut state = state(0xC0FFEE);
+    assert_eq!(state.draw_choice(2), 0);
+    assert_eq!(state.draw_choice(7), 5);
+    assert_eq!(state.draw_choice(1000), 411);
+    assert_eq!(state.draw_choice(1), 0);
+}
+
+#[test]
+fn draw_u64_is_within_range() {
+    assert!(
+        (0..u64::MAX, 0..u64::MAX, u64::generator())
+            .check(|(low, high, seed)| {
+                let (low, high) = (low.min(high), low.max(high));
+                (low..=high).contains(&state(seed).draw_u64(low..=high))
+            })
+            .is_none()
+    );
+}
+
+#[test]
+#[should_panic]
+fn draw_choice_panics_on_zero() {
+    state(0).draw_choice(0);
+}
+
+struct Recorder(RefCell<Vec<f64>>);
+
+impl Generate for Recorder {
+    type Item = u8;
+    type Shrink = u8;
+
+    fn generate(&self, state: &mut State) -> Self::Shrink {
+        self.0.borrow_mut().push(state.size());
+        0
+    }
+
+    fn constant(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn with_restores_only_overridden_parameters() {
+    let mut state = state(0);
+    {
+        let mut with = state.with().size(0.25).depth(3);
+        assert_eq!((*with).size(), 0.25);
+        assert_eq!((*with).depth(), 3);
+        drop(with.with().limit(7).limit(9));
+        assert_eq!((*with).limit(), 0);
+    }
+    assert_eq!(state.size(), 1.0);
+    assert_eq!(state.depth(), 0);
+}
+
+#[test]
+fn retry_with_growth_grows_the_size_across_retries() {
+    let mut state = States::new(4, 0.0..=1.0, Some(0)).next().unwrap();
+    let recorder = Recorder(RefCell::new(Vec::new()));
+    assert_eq!(state.retry_with_growth(&recorder, 4, |_| false), None);
+    assert_eq!(
+        recorder.0.borrow().as_slice(),
+        [0.0, 0.3125, 0.625, 0.9375, 1.0]
+    );
+    assert_eq!(state.size(), 0.0);
+}
+
+#[test]
+fn retry_with_growth_stops_at_the_accepted_shrinker() {
+    let mut state = state(0);
+    let mut attempts = 0;
+    let accepted = state.retry_with_growth(&(0..100u8), 1000, |shrinker| {
+        attempts += 1;
+        shrinker.item() >= 50
+    });
+    assert!(accepted.unwrap().item() >= 50);
+    assert!(attempts < 1000);
+}
+
+#[test]
+fn retry_with_growth_stops_at_a_rejected_constant() {
+    let mut attempts = 0;
+    let accepted = state(0).retry_with_growth(&5u8, 1000, |_| {
+        attempts += 1;
+        false
+    });
+    assert_eq!(accepted, None);
+    assert_eq!(attempts, 1);
+}
+
+/// Generates `count` items of `generator` from as many strata at full size.
+fn enumerated<G: Generate<Item = T>, T: Ord + Clone>(generator: G, count: usize) -> Vec<T> {
+    let mut checker = generator.checker();
+    checker.generate.count = count;
+    checker.generate.size = 1.0.into();
+    checker.generate.mode = check::Modes::Stratified;
+    let mut items = Vec::new();
+    for result in checker.checks(|item: T| items.push(item)) {
+        assert!(result.pass(false).is_some());
+    }
+    items.sort_unstable();
+    items
+}
+
+#[test]
+fn stratified_generation_is_a_bijection_for_128_bit_ranges_at_the_extremes() {
+    assert_eq!(
+        enumerated(u128::MAX - 7..=u128::MAX, 8),
+        Iterator::map(0..8, |offset| u128::MAX - 7 + offset).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        enumerated(..8u128, 8),
+        Iterator::collect::<Vec<_>>(0..8u128)
+    );
+    assert_eq!(
+        enumerated(i128::MAX - 7.., 8),
+        Iterator::map(0..8, |offset| i128::MAX - 7 + offset).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        enumerated(..=i128::MIN + 7, 8),
+        Iterator::map(0..8, |offset| i128::MIN + offset).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn stratified_generation_spans_full_width_128_bit_ranges() {
+    let items = enumerated(u128::MIN..=u128::MAX, 8);
+    for (index, item) in items.into_iter().enumerate() {
+        assert_eq!(item >> 125, index as u128);
+    }
+    let items = enumerated(i128::MIN..=i128::MAX, 8);
+    for (index, item) in items.into_iter().enumerate() {
+        assert_eq!(item >> 125, index as i128 - 4);
+    }
+}
+
+#[test]
+fn with_state_generates_from_the_state() {
+    let generator = with_state(|state| (state.size(), state.draw_u64(..)));
+    let mut left = state(7);
+    let mut right = state(7);
+    assert_eq!(
+        generator.generate(&mut left).item(),
+        generator.generate(&mut right).item()
+    );
+    assert!(
+        generator
+            .samples(100)
+            .all(|(size, _)| (0.0..=1.0).contains(&size))
+    );
+}
+
+#[test]
+fn with_state_does_not_shrink_by_default() {
+    let generator = with_state(|state| state.draw_u64(..));
+    let fail = generator.check(|item| item < 1000).unwrap();
+    assert_eq!(fail.shrinks, 0);
+}
+
+#[test]
+fn with_state_shrinks_with_the_candidates_in_order() {
+    let generator = with_state(|state| state.draw_u64(100..1000))
+        .shrink_with(|&item: &u64| Iterator::map(0..item.min(3), move |offset| item / 2 + offset));
+    let fail = generator.check(|item| item < 50).unwrap();
+    assert!((50..100).contains(&fail.item));
+    assert!(fail.shrinks > 0);
+}
+
+#[test]
+fn function_pointers_generate_from_the_state() {
+    let generator: fn(&mut State) -> u64 = |state| state.draw_u64(..10);
+    assert!(generator.samples(100).all(|item| item < 10));
+    let fail = generator.check(|item| item < 5).unwrap();
+    assert_eq!(fail.shrinks, 0);
+}
+
+#[test]
+fn closure_objects_generate_from_the_state() {
+    let bound = 10;
+    let generator: &dyn Fn(&mut State) -> u64 = &|state| state.draw_u64(..bound);
+    assert!(generator.samples(100).all(|item| item < bound));
+    let generator = (generator, generator).map(|(left, right)| left + right);
+    assert!(generator.samples(100).all(|item| item < bound * 2));
+}
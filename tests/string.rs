@@ -18,3 +18,127 @@ mod check {
         }
     }
 }
+
+#[test]
+fn shrinks_characters_to_a() {
+    let fail = String::generator()
+        .check(|text| text.chars().count() < 5)
+        .unwrap();
+    assert_eq!(fail.item, "aaaaa");
+}
+
+#[test]
+fn shrinks_to_a_single_word() {
+    let fail = String::generator()
+        .check(|text| !text.split_whitespace().any(|word| word.chars().count() > 2))
+        .unwrap();
+    assert_eq!(fail.item, "aaa");
+}
+
+fn is_label(label: &str) -> bool {
+    (1..=63).contains(&label.len())
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label
+            .chars()
+            .all(|value| value.is_ascii_lowercase() || value.is_ascii_digit() || value == '-')
+}
+
+#[test]
+fn hostname_is_valid() {
+    for host in hostname().samples(1000) {
+        assert!(host.len() <= 253);
+        assert!(host.split('.').all(is_label), "{host}");
+    }
+    assert_eq!(hostname().check(|_| false).unwrap().item, "a");
+}
+
+#[test]
+fn email_is_valid() {
+    for email in email().samples(1000) {
+        let (local, domain) = email.split_once('@').unwrap();
+        assert!(local.len() <= 64);
+        assert!(
+            local
+                .split('.')
+                .all(|atom| !atom.is_empty() && !atom.contains('@'))
+        );
+        assert!(domain.split('.').all(is_label), "{email}");
+        let top = domain.rsplit('.').next().unwrap();
+        assert!(top.len() >= 2 && top.chars().all(|value| value.is_ascii_lowercase()));
+    }
+    assert_eq!(email().check(|_| false).unwrap().item, "a@a.aa");
+}
+
+#[test]
+fn url_is_valid() {
+    for url in url().samples(1000) {
+        let (scheme, rest) = url.split_once("://").unwrap();
+        assert!(["http", "https", "ftp", "ws"].contains(&scheme));
+        let (authority, _) = rest.split_once('/').unwrap();
+        let host = authority.split(':').next().unwrap();
+        assert!(host.split('.').all(is_label), "{url}");
+        assert!(url.is_ascii() && !url.contains(' '));
+    }
+    assert_eq!(url().check(|_| false).unwrap().item, "http://a/");
+}
+
+#[test]
+fn sentences_are_made_of_script_words() {
+    let generator = sentence(word(text::Script::Cyrillic), unify(any(('.', '!', '?'))));
+    for sentence in generator.samples(1000) {
+        let (body, end) = sentence.split_at(sentence.len() - 1);
+        assert!([".", "!", "?"].contains(&end));
+        assert!(body.chars().next().map_or(false, char::is_uppercase));
+        assert!(body.split(' ').all(|word| {
+            !word.is_empty()
+                && word
+                    .to_lowercase()
+                    .chars()
+                    .all(|letter| text::Script::Cyrillic.letters().contains(&letter))
+        }));
+    }
+}
+
+#[test]
+fn lorem_sentences_are_made_of_placeholder_words() {
+    let words = "lorem ipsum dolor sit amet consectetur adipiscing elit sed do eiusmod tempor \
+                 incididunt ut labore et dolore magna aliqua enim ad minim veniam quis nostrud \
+                 exercitation ullamco laboris nisi aliquip ex ea commodo consequat duis aute \
+                 irure in reprehenderit voluptate velit esse cillum eu fugiat nulla pariatur \
+                 excepteur sint occaecat cupidatat non proident sunt culpa qui officia deserunt \
+                 mollit anim id est laborum"
+        .split(' ')
+        .collect::<Vec<_>>();
+    for sentence in sentence(lorem(), '.').samples(1000) {
+        let body = sentence.strip_suffix('.').unwrap().to_lowercase();
+        assert!(
+            body.split(' ').all(|word| words.contains(&word)),
+            "{sentence}"
+        );
+    }
+    assert_eq!(
+        sentence(lorem(), '.').check(|_| false).unwrap().item,
+        "Lorem."
+    );
+}
+
+#[test]
+fn han_letters_are_assigned_ideographs() {
+    let letters = text::Script::Han.letters();
+    assert_eq!(letters, '\u{4E00}'..='\u{9FA5}');
+    assert!(
+        text::Script::Han
+            .samples(1000)
+            .all(|letter| letters.contains(&letter))
+    );
+}
+
+#[test]
+fn paragraphs_shrink_by_removing_sentences_and_words() {
+    let generator = paragraph(sentence(word(text::Script::Latin), '.'));
+    let fail = generator
+        .check(|paragraph| paragraph.matches('.').count() < 2)
+        .unwrap();
+    assert_eq!(fail.item, "A. A.");
+}
@@ -0,0 +1,102 @@
+pub mod common;
+use checkito::tagged::{Frame, Width};
+use common::*;
+
+const WIDTHS: [Width; 4] = [Width::U8, Width::U16, Width::U32, Width::Varint];
+
+fn decode(bytes: &[u8], tag: Width, length: Width) -> Option<(u64, &[u8])> {
+    let (tag, used) = tag.decode(bytes)?;
+    let bytes = &bytes[used..];
+    let (length, used) = length.decode(bytes)?;
+    let payload = bytes.get(used..)?;
+    (payload.len() as u64 == length).then_some((tag, payload))
+}
+
+fn variants() -> [(u64, boxed::Boxed<Vec<u8>>); 3] {
+    [
+        (0, same(Vec::new()).boxed()),
+        (7, Vec::<u8>::generator().boxed()),
+        (300, (0..=255u8).collect_with(16..=16usize).boxed()),
+    ]
+}
+
+#[test]
+fn widths_round_trip() {
+    assert!(
+        (u64::generator(), enum_of(&WIDTHS))
+            .check(|(value, width)| {
+                let mut bytes = Vec::new();
+                width.encode(value, &mut bytes);
+                let expected = match width {
+                    Width::U8 => value as u8 as u64,
+                    Width::U16 => value as u16 as u64,
+                    Width::U32 => value as u32 as u64,
+                    Width::Varint => value,
+                };
+                width.decode(&bytes) == Some((expected, bytes.len()))
+            })
+            .is_none()
+    );
+}
+
+#[test]
+fn frames_decode_to_their_tag_and_payload() {
+    for tag in [Width::U16, Width::Varint] {
+        // Payloads are longer than what fits in a `Width::U8`.
+        for length in [Width::U16, Width::U32, Width::Varint] {
+            assert!(
+                tagged_union(variants(), tag, Some(length))
+                    .check(
+                        |Frame {
+                             tag: value,
+                             payload,
+                             bytes,
+                         }| {
+                            decode(&bytes, tag, length) == Some((value, payload.as_slice()))
+                        }
+                    )
+                    .is_none()
+            );
+        }
+    }
+}
+
+#[test]
+fn frames_without_length_end_with_their_payload() {
+    assert!(
+        tagged_union(variants(), Width::U8, None)
+            .check(|frame| frame.bytes[0] as u64 == frame.tag % 256
+                && frame.bytes[1..] == frame.payload[..])
+            .is_none()
+    );
+}
+
+#[test]
+fn shrinks_within_the_variant_first() {
+    let fail = tagged_union(variants(), Width::Varint, Some(Width::Varint))
+        .check(|frame| frame.tag != 7 || frame.payload.len() < 3)
+        .unwrap();
+    assert_eq!(fail.item.tag, 7);
+    assert_eq!(fail.item.payload, [0, 0, 0]);
+}
+
+#[test]
+fn shrinks_to_a_simpler_variant() {
+    let fail = tagged_union(variants(), Width::Varint, Some(Width::Varint))
+        .check(|frame| frame.tag == 0)
+        .unwrap();
+    assert_eq!(fail.item.tag, 7);
+    assert_eq!(fail.item.bytes, [7, 0]);
+}
+
+#[test]
+fn cardinality_is_the_sum_of_the_variants() {
+    fn byte(value: u8) -> [u8; 1] {
+        [value]
+    }
+    let variants = [(1, map(0..10u8, byte)), (2, map(0..5u8, byte))];
+    assert_eq!(
+        tagged_union(variants, Width::U8, None).cardinality(),
+        Some(15)
+    );
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "tracing")]
+
+pub mod common;
+use common::*;
+use core::fmt;
+use std::sync::{Arc, Mutex};
+use tracing::{
+    Event, Metadata, Subscriber,
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+};
+
+/// Records the fields of every span and event as a line of text.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+struct Line(String);
+
+impl Visit for Line {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.push_str(&format!(" {}={value:?}", field.name()));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn new_span(&self, attributes: &Attributes) -> Id {
+        let mut line = Line(attributes.metadata().name().into());
+        attributes.record(&mut line);
+        let mut lines = self.0.lock().unwrap();
+        lines.push(line.0);
+        Id::from_u64(lines.len() as u64)
+    }
+
+    fn record(&self, _: &Id, _: &Record) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event) {
+        let mut line = Line("event".into());
+        event.record(&mut line);
+        self.0.lock().unwrap().push(line.0);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn checks_run_in_spans_and_shrinks_emit_events() {
+    let recorder = Recorder::default();
+    let fail = tracing::subscriber::with_default(recorder.clone(), || {
+        (0..100u8).check(|value| value < 50).unwrap()
+    });
+    let lines = recorder.0.lock().unwrap();
+    let spans = lines
+        .iter()
+        .filter(|line| line.starts_with("check "))
+        .count();
+    let events = lines
+        .iter()
+        .filter(|line| line.starts_with("event "))
+        .count();
+    assert_eq!(spans, fail.generates + 1 + fail.shrinks);
+    assert_eq!(events, fail.shrinks);
+    let first = format!("check generates=0 shrinks=0 seed={} size=", fail.seed());
+    assert!(lines[0].starts_with(&first), "{}", lines[0]);
+    let accepted = lines
+        .iter()
+        .filter(|line| line.starts_with("event message=shrink") && line.ends_with("accepted=true"))
+        .count();
+    assert_eq!(accepted, fail.search.accepted);
+}